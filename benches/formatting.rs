@@ -56,21 +56,23 @@ fn bench_style_render(c: &mut Criterion) {
 }
 
 fn bench_highlight_inject_tags(c: &mut Criterion) {
-    let mut config = HighlightConfig::default();
-    config.enabled = true;
+    let mut config = HighlightConfig {
+        enabled: true,
+        ..Default::default()
+    };
     config.patterns.urls = Some("cyan".to_string());
     config.patterns.paths = Some("green".to_string());
     config.patterns.numbers = Some("yellow".to_string());
     config.patterns.quoted = Some("orange".to_string());
     config
         .keywords
-        .insert("error".to_string(), "red".to_string());
+        .insert("error".to_string(), "red".to_string().into());
     config
         .keywords
-        .insert("warning".to_string(), "yellow".to_string());
+        .insert("warning".to_string(), "yellow".to_string().into());
     config
         .keywords
-        .insert("success".to_string(), "green".to_string());
+        .insert("success".to_string(), "green".to_string().into());
 
     let mut group = c.benchmark_group("highlight::inject_tags");
 
@@ -1,4 +1,5 @@
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use hyprs_log::Logger;
 use hyprs_log::fmt::FormatValues;
 use hyprs_log::level::Level;
 use hyprs_log::output::{FileOutput, JsonOutput, LogRecord, Output};
@@ -17,6 +18,7 @@ fn make_record() -> LogRecord {
         label_override: None,
         app_name: Some("bench-app".to_string()),
         raw: false,
+        timestamp: None,
     }
 }
 
@@ -47,5 +49,23 @@ fn bench_json_output_write(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_file_output_write, bench_json_output_write,);
+fn bench_null_logger(c: &mut Criterion) {
+    let logger = Logger::builder().null().build();
+
+    c.bench_function("Logger::info (NullOutput)", |b| {
+        b.iter(|| {
+            logger.info(
+                black_box("BENCH"),
+                black_box("baseline <bold>message</bold>"),
+            );
+        });
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_file_output_write,
+    bench_json_output_write,
+    bench_null_logger,
+);
 criterion_main!(benches);
@@ -0,0 +1,63 @@
+use criterion::{Criterion, Throughput, black_box, criterion_group, criterion_main};
+use hyprs_log::config::HighlightConfig;
+use hyprs_log::fmt::FormatValues;
+use hyprs_log::level::Level;
+use hyprs_log::output::{LogRecord, TerminalOutput};
+
+fn make_record() -> LogRecord {
+    LogRecord {
+        level: Level::Warn,
+        scope: "NET".to_string(),
+        message: "Request error: GET https://api.example.com/users/123 returned 500 \
+                  at /var/log/api/2025-01-15.log - \"Internal Server Error\" with \
+                  warning: retry count 3, success rate dropped to 0.42"
+            .to_string(),
+        values: FormatValues::new(),
+        label_override: None,
+        app_name: Some("bench-app".to_string()),
+        raw: false,
+        timestamp: None,
+    }
+}
+
+fn highlight_config(enabled: bool) -> HighlightConfig {
+    let mut config = HighlightConfig {
+        enabled,
+        ..Default::default()
+    };
+    config.patterns.urls = Some("cyan".to_string());
+    config.patterns.paths = Some("green".to_string());
+    config.patterns.numbers = Some("yellow".to_string());
+    config.patterns.quoted = Some("orange".to_string());
+    for (keyword, color) in [
+        ("error", "red"),
+        ("warning", "yellow"),
+        ("success", "green"),
+        ("timeout", "red"),
+        ("retry", "orange"),
+    ] {
+        config.keywords.insert(keyword.to_string(), color.into());
+    }
+    config
+}
+
+fn bench_terminal_render(c: &mut Criterion) {
+    let record = make_record();
+    let mut group = c.benchmark_group("TerminalOutput::render");
+    group.throughput(Throughput::Elements(1));
+
+    let plain = TerminalOutput::new().highlight_config(highlight_config(false));
+    group.bench_function("highlight_off", |b| {
+        b.iter(|| plain.render(black_box(&record)));
+    });
+
+    let highlighted = TerminalOutput::new().highlight_config(highlight_config(true));
+    group.bench_function("highlight_on", |b| {
+        b.iter(|| highlighted.render(black_box(&record)));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_terminal_render);
+criterion_main!(benches);
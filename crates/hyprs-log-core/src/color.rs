@@ -0,0 +1,280 @@
+//! Color handling for terminal output.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+/// Named colors accepted by [`Color::parse`] (CSS values).
+const NAMED_COLORS: &[(&str, Color)] = &[
+    ("black", Color::new(0, 0, 0)),
+    ("white", Color::new(255, 255, 255)),
+    ("red", Color::new(255, 0, 0)),
+    ("green", Color::new(0, 128, 0)),
+    ("blue", Color::new(0, 0, 255)),
+    ("yellow", Color::new(255, 255, 0)),
+    ("cyan", Color::new(0, 255, 255)),
+    ("aqua", Color::new(0, 255, 255)),
+    ("magenta", Color::new(255, 0, 255)),
+    ("fuchsia", Color::new(255, 0, 255)),
+    ("purple", Color::new(128, 0, 128)),
+    ("orange", Color::new(255, 165, 0)),
+    ("pink", Color::new(255, 192, 203)),
+    ("brown", Color::new(165, 42, 42)),
+    ("gray", Color::new(128, 128, 128)),
+    ("grey", Color::new(128, 128, 128)),
+    ("silver", Color::new(192, 192, 192)),
+    ("maroon", Color::new(128, 0, 0)),
+    ("olive", Color::new(128, 128, 0)),
+    ("lime", Color::new(0, 255, 0)),
+    ("teal", Color::new(0, 128, 128)),
+    ("navy", Color::new(0, 0, 128)),
+    ("indigo", Color::new(75, 0, 130)),
+    ("violet", Color::new(238, 130, 238)),
+    ("gold", Color::new(255, 215, 0)),
+    ("coral", Color::new(255, 127, 80)),
+    ("salmon", Color::new(250, 128, 114)),
+    ("crimson", Color::new(220, 20, 60)),
+    ("turquoise", Color::new(64, 224, 208)),
+];
+
+/// RGB color for 24-bit true color terminal output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    /// Creates a new color from RGB values.
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Creates a color from a hex string (with or without `#` prefix).
+    ///
+    /// Returns white (`#ffffff`) for invalid input.
+    #[must_use]
+    pub fn from_hex(hex: &str) -> Self {
+        let hex = hex.trim_start_matches('#');
+        if hex.len() != 6 || !hex.is_ascii() {
+            return Self::white();
+        }
+
+        let r = u8::from_str_radix(&hex[0..2], 16).unwrap_or(255);
+        let g = u8::from_str_radix(&hex[2..4], 16).unwrap_or(255);
+        let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(255);
+
+        Self { r, g, b }
+    }
+
+    /// Parses a color written as hex (`#rrggbb`, `#rgb`, `#` optional), a
+    /// CSS color name (`red`, `teal`, ...) or `rgb(r, g, b)`/`rgba(r, g, b, a)`.
+    ///
+    /// Names are case-insensitive; the alpha channel of `rgba()` is validated
+    /// but ignored.
+    ///
+    /// # Errors
+    /// Returns [`ColorParseError`] if `s` is none of the accepted forms.
+    pub fn parse(s: &str) -> Result<Self, ColorParseError> {
+        let trimmed = s.trim();
+        let lower = trimmed.to_ascii_lowercase();
+        let error = || ColorParseError(trimmed.to_string());
+
+        if let Some(args) = lower
+            .strip_prefix("rgba(")
+            .or_else(|| lower.strip_prefix("rgb("))
+        {
+            let has_alpha = lower.starts_with("rgba(");
+            let args = args.strip_suffix(')').ok_or_else(error)?;
+            return Self::parse_rgb(args, has_alpha).ok_or_else(error);
+        }
+
+        if let Some(&(_, color)) = NAMED_COLORS.iter().find(|(name, _)| *name == lower) {
+            return Ok(color);
+        }
+
+        Self::parse_hex(trimmed.strip_prefix('#').unwrap_or(trimmed)).ok_or_else(error)
+    }
+
+    fn parse_hex(hex: &str) -> Option<Self> {
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+        match hex.len() {
+            6 => Some(Self::new(
+                channel(&hex[0..2])?,
+                channel(&hex[2..4])?,
+                channel(&hex[4..6])?,
+            )),
+            // #rgb expands each digit: #f0a == #ff00aa
+            3 => {
+                let short = |i: usize| channel(&hex[i..=i]).map(|v| v * 17);
+                Some(Self::new(short(0)?, short(1)?, short(2)?))
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_rgb(args: &str, has_alpha: bool) -> Option<Self> {
+        let parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        let (channels, alpha) = match (has_alpha, parts.as_slice()) {
+            (false, [r, g, b]) => ([*r, *g, *b], None),
+            (true, [r, g, b, a]) => ([*r, *g, *b], Some(*a)),
+            _ => return None,
+        };
+        if let Some(alpha) = alpha {
+            let alpha: f64 = alpha.parse().ok()?;
+            if !(0.0..=1.0).contains(&alpha) {
+                return None;
+            }
+        }
+        let [r, g, b] = channels.map(|c| c.parse::<u8>().ok());
+        Some(Self::new(r?, g?, b?))
+    }
+
+    /// Derives a stable color from a name (e.g. a log scope).
+    ///
+    /// Uses FNV-1a to pick a hue at fixed saturation and lightness, so the
+    /// same name maps to the same color on every run and platform. Needs the
+    /// `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn hashed(name: &str) -> Self {
+        let hash = name.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+            (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+        });
+        Self::from_hsl(f64::from(hash % 360), 0.65, 0.65)
+    }
+
+    /// Converts HSL (hue in degrees, saturation/lightness in `0..=1`) to RGB.
+    #[cfg(feature = "std")]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        let chroma = (1.0 - 2.0f64.mul_add(lightness, -1.0).abs()) * saturation;
+        let sector = hue / 60.0;
+        let second = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (red, green, blue) = match sector as u8 {
+            0 => (chroma, second, 0.0),
+            1 => (second, chroma, 0.0),
+            2 => (0.0, chroma, second),
+            3 => (0.0, second, chroma),
+            4 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+        let offset = lightness - chroma / 2.0;
+        let channel = |v: f64| ((v + offset) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self::new(channel(red), channel(green), channel(blue))
+    }
+
+    /// Returns the ANSI escape sequence for foreground color.
+    #[must_use]
+    pub fn fg_ansi(self) -> String {
+        format!("\x1b[38;2;{};{};{}m", self.r, self.g, self.b)
+    }
+
+    /// Returns the ANSI escape sequence for background color.
+    #[must_use]
+    pub fn bg_ansi(self) -> String {
+        format!("\x1b[48;2;{};{};{}m", self.r, self.g, self.b)
+    }
+
+    /// ANSI reset sequence.
+    pub const RESET: &'static str = "\x1b[0m";
+
+    #[must_use]
+    pub const fn white() -> Self {
+        Self::new(255, 255, 255)
+    }
+
+    #[must_use]
+    pub const fn green() -> Self {
+        Self::new(80, 250, 123)
+    }
+
+    #[must_use]
+    pub const fn yellow() -> Self {
+        Self::new(241, 250, 140)
+    }
+
+    #[must_use]
+    pub const fn cyan() -> Self {
+        Self::new(139, 233, 253)
+    }
+
+    #[must_use]
+    pub const fn red() -> Self {
+        Self::new(255, 85, 85)
+    }
+
+    #[must_use]
+    pub const fn purple() -> Self {
+        Self::new(189, 147, 249)
+    }
+
+    #[must_use]
+    pub const fn pink() -> Self {
+        Self::new(255, 121, 198)
+    }
+
+    #[must_use]
+    pub const fn orange() -> Self {
+        Self::new(255, 184, 108)
+    }
+
+    #[must_use]
+    pub const fn blue() -> Self {
+        Self::new(98, 114, 164)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Error returned when parsing an invalid color string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorParseError(String);
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid color: '{}' (expected #rrggbb, #rgb, a color name, or rgb(r, g, b))",
+            self.0
+        )
+    }
+}
+
+impl core::error::Error for ColorParseError {}
+
+/// Colorize a string with foreground color.
+#[must_use]
+pub fn colorize(text: &str, color: Color) -> String {
+    let fg = color.fg_ansi();
+    let reset = Color::RESET;
+    format!("{fg}{text}{reset}")
+}
+
+/// Colorize a string with foreground and background colors.
+#[must_use]
+pub fn colorize_bg(text: &str, fg: Color, bg: Color) -> String {
+    let fg_code = fg.fg_ansi();
+    let bg_code = bg.bg_ansi();
+    let reset = Color::RESET;
+    format!("{fg_code}{bg_code}{text}{reset}")
+}
@@ -0,0 +1,149 @@
+//! Log level definitions.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+/// Most verbose level the `hl_*!` macros compile in.
+///
+/// Selected with the `max_level_*` Cargo features; when several are enabled
+/// the most restrictive wins. `None` (`max_level_off`) compiles every macro
+/// call away. Direct logger method calls like `Logger::trace` are not
+/// affected.
+pub const STATIC_MAX_LEVEL: Option<Level> = if cfg!(feature = "max_level_off") {
+    None
+} else if cfg!(feature = "max_level_error") {
+    Some(Level::Error)
+} else if cfg!(feature = "max_level_warn") {
+    Some(Level::Warn)
+} else if cfg!(feature = "max_level_info") {
+    Some(Level::Info)
+} else if cfg!(feature = "max_level_debug") {
+    Some(Level::Debug)
+} else {
+    Some(Level::Trace)
+};
+
+/// Log severity levels, ordered from most to least verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Level {
+    /// Fine-grained debugging information.
+    Trace = 0,
+    /// Debugging information.
+    Debug = 1,
+    /// Informational messages.
+    #[default]
+    Info = 2,
+    /// Warning messages.
+    Warn = 3,
+    /// Error messages.
+    Error = 4,
+}
+
+impl Level {
+    /// Returns the canonical lowercase name.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+
+    /// Returns whether this level survives [`STATIC_MAX_LEVEL`].
+    ///
+    /// Constant for a constant level, so the `hl_*!` macros fold to nothing
+    /// below the compiled maximum.
+    #[must_use]
+    pub const fn is_statically_enabled(self) -> bool {
+        match STATIC_MAX_LEVEL {
+            Some(max) => self as u8 >= max as u8,
+            None => false,
+        }
+    }
+
+    /// Parses a level from loosely formatted input, such as other tools' logs.
+    ///
+    /// Accepts everything [`FromStr`] does, plus the numbers `0`-`4` (trace
+    /// to error), the single letters `t`, `d`, `i`, `w` and `e`, `notice`
+    /// (info) and `crit`, `critical` or `fatal` (error). Case-insensitive;
+    /// surrounding whitespace is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseLevelError`] if the input matches none of these.
+    pub fn parse_lenient(s: &str) -> Result<Self, ParseLevelError> {
+        let trimmed = s.trim();
+        if let Ok(level) = trimmed.parse() {
+            return Ok(level);
+        }
+        match trimmed.to_lowercase().as_str() {
+            "0" | "t" => Ok(Self::Trace),
+            "1" | "d" => Ok(Self::Debug),
+            "2" | "i" | "notice" => Ok(Self::Info),
+            "3" | "w" => Ok(Self::Warn),
+            "4" | "e" | "crit" | "critical" | "fatal" => Ok(Self::Error),
+            _ => Err(ParseLevelError(s.to_string())),
+        }
+    }
+
+    /// Returns all levels in order of verbosity.
+    #[must_use]
+    pub const fn all() -> [Self; 5] {
+        [
+            Self::Trace,
+            Self::Debug,
+            Self::Info,
+            Self::Warn,
+            Self::Error,
+        ]
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an invalid level string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLevelError(String);
+
+impl fmt::Display for ParseLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown log level: '{}'", self.0)
+    }
+}
+
+impl core::error::Error for ParseLevelError {}
+
+impl From<u8> for Level {
+    fn from(v: u8) -> Self {
+        match v {
+            0 => Self::Trace,
+            1 => Self::Debug,
+            2 => Self::Info,
+            3 => Self::Warn,
+            _ => Self::Error,
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = ParseLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "trace" => Ok(Self::Trace),
+            "debug" => Ok(Self::Debug),
+            "info" => Ok(Self::Info),
+            "warn" | "warning" => Ok(Self::Warn),
+            "error" | "err" => Ok(Self::Error),
+            _ => Err(ParseLevelError(s.to_string())),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! `no_std` core of `hyprs-log`.
+//!
+//! Log levels, tag formatting, colors, display width and inline style tags,
+//! built on `core` and `alloc` only. `hyprs-log` re-exports everything here
+//! from its `level` and `fmt` modules.
+//!
+//! # Features
+//!
+//! - `std`: Enables [`Color::hashed`] and [`ColorMap`] for `HashMap`
+//! - `max_level_*`: Compile-time level ceiling, see [`STATIC_MAX_LEVEL`]
+
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod color;
+pub mod level;
+pub mod style;
+mod tag;
+mod width;
+
+pub use color::{Color, ColorParseError, colorize, colorize_bg};
+pub use level::{Level, ParseLevelError, STATIC_MAX_LEVEL};
+pub use style::{ColorMap, Segment, parse, render, render_plain, strip_tags};
+pub use tag::{Alignment, TagConfig, Transform};
+pub use width::{WrapMode, display_width, pad, skip_escape, truncate, wrap};
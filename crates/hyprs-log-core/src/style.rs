@@ -1,12 +1,43 @@
 //! Inline message styling with XML-like tags.
 //!
-//! Supports tags like `<bold>text</bold>` and `<red>text</red>`.
-
-use super::Color;
+//! Supports tags like `<bold>text</bold>`, `<red>text</red>` and
+//! `<bg:red>text</bg:red>`. Tags nest: styled content is parsed again.
+
+use crate::color::Color;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
 
+/// Named colors for [`render`], looked up by tag name.
+///
+/// Implemented for `BTreeMap<String, Color>` and, with the `std` feature,
+/// for `HashMap<String, Color>`.
+pub trait ColorMap {
+    /// Returns the color registered under `name`.
+    fn color(&self, name: &str) -> Option<Color>;
+}
+
+impl ColorMap for BTreeMap<String, Color> {
+    fn color(&self, name: &str) -> Option<Color> {
+        self.get(name).copied()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: core::hash::BuildHasher> ColorMap for HashMap<String, Color, S> {
+    fn color(&self, name: &str) -> Option<Color> {
+        self.get(name).copied()
+    }
+}
+
 /// A styled segment of text.
+///
+/// New styles may be added, so matches outside this crate need a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Segment {
     /// Plain text without styling.
     Plain(String),
@@ -20,6 +51,8 @@ pub enum Segment {
     Underline(String),
     /// Colored text (named color or hex).
     Colored(String, String),
+    /// Text on a background color (named color or hex).
+    Background(String, String),
 }
 
 impl Segment {
@@ -32,41 +65,60 @@ impl Segment {
             | Self::Dim(t)
             | Self::Italic(t)
             | Self::Underline(t)
-            | Self::Colored(t, _) => t,
+            | Self::Colored(t, _)
+            | Self::Background(t, _) => t,
         }
     }
 
     /// Renders the segment with ANSI escape codes.
     #[must_use]
-    pub fn render(&self, colors: &HashMap<String, Color>) -> String {
+    pub fn render(&self, colors: &impl ColorMap) -> String {
+        if let Self::Plain(t) = self {
+            return t.clone();
+        }
+
+        // Styled content may hold nested tags
+        let t = render(&parse(self.text()), colors);
         match self {
-            Self::Plain(t) => t.clone(),
-            Self::Bold(t) => format!("\x1b[1m{t}\x1b[0m"),
-            Self::Dim(t) => format!("\x1b[2m{t}\x1b[0m"),
-            Self::Italic(t) => format!("\x1b[3m{t}\x1b[0m"),
-            Self::Underline(t) => format!("\x1b[4m{t}\x1b[0m"),
-            Self::Colored(t, name) => {
-                let color = if name.starts_with('#') {
-                    Color::from_hex(name)
-                } else {
-                    colors.get(name).copied().unwrap_or(Color::white())
-                };
-                let fg = color.fg_ansi();
+            Self::Bold(_) => format!("\x1b[1m{t}\x1b[0m"),
+            Self::Dim(_) => format!("\x1b[2m{t}\x1b[0m"),
+            Self::Italic(_) => format!("\x1b[3m{t}\x1b[0m"),
+            Self::Underline(_) => format!("\x1b[4m{t}\x1b[0m"),
+            Self::Colored(_, name) => {
+                let fg = resolve_color(name, colors).fg_ansi();
                 format!("{fg}{t}\x1b[0m")
             }
+            // SGR 49 resets only the background, keeping an enclosing fg color
+            Self::Background(_, name) => {
+                let bg = resolve_color(name, colors).bg_ansi();
+                format!("{bg}{t}\x1b[49m")
+            }
+            Self::Plain(_) => t,
         }
     }
 
     /// Returns plain text without any ANSI codes.
     #[must_use]
     pub fn render_plain(&self) -> String {
-        self.text().to_string()
+        match self {
+            Self::Plain(t) => t.clone(),
+            _ => strip_tags(self.text()),
+        }
     }
 }
 
+/// Looks up a color name in `colors`, then as a literal color, else white.
+fn resolve_color(name: &str, colors: &impl ColorMap) -> Color {
+    colors
+        .color(name)
+        .or_else(|| Color::parse(name).ok())
+        .unwrap_or(Color::white())
+}
+
 /// Parses a message string into styled segments.
 ///
-/// Supports: `<bold>`, `<dim>`, `<italic>`, `<underline>`, and any color name.
+/// Supports: `<bold>`, `<dim>`, `<italic>`, `<underline>`, any color name, and
+/// background colors as `<bg:NAME>` or `<on-NAME>`.
 #[must_use]
 pub fn parse(msg: &str) -> Vec<Segment> {
     let mut segments = Vec::new();
@@ -97,7 +149,10 @@ pub fn parse(msg: &str) -> Vec<Segment> {
                         "dim" => Segment::Dim(content.to_string()),
                         "italic" | "i" => Segment::Italic(content.to_string()),
                         "underline" | "u" => Segment::Underline(content.to_string()),
-                        _ => Segment::Colored(content.to_string(), tag_name.to_string()),
+                        _ => background_name(tag_name).map_or_else(
+                            || Segment::Colored(content.to_string(), tag_name.to_string()),
+                            |name| Segment::Background(content.to_string(), name.to_string()),
+                        ),
                     };
 
                     segments.push(segment);
@@ -107,8 +162,9 @@ pub fn parse(msg: &str) -> Vec<Segment> {
             }
         }
 
-        // Find next < or end of string
-        let next_tag = find_char(bytes, i, b'<').unwrap_or(bytes.len());
+        // Find next < or end of string; an unmatched < here is plain text
+        let search_from = if bytes[i] == b'<' { i + 1 } else { i };
+        let next_tag = find_char(bytes, search_from, b'<').unwrap_or(bytes.len());
         if next_tag > i {
             segments.push(Segment::Plain(msg[i..next_tag].to_string()));
         }
@@ -118,6 +174,14 @@ pub fn parse(msg: &str) -> Vec<Segment> {
     segments
 }
 
+/// Returns the color of a `bg:NAME` or `on-NAME` tag.
+fn background_name(tag_name: &str) -> Option<&str> {
+    tag_name
+        .strip_prefix("bg:")
+        .or_else(|| tag_name.strip_prefix("on-"))
+        .filter(|name| !name.is_empty())
+}
+
 fn find_char(bytes: &[u8], start: usize, c: u8) -> Option<usize> {
     bytes[start..]
         .iter()
@@ -127,8 +191,7 @@ fn find_char(bytes: &[u8], start: usize, c: u8) -> Option<usize> {
 
 /// Renders parsed segments to a styled string.
 #[must_use]
-#[allow(clippy::implicit_hasher)]
-pub fn render(segments: &[Segment], colors: &HashMap<String, Color>) -> String {
+pub fn render(segments: &[Segment], colors: &impl ColorMap) -> String {
     segments.iter().map(|s| s.render(colors)).collect()
 }
 
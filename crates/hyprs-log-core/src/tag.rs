@@ -1,7 +1,10 @@
 //! Tag formatting for log levels.
 
 use crate::level::Level;
-use std::collections::HashMap;
+use crate::width;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::{String, ToString};
 
 /// Text transformation for tags.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -62,7 +65,9 @@ pub struct TagConfig {
     /// Text alignment within `min_width`.
     pub alignment: Alignment,
     /// Custom labels per level (overrides default level name).
-    pub labels: HashMap<Level, String>,
+    pub labels: BTreeMap<Level, String>,
+    /// Levels whose tag renders empty, see [`TagConfig::hide_for`].
+    pub hidden: BTreeSet<Level>,
 }
 
 impl Default for TagConfig {
@@ -73,7 +78,8 @@ impl Default for TagConfig {
             transform: Transform::Uppercase,
             min_width: 5,
             alignment: Alignment::Center,
-            labels: HashMap::new(),
+            labels: BTreeMap::new(),
+            hidden: BTreeSet::new(),
         }
     }
 }
@@ -127,21 +133,38 @@ impl TagConfig {
         self
     }
 
+    /// Hides the tag for a level.
+    ///
+    /// `{tag}` then expands to an empty string, so `{tag?}` also drops the
+    /// whitespace after it. Explicit label overrides still render.
+    #[must_use]
+    pub fn hide_for(mut self, level: Level) -> Self {
+        self.hidden.insert(level);
+        self
+    }
+
     /// Formats a tag for the given level.
+    ///
+    /// Returns an empty string for levels hidden with [`hide_for`](Self::hide_for).
     #[must_use]
     pub fn format(&self, level: Level) -> String {
+        if self.hidden.contains(&level) {
+            return String::new();
+        }
+
         let label = self
             .labels
             .get(&level)
             .map_or_else(|| level.as_str(), String::as_str);
 
-        let transformed = self.transform.apply(label);
-        let padded = self.pad(&transformed);
-
-        format!("{}{}{}", self.prefix, padded, self.suffix)
+        self.format_with_label(level, label)
     }
 
     /// Formats a tag with a custom label, ignoring the level's default label.
+    ///
+    /// The transform applies to custom labels too. Labels longer than
+    /// `min_width` are rendered in full, never clipped; shorter ones are
+    /// padded according to `alignment`.
     #[must_use]
     pub fn format_with_label(&self, _level: Level, label: &str) -> String {
         let transformed = self.transform.apply(label);
@@ -150,20 +173,6 @@ impl TagConfig {
     }
 
     fn pad(&self, s: &str) -> String {
-        let len = s.chars().count();
-        if len >= self.min_width {
-            return s.to_string();
-        }
-
-        let padding = self.min_width - len;
-        match self.alignment {
-            Alignment::Left => format!("{}{}", s, " ".repeat(padding)),
-            Alignment::Right => format!("{}{}", " ".repeat(padding), s),
-            Alignment::Center => {
-                let left = padding / 2;
-                let right = padding - left;
-                format!("{}{}{}", " ".repeat(left), s, " ".repeat(right))
-            }
-        }
+        width::pad(s, self.min_width, self.alignment)
     }
 }
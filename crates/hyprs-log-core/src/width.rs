@@ -0,0 +1,231 @@
+//! Display-width aware padding for column alignment.
+
+use crate::tag::Alignment;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use unicode_width::UnicodeWidthChar;
+
+/// Returns the number of terminal columns `s` occupies.
+///
+/// ANSI escape sequences count as zero columns; wide characters (CJK, most
+/// emoji) count as two.
+#[must_use]
+pub fn display_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            skip_escape(&mut chars);
+            continue;
+        }
+        width += c.width().unwrap_or(0);
+    }
+
+    width
+}
+
+/// Consumes the rest of an escape sequence (CSI `ESC [ ... final` or OSC
+/// `ESC ] ... BEL/ST`) whose `ESC` was just read from `chars`.
+pub fn skip_escape(chars: &mut core::str::Chars<'_>) {
+    match chars.next() {
+        Some('[') => {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+        Some(']') => {
+            while let Some(c) = chars.next() {
+                if c == '\x07' || (c == '\x1b' && chars.next() == Some('\\')) {
+                    break;
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Pads `s` with spaces to `min_width` display columns.
+///
+/// Strings already at least `min_width` wide are returned unchanged.
+#[must_use]
+pub fn pad(s: &str, min_width: usize, alignment: Alignment) -> String {
+    let width = display_width(s);
+    if width >= min_width {
+        return s.to_string();
+    }
+
+    let padding = min_width - width;
+    match alignment {
+        Alignment::Left => format!("{}{}", s, " ".repeat(padding)),
+        Alignment::Right => format!("{}{}", " ".repeat(padding), s),
+        Alignment::Center => {
+            let left = padding / 2;
+            let right = padding - left;
+            format!("{}{}{}", " ".repeat(left), s, " ".repeat(right))
+        }
+    }
+}
+
+/// Shortens `s` to at most `max_width` columns, ending it with `…`.
+///
+/// Strings that fit are returned unchanged. ANSI escapes are kept and count
+/// as zero columns; when styled text is cut, a reset (and the end of an open
+/// OSC 8 hyperlink) goes before the `…` so nothing leaks past it. A newline
+/// counts as one column, so multi-line messages are shortened too. A width
+/// of zero leaves nothing, not even the `…`.
+#[must_use]
+pub fn truncate(s: &str, max_width: usize) -> String {
+    if visible_columns(s) <= max_width {
+        return s.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    // Leave a column for the ellipsis
+    let budget = max_width.saturating_sub(1);
+    let mut out = String::new();
+    let mut width = 0;
+    let mut styled = false;
+    let mut in_link = false;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            let rest = chars.as_str();
+            skip_escape(&mut chars);
+            let sequence = &rest[..rest.len() - chars.as_str().len()];
+            if let Some(target) = sequence.strip_prefix("]8;;") {
+                in_link = !target.trim_end_matches(['\x07', '\x1b', '\\']).is_empty();
+            } else {
+                styled = true;
+            }
+            out.push(c);
+            out.push_str(sequence);
+            continue;
+        }
+
+        let char_width = columns(c);
+        if width + char_width > budget {
+            break;
+        }
+        out.push(c);
+        width += char_width;
+    }
+
+    if in_link {
+        out.push_str("\x1b]8;;\x1b\\");
+    }
+    if styled {
+        out.push_str("\x1b[0m");
+    }
+    out.push('…');
+    out
+}
+
+/// Columns of `s` as [`truncate`] counts them.
+fn visible_columns(s: &str) -> usize {
+    let mut width = 0;
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            skip_escape(&mut chars);
+        } else {
+            width += columns(c);
+        }
+    }
+    width
+}
+
+/// Display columns of a character, counting a newline as one.
+fn columns(c: char) -> usize {
+    if c == '\n' { 1 } else { c.width().unwrap_or(0) }
+}
+
+/// How long lines are broken to fit a width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WrapMode {
+    /// No wrapping.
+    #[default]
+    Off,
+    /// Break between words; words wider than the line are split.
+    Word,
+    /// Break at any character.
+    Char,
+}
+
+/// Splits `s` into lines of at most `width` display columns.
+///
+/// ANSI escapes are kept but count as zero columns. Existing newlines are not
+/// treated specially; split on them first. `WrapMode::Off` or a zero width
+/// returns `s` unchanged.
+#[must_use]
+pub fn wrap(s: &str, width: usize, mode: WrapMode) -> Vec<String> {
+    match mode {
+        _ if width == 0 => vec![s.to_string()],
+        WrapMode::Off => vec![s.to_string()],
+        WrapMode::Char => wrap_chars(s, width),
+        WrapMode::Word => wrap_words(s, width),
+    }
+}
+
+/// Greedy word wrap, hard-breaking words that don't fit on a line of their own.
+fn wrap_words(s: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+
+    for word in s.split(' ') {
+        let word_width = display_width(word);
+        if !line.is_empty() {
+            if line_width + 1 + word_width <= width {
+                line.push(' ');
+                line.push_str(word);
+                line_width += 1 + word_width;
+                continue;
+            }
+            lines.push(core::mem::take(&mut line));
+        }
+
+        let mut pieces = wrap_chars(word, width);
+        line = pieces.pop().unwrap_or_default();
+        line_width = display_width(&line);
+        lines.extend(pieces);
+    }
+
+    lines.push(line);
+    lines
+}
+
+/// Breaks at the last character that fits, keeping escapes with the text after them.
+fn wrap_chars(s: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            let rest = chars.as_str();
+            skip_escape(&mut chars);
+            line.push(c);
+            line.push_str(&rest[..rest.len() - chars.as_str().len()]);
+            continue;
+        }
+
+        let char_width = c.width().unwrap_or(0);
+        if line_width > 0 && line_width + char_width > width {
+            lines.push(core::mem::take(&mut line));
+            line_width = 0;
+        }
+        line.push(c);
+        line_width += char_width;
+    }
+
+    lines.push(line);
+    lines
+}
@@ -0,0 +1,40 @@
+//! Uses the crate the way a `no_std` dependent would: `core` and `alloc` only.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use hyprs_log_core::{Color, Level, TagConfig, Transform, parse, render, strip_tags};
+
+#[test]
+fn tag_formats_without_std() {
+    let tag = TagConfig::new()
+        .transform(Transform::Capitalize)
+        .min_width(0)
+        .label(Level::Warn, "careful")
+        .hide_for(Level::Trace);
+
+    assert_eq!(tag.format(Level::Warn), "[Careful]");
+    assert_eq!(tag.format(Level::Error), "[Error]");
+    assert_eq!(tag.format(Level::Trace), "");
+}
+
+#[test]
+fn level_parses_without_std() {
+    assert_eq!("warning".parse::<Level>(), Ok(Level::Warn));
+    assert_eq!(Level::parse_lenient(" fatal "), Ok(Level::Error));
+    assert!("loud".parse::<Level>().is_err());
+}
+
+#[test]
+fn styles_render_with_btree_color_map() {
+    let mut colors = BTreeMap::new();
+    colors.insert(String::from("accent"), Color::new(1, 2, 3));
+
+    let rendered = render(&parse("<accent>hi</accent> <bold>there</bold>"), &colors);
+
+    assert_eq!(rendered, "\x1b[38;2;1;2;3mhi\x1b[0m \x1b[1mthere\x1b[0m");
+    assert_eq!(strip_tags("<accent>hi</accent>"), "hi");
+}
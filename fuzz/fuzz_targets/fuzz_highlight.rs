@@ -10,9 +10,11 @@ fuzz_target!(|data: &str| {
     config.patterns.paths = Some("green".to_string());
     config.patterns.numbers = Some("yellow".to_string());
     config.patterns.quoted = Some("orange".to_string());
-    config.keywords.insert("error".to_string(), "red".to_string());
-    config.keywords.insert("warning".to_string(), "yellow".to_string());
+    config.patterns.ip = Some("purple".to_string());
+    config.patterns.timestamp = Some("pink".to_string());
+    config.keywords.insert("error".to_string(), "red".to_string().into());
+    config.keywords.insert("warning".to_string(), "yellow".to_string().into());
 
-    // Must not panic; exercises 5 regexes + overlap logic
+    // Must not panic; exercises 8 regexes + overlap logic
     let _ = inject_tags(data, &config);
 });
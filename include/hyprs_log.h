@@ -109,9 +109,12 @@ void hyprlog_free(struct HyprlogContext *ctx);
 /**
  * Logs a message at the specified level.
  *
+ * Invalid UTF-8 in `scope` or `msg` is replaced with U+FFFD and the message
+ * is still logged; `hyprlog_get_last_error` then reports the lossy conversion.
+ *
  * # Safety
  * - `ctx` must be a valid context pointer
- * - `scope` and `msg` must be valid null-terminated UTF-8 strings
+ * - `scope` and `msg` must be valid null-terminated strings
  *
  * # Arguments
  * * `ctx` - Logger context
@@ -121,6 +124,25 @@ void hyprlog_free(struct HyprlogContext *ctx);
  */
 void hyprlog_log(struct HyprlogContext *ctx, int level, const char *scope, const char *msg);
 
+/**
+ * Logs `len` bytes of `msg` at the specified level.
+ *
+ * Unlike `hyprlog_log`, `msg` need not be null-terminated: exactly `len`
+ * bytes are read, so slices of larger buffers and embedded NUL bytes are
+ * logged as given. `scope` is still a null-terminated string. Invalid UTF-8
+ * is handled as in `hyprlog_log`.
+ *
+ * # Safety
+ * - `ctx` must be a valid context pointer
+ * - `scope` must be a valid null-terminated string
+ * - `msg` must point to at least `len` readable bytes
+ */
+void hyprlog_log_n(struct HyprlogContext *ctx,
+                   int level,
+                   const char *scope,
+                   const char *msg,
+                   size_t len);
+
 /**
  * Logs a trace message.
  *
@@ -186,6 +208,20 @@ int hyprlog_get_last_error(struct HyprlogContext *ctx, char *buffer, size_t len)
  */
 int hyprlog_flush(struct HyprlogContext *ctx);
 
+/**
+ * Enables or disables flushing all outputs after every log call.
+ *
+ * Use for crash-prone callers: lines reach disk before the next call returns,
+ * at the cost of throughput. Off by default.
+ *
+ * # Safety
+ * `ctx` must be a valid context pointer.
+ *
+ * # Returns
+ * 0 on success, -1 if `ctx` is `NULL`.
+ */
+int hyprlog_set_autoflush(struct HyprlogContext *ctx, int enabled);
+
 #ifdef __cplusplus
 }  // extern "C"
 #endif  // __cplusplus
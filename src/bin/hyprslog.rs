@@ -8,33 +8,44 @@
 //!   hyprslog preset <name>                Run a preset
 //!   hyprslog presets                      List presets
 //!   hyprslog stats                        Show statistics
+//!   hyprslog query [options] [<file>]     Search the JSON output file
 //!   hyprslog cleanup [options]            Clean up logs
 //!   hyprslog help                         Show help
 
-#[cfg(feature = "hyprland")]
-use hyprs_log::cli::cmd_watch;
 #[cfg(feature = "rserver")]
 use hyprs_log::cli::cmd_server;
-use hyprs_log::cli::{build_logger, parse_level, print_help};
+#[cfg(feature = "hyprland")]
+use hyprs_log::cli::cmd_watch;
+use hyprs_log::cli::{apply_color_choice, build_logger, parse_level, print_help, take_color_flag};
 use hyprs_log::cli::{
-    cmd_cleanup, cmd_json, cmd_log, cmd_log_shorthand, cmd_preset, cmd_presets, cmd_send,
-    cmd_stats, cmd_themes,
+    cmd_cleanup, cmd_config, cmd_export, cmd_json, cmd_log, cmd_log_shorthand, cmd_preset,
+    cmd_presets, cmd_query, cmd_send, cmd_show, cmd_stats, cmd_themes,
 };
 use hyprs_log::config::Config;
 use hyprs_log::internal;
 use std::process::ExitCode;
 
 fn main() -> ExitCode {
-    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let color = match take_color_flag(&mut args) {
+        Ok(color) => color,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
 
     // Load config
-    let config = match Config::load() {
+    let mut config = match Config::load() {
         Ok(c) => c,
         Err(e) => {
             eprintln!("Error loading config: {e}");
             return ExitCode::FAILURE;
         }
     };
+    if let Some(color) = color {
+        apply_color_choice(&mut config, color);
+    }
 
     // Init internal logging with config
     internal::init_with_config(&config);
@@ -63,11 +74,15 @@ fn main() -> ExitCode {
             println!("hyprslog {}", env!("CARGO_PKG_VERSION"));
             ExitCode::SUCCESS
         }
-        "log" => cmd_log(&args_str[1..], &logger),
-        "json" => cmd_json(args_str.get(1).copied(), &logger),
+        "log" => cmd_log(&args_str[1..], &config, &logger),
+        "json" => cmd_json(&args_str[1..], &config, &logger),
         "preset" => cmd_preset(&args_str[1..], &config, &logger),
         "presets" => cmd_presets(&config, &logger),
         "stats" => cmd_stats(&args_str[1..], &config, &logger),
+        "config" => cmd_config(&args_str[1..], &config),
+        "show" => cmd_show(&args_str[1..], &config),
+        "query" => cmd_query(&args_str[1..], &config),
+        "export" => cmd_export(&args_str[1..]),
         "cleanup" => cmd_cleanup(&args_str[1..], &config, &logger),
         "themes" => cmd_themes(&args_str[1..], &logger),
         #[cfg(feature = "hyprland")]
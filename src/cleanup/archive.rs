@@ -0,0 +1,72 @@
+//! Moving log files into an archive directory.
+
+use crate::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Returns where `path` lands under `archive_dir`, mirroring its subpath below `base_dir`.
+pub(super) fn archive_path(path: &Path, base_dir: &Path, archive_dir: &Path) -> PathBuf {
+    let relative = path
+        .strip_prefix(base_dir)
+        .unwrap_or_else(|_| path.file_name().map_or(path, Path::new));
+    archive_dir.join(relative)
+}
+
+/// Moves a file into the archive directory, creating parent directories as needed.
+///
+/// Returns the destination path.
+pub(super) fn archive_file(
+    path: &Path,
+    base_dir: &Path,
+    archive_dir: &Path,
+) -> Result<PathBuf, Error> {
+    let dest = archive_path(path, base_dir, archive_dir);
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    move_file(path, &dest)?;
+    Ok(dest)
+}
+
+/// Renames a file, falling back to copy + remove when crossing filesystems.
+fn move_file(src: &Path, dst: &Path) -> io::Result<()> {
+    match fs::rename(src, dst) {
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => copy_and_remove(src, dst),
+        other => other,
+    }
+}
+
+/// Copies a file and removes the original once the copy succeeded.
+fn copy_and_remove(src: &Path, dst: &Path) -> io::Result<()> {
+    fs::copy(src, dst)?;
+    fs::remove_file(src)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn archive_path_mirrors_subpath() {
+        let dest = archive_path(
+            Path::new("/logs/app/2024/app.log"),
+            Path::new("/logs"),
+            Path::new("/cold"),
+        );
+        assert_eq!(dest, PathBuf::from("/cold/app/2024/app.log"));
+    }
+
+    #[test]
+    fn copy_fallback_moves_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("old.log");
+        let dst = dir.path().join("archived.log");
+        fs::write(&src, "content").unwrap();
+
+        copy_and_remove(&src, &dst).unwrap();
+
+        assert!(!src.exists());
+        assert_eq!(fs::read_to_string(&dst).unwrap(), "content");
+    }
+}
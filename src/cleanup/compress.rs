@@ -7,6 +7,24 @@ use std::fs::{self, File};
 use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::Path;
 
+/// Extensions of compressed logs.
+const COMPRESSED_EXTENSIONS: &[&str] = &["gz", "zst"];
+
+/// Returns whether `path` is compressed or has a compressed copy beside it.
+///
+/// Compressing such a file again would double-compress it or overwrite the
+/// existing archive.
+pub(super) fn already_compressed(path: &Path) -> bool {
+    let is_compressed = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| COMPRESSED_EXTENSIONS.contains(&e));
+    is_compressed
+        || COMPRESSED_EXTENSIONS
+            .iter()
+            .any(|ext| Path::new(&format!("{}.{ext}", path.display())).exists())
+}
+
 /// Compresses a file using gzip.
 ///
 /// Returns the bytes saved (original size - compressed size).
@@ -7,88 +7,153 @@ use std::fs;
 use std::path::Path;
 use std::time::SystemTime;
 
-/// Collects all log files from the directory.
+/// Collects all files with one of `extensions` from the directory.
+///
+/// Descends at most `max_depth` directory levels below `dir`; deeper
+/// directories are skipped with a warning.
 pub(super) fn collect_log_files(
     dir: &Path,
     now: SystemTime,
     app_filter: Option<&str>,
+    extensions: &[&str],
+    max_depth: usize,
 ) -> Result<Vec<LogFileInfo>, crate::Error> {
     internal::debug(
         "CLEANUP",
         &format!("Collecting log files from {}", dir.display()),
     );
-    let mut files = Vec::new();
-    let mut folders = HashSet::new();
-    collect_log_files_recursive(dir, now, app_filter, &mut files, &mut folders)?;
+    let mut collector = Collector {
+        now,
+        extensions,
+        max_depth,
+        files: Vec::new(),
+        folders: HashSet::new(),
+        too_deep: 0,
+    };
+    collector.visit(dir, app_filter, 0)?;
     internal::debug(
         "CLEANUP",
         &format!(
             "Found {} log files in {} folders",
-            files.len(),
-            folders.len()
+            collector.files.len(),
+            collector.folders.len()
         ),
     );
-    Ok(files)
+    if collector.too_deep > 0 {
+        internal::warn(
+            "CLEANUP",
+            &format!(
+                "Skipped {} directories more than {max_depth} levels below {}",
+                collector.too_deep,
+                dir.display()
+            ),
+        );
+    }
+    Ok(collector.files)
 }
 
-fn collect_log_files_recursive(
-    dir: &Path,
-    now: SystemTime,
-    app_filter: Option<&str>,
-    files: &mut Vec<LogFileInfo>,
-    folders: &mut HashSet<String>,
-) -> Result<(), crate::Error> {
-    if !dir.is_dir() {
-        return Ok(());
+/// Whole days between `modified` and `now`.
+///
+/// A modification time in the future (clock skew, bad metadata) counts as
+/// age 0, the same as a file written just now.
+fn age_days(path: &Path, modified: SystemTime, now: SystemTime) -> u64 {
+    match now.duration_since(modified) {
+        Ok(age) => age.as_secs() / 86400,
+        Err(e) => {
+            internal::trace(
+                "CLEANUP",
+                &format!(
+                    "Modification time {}s in the future, treating as age 0: {}",
+                    e.duration().as_secs(),
+                    path.display()
+                ),
+            );
+            0
+        }
     }
+}
 
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
+/// State for one recursive walk.
+struct Collector<'a> {
+    now: SystemTime,
+    extensions: &'a [&'a str],
+    max_depth: usize,
+    files: Vec<LogFileInfo>,
+    folders: HashSet<String>,
+    /// Directories not entered because of `max_depth`.
+    too_deep: usize,
+}
 
-        if path.is_dir() {
-            if let Some(app) = app_filter {
-                let dir_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-                if dir_name == app {
-                    // Found app dir, collect all files within
-                    collect_log_files_recursive(&path, now, None, files, folders)?;
-                } else {
-                    // Keep searching
-                    collect_log_files_recursive(&path, now, app_filter, files, folders)?;
+impl Collector<'_> {
+    /// Collects from `dir`, which is `depth` levels below the base directory.
+    fn visit(
+        &mut self,
+        dir: &Path,
+        app_filter: Option<&str>,
+        depth: usize,
+    ) -> Result<(), crate::Error> {
+        if !dir.is_dir() {
+            return Ok(());
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+
+            if path.is_dir() {
+                if depth >= self.max_depth {
+                    internal::trace(
+                        "CLEANUP",
+                        &format!("Too deep, not descending: {}", path.display()),
+                    );
+                    self.too_deep += 1;
+                    continue;
                 }
-            } else {
-                collect_log_files_recursive(&path, now, None, files, folders)?;
-            }
-        } else if app_filter.is_none()
-            && path.extension().is_some_and(|e| e == "log")
-            && let Ok(meta) = fs::metadata(&path)
-        {
-            let size = meta.len();
-            let modified = meta.modified().ok();
-            let age_days = modified
-                .and_then(|m| now.duration_since(m).ok())
-                .map_or(0, |d| d.as_secs() / 86400);
+                let dir_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                // Once the app dir is found, collect all files within
+                let filter = app_filter.filter(|&app| dir_name != app);
+                self.visit(&path, filter, depth + 1)?;
+            } else if app_filter.is_none()
+                && path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| self.extensions.contains(&e))
+                && let Ok(meta) = fs::metadata(&path)
+            {
+                let size = meta.len();
+                let modified = meta.modified().ok();
+                let age_days = modified.map_or(0, |m| age_days(&path, m, self.now));
 
-            let modified_date = modified.and_then(|m| {
-                let duration = m.duration_since(std::time::UNIX_EPOCH).ok()?;
-                let timestamp = i64::try_from(duration.as_secs()).ok()?;
-                chrono::DateTime::from_timestamp(timestamp, 0).map(|dt| dt.naive_utc().date())
-            });
+                let modified_date = modified.and_then(|m| {
+                    let duration = m.duration_since(std::time::UNIX_EPOCH).ok()?;
+                    let timestamp = i64::try_from(duration.as_secs()).ok()?;
+                    chrono::DateTime::from_timestamp(timestamp, 0).map(|dt| dt.naive_utc().date())
+                });
 
-            // Track parent folder
-            if let Some(parent) = path.parent() {
-                folders.insert(parent.display().to_string());
-            }
+                // Track parent folder
+                if let Some(parent) = path.parent() {
+                    self.folders.insert(parent.display().to_string());
+                }
 
-            internal::trace("CLEANUP", &format!("Found: {}", path.display()));
-            files.push(LogFileInfo {
-                path: path.display().to_string(),
-                size,
-                age_days,
-                modified_date,
-            });
+                internal::trace("CLEANUP", &format!("Found: {}", path.display()));
+                self.files.push(LogFileInfo {
+                    path: path.display().to_string(),
+                    size,
+                    age_days,
+                    modified_date,
+                });
+            }
         }
+
+        Ok(())
     }
+}
 
-    Ok(())
+/// Returns the app a log file belongs to: its parent directory name.
+pub(super) fn app_segment(path: &str) -> &str {
+    Path::new(path)
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|n| n.to_str())
+        .unwrap_or("")
 }
@@ -1,5 +1,6 @@
 //! Log file cleanup and statistics.
 
+mod archive;
 mod compress;
 mod files;
 mod options;
@@ -9,12 +10,14 @@ mod stats;
 
 pub use options::CleanupOptions;
 pub use result::CleanupResult;
-pub use size::{format_size, parse_size};
+pub use size::{SizeUnit, format_size, format_size_with, parse_size};
 pub use stats::{LogFileInfo, LogStats};
 
 use crate::internal;
-use compress::{cleanup_empty_dirs, compress_file};
-use files::collect_log_files;
+use archive::{archive_file, archive_path};
+use compress::{already_compressed, cleanup_empty_dirs, compress_file};
+use files::{app_segment, collect_log_files};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::time::SystemTime;
@@ -23,8 +26,27 @@ use std::time::SystemTime;
 ///
 /// # Errors
 /// Returns error if cleanup fails.
-#[allow(clippy::too_many_lines)]
 pub fn cleanup(base_dir: &Path, options: &CleanupOptions) -> Result<CleanupResult, crate::Error> {
+    cleanup_with_progress(base_dir, options, &mut |_, _| {})
+}
+
+/// Performs cleanup on log files, reporting progress as `(processed, total)`.
+///
+/// `progress` is called after each collected file is checked against the
+/// options, so `processed` climbs from 1 to `total`. It is not called when
+/// there are no files.
+///
+/// Only this per-file pass is reported. The `max_total_size` pass that follows
+/// it removes the oldest remaining files without further progress calls.
+///
+/// # Errors
+/// Returns error if cleanup fails.
+#[allow(clippy::too_many_lines)]
+pub fn cleanup_with_progress(
+    base_dir: &Path,
+    options: &CleanupOptions,
+    progress: &mut dyn FnMut(usize, usize),
+) -> Result<CleanupResult, crate::Error> {
     internal::info(
         "CLEANUP",
         &format!("Starting cleanup in {}", base_dir.display()),
@@ -32,11 +54,17 @@ pub fn cleanup(base_dir: &Path, options: &CleanupOptions) -> Result<CleanupResul
     internal::debug(
         "CLEANUP",
         &format!(
-            "Options: delete_all={}, dry_run={}, compress={}",
-            options.delete_all, options.dry_run, options.compress
+            "Options: delete_all={}, dry_run={}, compress={}, archive_to={:?}",
+            options.delete_all, options.dry_run, options.compress, options.archive_to
         ),
     );
 
+    if options.compress && options.archive_to.is_some() {
+        return Err(crate::Error::InvalidOptions(
+            "compress and archive_to are mutually exclusive".to_string(),
+        ));
+    }
+
     let mut result = CleanupResult::default();
     let now = SystemTime::now();
 
@@ -46,7 +74,14 @@ pub fn cleanup(base_dir: &Path, options: &CleanupOptions) -> Result<CleanupResul
     }
 
     // Collect all log files
-    let mut files = collect_log_files(base_dir, now, options.app_filter.as_deref())?;
+    let max_depth = options.max_depth.unwrap_or(DEFAULT_MAX_DEPTH);
+    let mut files = collect_log_files(
+        base_dir,
+        now,
+        options.app_filter.as_deref(),
+        &["log"],
+        max_depth,
+    )?;
 
     // Sort by age (oldest first for deletion, newest first for keep_last)
     files.sort_by_key(|f| std::cmp::Reverse(f.age_days));
@@ -65,10 +100,33 @@ pub fn cleanup(base_dir: &Path, options: &CleanupOptions) -> Result<CleanupResul
         }
     }
 
+    // Apply keep_last_per_app: protect the N most recent files of each app
+    if let Some(keep_n) = options.keep_last_per_app {
+        internal::debug(
+            "CLEANUP",
+            &format!("Protecting {keep_n} most recent files per app"),
+        );
+        let mut by_app: HashMap<&str, Vec<&LogFileInfo>> = HashMap::new();
+        for file in &files {
+            by_app
+                .entry(app_segment(&file.path))
+                .or_default()
+                .push(file);
+        }
+        for group in by_app.values_mut() {
+            group.sort_by_key(|f| f.age_days);
+            for file in group.iter().take(keep_n) {
+                protected_paths.insert(file.path.clone());
+            }
+        }
+    }
+
     // Determine which files should be processed
-    for file in &files {
+    let total = files.len();
+    for (index, file) in files.iter().enumerate() {
         // Skip protected files
         if protected_paths.contains(&file.path) {
+            progress(index + 1, total);
             continue;
         }
 
@@ -94,7 +152,13 @@ pub fn cleanup(base_dir: &Path, options: &CleanupOptions) -> Result<CleanupResul
         if should_process {
             if options.compress {
                 // Compress instead of delete
-                if options.dry_run {
+                if already_compressed(Path::new(&file.path)) {
+                    internal::debug(
+                        "CLEANUP",
+                        &format!("Already compressed, skipping: {}", file.path),
+                    );
+                    result.skipped.push(file.path.clone());
+                } else if options.dry_run {
                     result.would_compress.push(file.path.clone());
                     // Estimate ~50% compression ratio for text logs
                     result.would_compress_save += file.size / 2;
@@ -126,22 +190,13 @@ pub fn cleanup(base_dir: &Path, options: &CleanupOptions) -> Result<CleanupResul
                     }
                 }
             } else {
-                // Delete
-                if options.dry_run {
-                    result.would_delete.push(file.path.clone());
-                    result.would_free += file.size;
-                } else {
-                    internal::debug("CLEANUP", &format!("Deleting: {}", file.path));
-                    if fs::remove_file(&file.path).is_ok() {
-                        result.deleted.push(file.path.clone());
-                        result.freed += file.size;
-                    }
-                }
+                discard(file, base_dir, options, &mut result);
             }
         }
+        progress(index + 1, total);
     }
 
-    // Delete by size limit (only applies to deletion, not compression)
+    // Delete (or archive) by size limit; not applied when compressing
     if !options.compress
         && let Some(limit) = options.max_total_size
     {
@@ -150,25 +205,21 @@ pub fn cleanup(base_dir: &Path, options: &CleanupOptions) -> Result<CleanupResul
             .filter(|f| {
                 !result.deleted.contains(&f.path)
                     && !result.would_delete.contains(&f.path)
+                    && !result.moved.iter().any(|(from, _)| *from == f.path)
+                    && !result.would_move.iter().any(|(from, _)| *from == f.path)
                     && !protected_paths.contains(&f.path)
             })
             .collect();
 
-        let mut total: u64 = remaining.iter().map(|f| f.size).sum();
+        let mut remaining_size: u64 = remaining.iter().map(|f| f.size).sum();
 
         // Delete oldest files until under limit
         for file in remaining.iter().rev() {
-            if total <= limit {
+            if remaining_size <= limit {
                 break;
             }
-            if options.dry_run {
-                result.would_delete.push(file.path.clone());
-                result.would_free += file.size;
-            } else if fs::remove_file(&file.path).is_ok() {
-                result.deleted.push(file.path.clone());
-                result.freed += file.size;
-            }
-            total = total.saturating_sub(file.size);
+            discard(file, base_dir, options, &mut result);
+            remaining_size = remaining_size.saturating_sub(file.size);
         }
     }
 
@@ -177,7 +228,7 @@ pub fn cleanup(base_dir: &Path, options: &CleanupOptions) -> Result<CleanupResul
         cleanup_empty_dirs(base_dir)?;
     }
 
-    let total_count = result.count() + result.compressed_count();
+    let total_count = result.count() + result.compressed_count() + result.moved_count();
     let total_bytes = result.bytes() + result.compressed_bytes();
     internal::info(
         "CLEANUP",
@@ -187,11 +238,76 @@ pub fn cleanup(base_dir: &Path, options: &CleanupOptions) -> Result<CleanupResul
     Ok(result)
 }
 
+/// Deletes a file, or moves it under `archive_to` when set.
+fn discard(
+    file: &LogFileInfo,
+    base_dir: &Path,
+    options: &CleanupOptions,
+    result: &mut CleanupResult,
+) {
+    let path = Path::new(&file.path);
+    if let Some(archive_dir) = &options.archive_to {
+        if options.dry_run {
+            let dest = archive_path(path, base_dir, archive_dir);
+            result
+                .would_move
+                .push((file.path.clone(), dest.display().to_string()));
+            result.would_move_size += file.size;
+        } else {
+            internal::debug("CLEANUP", &format!("Moving: {}", file.path));
+            match archive_file(path, base_dir, archive_dir) {
+                Ok(dest) => {
+                    result
+                        .moved
+                        .push((file.path.clone(), dest.display().to_string()));
+                    result.moved_size += file.size;
+                }
+                Err(e) => {
+                    result.failed.push((file.path.clone(), e.to_string()));
+                }
+            }
+        }
+    } else if options.dry_run {
+        result.would_delete.push(file.path.clone());
+        result.would_free += file.size;
+    } else {
+        internal::debug("CLEANUP", &format!("Deleting: {}", file.path));
+        if fs::remove_file(path).is_ok() {
+            result.deleted.push(file.path.clone());
+            result.freed += file.size;
+        }
+    }
+}
+
+/// Directory levels below the base directory searched for log files.
+///
+/// Bounds the walk when the base directory is misconfigured (say, `$HOME`);
+/// override per cleanup with [`CleanupOptions::max_depth`].
+pub const DEFAULT_MAX_DEPTH: usize = 16;
+
+/// File extensions counted by [`stats`]: plain logs and compressed archives.
+pub const STATS_EXTENSIONS: &[&str] = &["log", "gz", "zst"];
+
 /// Gets statistics about log files.
 ///
+/// Counts files matching [`STATS_EXTENSIONS`], so compressed logs still show
+/// up in the total size.
+///
 /// # Errors
 /// Returns error if stats cannot be collected.
 pub fn stats(base_dir: &Path, app_filter: Option<&str>) -> Result<LogStats, crate::Error> {
+    stats_with_extensions(base_dir, app_filter, STATS_EXTENSIONS)
+}
+
+/// Gets statistics about files with one of the given extensions (without the dot).
+///
+/// # Errors
+/// Returns error if stats cannot be collected.
+pub fn stats_with_extensions(
+    base_dir: &Path,
+    app_filter: Option<&str>,
+    extensions: &[&str],
+) -> Result<LogStats, crate::Error> {
     let mut stats = LogStats::default();
     let now = SystemTime::now();
 
@@ -199,7 +315,7 @@ pub fn stats(base_dir: &Path, app_filter: Option<&str>) -> Result<LogStats, crat
         return Ok(stats);
     }
 
-    let files = collect_log_files(base_dir, now, app_filter)?;
+    let files = collect_log_files(base_dir, now, app_filter, extensions, DEFAULT_MAX_DEPTH)?;
 
     stats.total_files = files.len();
     stats.total_size = files.iter().map(|f| f.size).sum();
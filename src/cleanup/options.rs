@@ -2,6 +2,7 @@
 
 use super::size::parse_size;
 use chrono::NaiveDate;
+use std::path::PathBuf;
 
 /// Options for cleanup operations.
 #[derive(Debug, Clone, Default)]
@@ -22,8 +23,14 @@ pub struct CleanupOptions {
     pub after_date: Option<NaiveDate>,
     /// Always keep the N most recent files.
     pub keep_last: Option<usize>,
+    /// Always keep the N most recent files of each app.
+    pub keep_last_per_app: Option<usize>,
     /// Compress files instead of deleting.
     pub compress: bool,
+    /// Move files into this directory instead of deleting (exclusive with `compress`).
+    pub archive_to: Option<PathBuf>,
+    /// Directory levels to descend (None = [`DEFAULT_MAX_DEPTH`](super::DEFAULT_MAX_DEPTH)).
+    pub max_depth: Option<usize>,
 }
 
 impl CleanupOptions {
@@ -96,10 +103,41 @@ impl CleanupOptions {
         self
     }
 
+    /// Sets keep last N files per app.
+    ///
+    /// Files are grouped by their parent directory name, the `{app}` segment
+    /// of the default `{year}/{month}/{app}` layout. Combines with
+    /// [`keep_last`](Self::keep_last): a file protected by either is kept.
+    #[must_use]
+    pub const fn keep_last_per_app(mut self, n: usize) -> Self {
+        self.keep_last_per_app = Some(n);
+        self
+    }
+
     /// Sets compress flag (compress instead of delete).
     #[must_use]
     pub const fn compress(mut self, compress: bool) -> Self {
         self.compress = compress;
         self
     }
+
+    /// Moves files into `dir` instead of deleting them.
+    ///
+    /// Files keep their path relative to the base directory. Cannot be
+    /// combined with [`compress`](Self::compress).
+    #[must_use]
+    pub fn archive_to(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.archive_to = Some(dir.into());
+        self
+    }
+
+    /// Sets how many directory levels below the base directory are searched.
+    ///
+    /// Files directly in the base directory are at level 0. Deeper
+    /// directories are skipped with a warning.
+    #[must_use]
+    pub const fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
 }
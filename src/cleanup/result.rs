@@ -22,8 +22,19 @@ pub struct CleanupResult {
     pub would_compress: Vec<String>,
     /// Bytes that would be saved (dry run estimate).
     pub would_compress_save: u64,
+    /// Files that were moved to the archive directory (source, destination).
+    pub moved: Vec<(String, String)>,
+    /// Bytes moved to the archive directory.
+    pub moved_size: u64,
+    /// Files that would be moved (dry run).
+    pub would_move: Vec<(String, String)>,
+    /// Bytes that would be moved (dry run).
+    pub would_move_size: u64,
     /// Files that failed to process (path, error message).
     pub failed: Vec<(String, String)>,
+    /// Files left alone because they are compressed or already have a
+    /// compressed copy next to them.
+    pub skipped: Vec<String>,
 }
 
 impl CleanupResult {
@@ -67,15 +78,73 @@ impl CleanupResult {
         }
     }
 
+    /// Returns the number of files moved to the archive directory.
+    #[must_use]
+    pub const fn moved_count(&self) -> usize {
+        if self.moved.is_empty() {
+            self.would_move.len()
+        } else {
+            self.moved.len()
+        }
+    }
+
+    /// Returns the bytes moved to the archive directory.
+    #[must_use]
+    pub const fn moved_bytes(&self) -> u64 {
+        if self.moved_size == 0 {
+            self.would_move_size
+        } else {
+            self.moved_size
+        }
+    }
+
+    /// Projects disk usage as `(before, after, freed)` given the current total.
+    ///
+    /// Freed bytes count deletions, moves to the archive and compression
+    /// savings (estimated in a dry run), so `after` reflects compressed sizes.
+    #[must_use]
+    pub const fn projection(&self, current_total: u64) -> (u64, u64, u64) {
+        let freed = self
+            .bytes()
+            .saturating_add(self.compressed_bytes())
+            .saturating_add(self.moved_bytes());
+        (current_total, current_total.saturating_sub(freed), freed)
+    }
+
+    /// Logs a one-line before/after summary of disk usage.
+    pub fn log_projection(&self, logger: &Logger, current_total: u64) {
+        let files = self.count() + self.compressed_count() + self.moved_count();
+        if files == 0 {
+            return;
+        }
+
+        let (before, after, freed) = self.projection(current_total);
+        logger.print(
+            "CLEANUP",
+            &format!(
+                "Current {} → after cleanup {} (freed <green>{}</green> across {files} files)",
+                format_size(before),
+                format_size(after),
+                format_size(freed)
+            ),
+        );
+    }
+
     /// Logs the cleanup result using the provided logger.
+    ///
+    /// Savings are tagged `<green>` and the failure count `<red>`, so they
+    /// stand out on colored terminals and render plain elsewhere. The failed
+    /// paths themselves are left to the caller's diagnostics.
     pub fn log(&self, logger: &Logger, dry_run: bool) {
         let has_output = if dry_run {
             self.log_dry_run(logger)
         } else {
             self.log_actual(logger)
         };
+        let has_failures = self.log_failures(logger);
+        let has_skipped = self.log_skipped(logger);
 
-        if !has_output {
+        if !has_output && !has_failures && !has_skipped {
             let msg = if dry_run {
                 "No files to process"
             } else {
@@ -85,13 +154,42 @@ impl CleanupResult {
         }
     }
 
+    fn log_failures(&self, logger: &Logger) -> bool {
+        if self.failed.is_empty() {
+            return false;
+        }
+
+        let count = self.failed.len();
+        logger.print(
+            "CLEANUP",
+            &format!("<red>Failed to process {count} file(s)</red>"),
+        );
+        true
+    }
+
+    fn log_skipped(&self, logger: &Logger) -> bool {
+        if self.skipped.is_empty() {
+            return false;
+        }
+
+        let count = self.skipped.len();
+        logger.print(
+            "CLEANUP",
+            &format!("Skipped {count} file(s) already compressed"),
+        );
+        for path in &self.skipped {
+            logger.raw(&format!("  {path}"));
+        }
+        true
+    }
+
     fn log_dry_run(&self, logger: &Logger) -> bool {
         if !self.would_delete.is_empty() {
             let count = self.would_delete.len();
             let size = format_size(self.would_free);
             logger.print(
                 "CLEANUP",
-                &format!("Would delete {count} file(s), freeing {size}"),
+                &format!("Would delete {count} file(s), freeing <green>{size}</green>"),
             );
             for path in &self.would_delete {
                 logger.raw(&format!("  {path}"));
@@ -103,21 +201,38 @@ impl CleanupResult {
             let size = format_size(self.would_compress_save);
             logger.print(
                 "CLEANUP",
-                &format!("Would compress {count} file(s), saving ~{size}"),
+                &format!("Would compress {count} file(s), saving <green>~{size}</green>"),
             );
             for path in &self.would_compress {
                 logger.raw(&format!("  {path}"));
             }
         }
 
-        !self.would_delete.is_empty() || !self.would_compress.is_empty()
+        if !self.would_move.is_empty() {
+            let count = self.would_move.len();
+            let size = format_size(self.would_move_size);
+            logger.print(
+                "CLEANUP",
+                &format!("Would move {count} file(s), {size} total"),
+            );
+            for (from, to) in &self.would_move {
+                logger.raw(&format!("  {from} -> {to}"));
+            }
+        }
+
+        !self.would_delete.is_empty()
+            || !self.would_compress.is_empty()
+            || !self.would_move.is_empty()
     }
 
     fn log_actual(&self, logger: &Logger) -> bool {
         if !self.deleted.is_empty() {
             let count = self.deleted.len();
             let size = format_size(self.freed);
-            logger.print("CLEANUP", &format!("Deleted {count} file(s), freed {size}"));
+            logger.print(
+                "CLEANUP",
+                &format!("Deleted {count} file(s), freed <green>{size}</green>"),
+            );
             for path in &self.deleted {
                 logger.raw(&format!("  {path}"));
             }
@@ -128,13 +243,22 @@ impl CleanupResult {
             let size = format_size(self.compressed_saved);
             logger.print(
                 "CLEANUP",
-                &format!("Compressed {count} file(s), saved {size}"),
+                &format!("Compressed {count} file(s), saved <green>{size}</green>"),
             );
             for path in &self.compressed {
                 logger.raw(&format!("  {path}"));
             }
         }
 
-        !self.deleted.is_empty() || !self.compressed.is_empty()
+        if !self.moved.is_empty() {
+            let count = self.moved.len();
+            let size = format_size(self.moved_size);
+            logger.print("CLEANUP", &format!("Moved {count} file(s), {size} total"));
+            for (from, to) in &self.moved {
+                logger.raw(&format!("  {from} -> {to}"));
+            }
+        }
+
+        !self.deleted.is_empty() || !self.compressed.is_empty() || !self.moved.is_empty()
     }
 }
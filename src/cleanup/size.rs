@@ -27,19 +27,78 @@ pub fn parse_size(s: &str) -> Option<u64> {
     })
 }
 
+/// Unit for [`format_size_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeUnit {
+    /// Bytes.
+    B,
+    /// Kibibytes (1024 bytes).
+    KB,
+    /// Mebibytes (1024 KB).
+    MB,
+    /// Gibibytes (1024 MB).
+    GB,
+}
+
+impl SizeUnit {
+    /// Picks the largest unit that keeps the value at or above 1.
+    #[must_use]
+    pub const fn for_bytes(bytes: u64) -> Self {
+        if bytes >= 1024 * 1024 * 1024 {
+            Self::GB
+        } else if bytes >= 1024 * 1024 {
+            Self::MB
+        } else if bytes >= 1024 {
+            Self::KB
+        } else {
+            Self::B
+        }
+    }
+
+    /// Number of bytes in one of this unit.
+    #[must_use]
+    pub const fn bytes(self) -> u64 {
+        match self {
+            Self::B => 1,
+            Self::KB => 1024,
+            Self::MB => 1024 * 1024,
+            Self::GB => 1024 * 1024 * 1024,
+        }
+    }
+
+    /// Returns the unit suffix.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::B => "B",
+            Self::KB => "KB",
+            Self::MB => "MB",
+            Self::GB => "GB",
+        }
+    }
+}
+
 /// Formats bytes as a human-readable string.
+///
+/// Picks the unit automatically and uses two decimal places; see
+/// [`format_size_with`] to force either.
 #[must_use]
 pub fn format_size(bytes: u64) -> String {
-    #[allow(clippy::cast_precision_loss)]
-    let bytes_f = bytes as f64;
-
-    if bytes >= 1024 * 1024 * 1024 {
-        format!("{:.2} GB", bytes_f / (1024.0 * 1024.0 * 1024.0))
-    } else if bytes >= 1024 * 1024 {
-        format!("{:.2} MB", bytes_f / (1024.0 * 1024.0))
-    } else if bytes >= 1024 {
-        format!("{:.2} KB", bytes_f / 1024.0)
-    } else {
-        format!("{bytes} B")
+    format_size_with(bytes, None, 2)
+}
+
+/// Formats bytes in `unit` (or an automatically chosen one) with
+/// `precision` decimal places.
+///
+/// Plain bytes are always printed as an integer.
+#[must_use]
+pub fn format_size_with(bytes: u64, unit: Option<SizeUnit>, precision: usize) -> String {
+    let unit = unit.unwrap_or_else(|| SizeUnit::for_bytes(bytes));
+    if unit == SizeUnit::B {
+        return format!("{bytes} B");
     }
+
+    #[allow(clippy::cast_precision_loss)]
+    let value = bytes as f64 / unit.bytes() as f64;
+    format!("{value:.precision$} {}", unit.as_str())
 }
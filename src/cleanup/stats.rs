@@ -3,9 +3,15 @@
 use super::format_size;
 use crate::logger::Logger;
 use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+/// Width of the longest histogram bar in characters.
+const HISTOGRAM_WIDTH: usize = 40;
 
 /// Statistics about log files.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct LogStats {
     /// Total number of log files.
     pub total_files: usize,
@@ -59,8 +65,84 @@ impl LogStats {
     }
 }
 
+impl LogStats {
+    /// Renders the per-file statistics as CSV.
+    ///
+    /// One header row (`path,size,age_days,modified_date`) followed by one
+    /// row per file. Files without a modification date leave the last
+    /// column empty.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("path,size,age_days,modified_date\n");
+        for file in &self.files {
+            let date = file
+                .modified_date
+                .map(|d| d.to_string())
+                .unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "{},{},{},{date}",
+                csv_field(&file.path),
+                file.size,
+                file.age_days
+            );
+        }
+        out
+    }
+
+    /// Sums file sizes per modification day, oldest day first.
+    ///
+    /// Files without a modification date are skipped.
+    #[must_use]
+    pub fn bytes_per_day(&self) -> BTreeMap<NaiveDate, u64> {
+        let mut days = BTreeMap::new();
+        for file in &self.files {
+            if let Some(date) = file.modified_date {
+                *days.entry(date).or_insert(0) += file.size;
+            }
+        }
+        days
+    }
+
+    /// Prints a text histogram of log volume per day.
+    ///
+    /// One row per day; bar length is proportional to the bytes written that day.
+    pub fn print_histogram(&self, logger: &Logger) {
+        let days = self.bytes_per_day();
+        let Some(&max) = days.values().max() else {
+            logger.print("STATS", "No dated files");
+            return;
+        };
+
+        logger.print("STATS", "Volume per day:");
+        for (date, &bytes) in &days {
+            // Non-empty days get at least one cell so they stay visible
+            let len = if bytes == 0 {
+                0
+            } else {
+                let scaled = bytes.saturating_mul(HISTOGRAM_WIDTH as u64) / max;
+                usize::try_from(scaled).unwrap_or(HISTOGRAM_WIDTH).max(1)
+            };
+            let bar = "#".repeat(len);
+            logger.raw(&format!(
+                "  {date} {bar:<HISTOGRAM_WIDTH$} {}",
+                format_size(bytes)
+            ));
+        }
+    }
+}
+
+/// Quotes a CSV field if it contains a comma, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 /// Information about a log file.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct LogFileInfo {
     /// File path.
     pub path: String,
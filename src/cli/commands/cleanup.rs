@@ -1,6 +1,6 @@
 //! Cleanup command implementation.
 
-use crate::cleanup::{CleanupOptions, cleanup};
+use crate::cleanup::{CleanupOptions, cleanup, stats};
 use crate::cli::util::expand_path;
 use crate::config::Config;
 use crate::internal;
@@ -19,24 +19,13 @@ pub fn cmd_cleanup(args: &[&str], config: &Config, logger: &Logger) -> ExitCode
         &format!("dry_run={dry_run}, all={all}, compress={compress}"),
     );
 
-    let mut options = CleanupOptions::new()
+    let options = CleanupOptions::new()
         .dry_run(dry_run)
         .delete_all(all)
         .compress(compress);
 
     // Apply config defaults first
-    if let Some(days) = config.cleanup.max_age_days {
-        internal::debug("CLEANUP", &format!("Config: max_age_days={days}"));
-        options = options.max_age_days(days);
-    }
-    if let Some(ref size) = config.cleanup.max_total_size {
-        internal::debug("CLEANUP", &format!("Config: max_total_size={size}"));
-        options = options.max_total_size(size);
-    }
-    if let Some(keep) = config.cleanup.keep_last {
-        internal::debug("CLEANUP", &format!("Config: keep_last={keep}"));
-        options = options.keep_last(keep);
-    }
+    let mut options = apply_config_defaults(options, config);
 
     // CLI overrides config
     if let Some(idx) = args.iter().position(|&a| a == "--older-than")
@@ -69,6 +58,14 @@ pub fn cmd_cleanup(args: &[&str], config: &Config, logger: &Logger) -> ExitCode
         options = options.keep_last(n);
     }
 
+    if let Some(idx) = args.iter().position(|&a| a == "--keep-last-per-app")
+        && let Some(n_str) = args.get(idx + 1)
+        && let Ok(n) = n_str.parse::<usize>()
+    {
+        internal::debug("CLEANUP", &format!("CLI override: keep_last_per_app={n}"));
+        options = options.keep_last_per_app(n);
+    }
+
     if let Some(idx) = args.iter().position(|&a| a == "--before")
         && let Some(date_str) = args.get(idx + 1)
     {
@@ -99,9 +96,19 @@ pub fn cmd_cleanup(args: &[&str], config: &Config, logger: &Logger) -> ExitCode
         }
     }
 
+    if let Some(idx) = args.iter().position(|&a| a == "--move-to")
+        && let Some(dir) = args.get(idx + 1)
+    {
+        internal::debug("CLEANUP", &format!("CLI override: move_to={dir}"));
+        options = options.archive_to(expand_path(dir));
+    }
+
     let base_dir = expand_path(&config.file.base_dir);
     internal::debug("CLEANUP", &format!("Base dir: {}", base_dir.display()));
 
+    // Measured up front for the before/after summary
+    let current_total = stats(&base_dir, options.app_filter.as_deref()).map_or(0, |s| s.total_size);
+
     match cleanup(&base_dir, &options) {
         Ok(result) => {
             // Log failures
@@ -110,6 +117,7 @@ pub fn cmd_cleanup(args: &[&str], config: &Config, logger: &Logger) -> ExitCode
             }
 
             result.log(logger, dry_run);
+            result.log_projection(logger, current_total);
             ExitCode::SUCCESS
         }
         Err(e) => {
@@ -118,3 +126,28 @@ pub fn cmd_cleanup(args: &[&str], config: &Config, logger: &Logger) -> ExitCode
         }
     }
 }
+
+/// Applies `[cleanup]` config defaults; CLI flags override them afterwards.
+fn apply_config_defaults(mut options: CleanupOptions, config: &Config) -> CleanupOptions {
+    if let Some(days) = config.cleanup.max_age_days {
+        internal::debug("CLEANUP", &format!("Config: max_age_days={days}"));
+        options = options.max_age_days(days);
+    }
+    if let Some(ref size) = config.cleanup.max_total_size {
+        internal::debug("CLEANUP", &format!("Config: max_total_size={size}"));
+        options = options.max_total_size(size);
+    }
+    if let Some(keep) = config.cleanup.keep_last {
+        internal::debug("CLEANUP", &format!("Config: keep_last={keep}"));
+        options = options.keep_last(keep);
+    }
+    if let Some(keep) = config.cleanup.keep_last_per_app {
+        internal::debug("CLEANUP", &format!("Config: keep_last_per_app={keep}"));
+        options = options.keep_last_per_app(keep);
+    }
+    if let Some(depth) = config.cleanup.max_depth {
+        internal::debug("CLEANUP", &format!("Config: max_depth={depth}"));
+        options = options.max_depth(depth);
+    }
+    options
+}
@@ -0,0 +1,47 @@
+//! Config command implementation.
+
+use crate::config::Config;
+use crate::internal;
+use std::process::ExitCode;
+
+/// Handles `hyprslog config [--app <name>] [--diff]`.
+///
+/// Prints the effective config as TOML: after `source` includes and, with
+/// `--app`, the `[apps.<name>]` overrides. Validation warnings go to stderr.
+/// `--diff` (requires `--app`) prints only the fields the app overrides, one
+/// `key: old -> new` line each.
+#[must_use]
+pub fn cmd_config(args: &[&str], config: &Config) -> ExitCode {
+    let app = args
+        .iter()
+        .position(|&a| a == "--app")
+        .and_then(|i| args.get(i + 1).copied());
+
+    if args.contains(&"--diff") {
+        let Some(app) = app else {
+            internal::error("CONFIG", "--diff needs --app <name>");
+            return ExitCode::FAILURE;
+        };
+        for (key, old, new) in config.diff(&config.for_app(app)) {
+            println!("{key}: {old} -> {new}");
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let config = app.map_or_else(|| config.clone(), |app| config.for_app(app));
+
+    for warning in config.validate() {
+        internal::warn("CONFIG", &warning);
+    }
+
+    match config.to_toml() {
+        Ok(toml) => {
+            print!("{toml}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            internal::error("CONFIG", &format!("{e}"));
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Export command implementation.
+
+use crate::cli::util::expand_path;
+use crate::fmt::ansi_to_html_document;
+use crate::internal;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+/// Handles `hyprslog export [--format html] [--output <path>] [<file>]`.
+///
+/// Converts a log captured with ANSI colors (from `<file>`, or stdin when
+/// omitted or `-`) into a standalone HTML document, written to stdout or
+/// `--output`.
+#[must_use]
+pub fn cmd_export(args: &[&str]) -> ExitCode {
+    let mut format = "html";
+    let mut output = None;
+    let mut input = None;
+
+    let mut iter = args.iter();
+    while let Some(&arg) = iter.next() {
+        match arg {
+            "--format" | "--output" | "-o" => {
+                let Some(&value) = iter.next() else {
+                    internal::error("EXPORT", &format!("Missing value for {arg}"));
+                    return ExitCode::FAILURE;
+                };
+                if arg == "--format" {
+                    format = value;
+                } else {
+                    output = Some(value);
+                }
+            }
+            _ => input = Some(arg),
+        }
+    }
+
+    if format != "html" {
+        internal::error(
+            "EXPORT",
+            &format!("Unsupported format: {format} (expected html)"),
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let (content, title) = match input {
+        None | Some("-") => {
+            let mut content = String::new();
+            if let Err(e) = io::stdin().read_to_string(&mut content) {
+                internal::error("EXPORT", &format!("Error reading stdin: {e}"));
+                return ExitCode::FAILURE;
+            }
+            (content, "hyprslog")
+        }
+        Some(path) => match fs::read(expand_path(path)) {
+            Ok(bytes) => (String::from_utf8_lossy(&bytes).into_owned(), path),
+            Err(e) => {
+                internal::error("EXPORT", &format!("{path}: {e}"));
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let html = ansi_to_html_document(&content, title);
+    let result = match output {
+        Some(path) => fs::write(expand_path(path), html),
+        None => io::stdout().write_all(html.as_bytes()),
+    };
+    if let Err(e) = result {
+        internal::error("EXPORT", &format!("Error writing HTML: {e}"));
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -70,42 +70,46 @@ unsafe fn register_sigint_handler() {
     unsafe { signal(2, sigint_handler) };
 }
 
-/// Handles `hyprslog watch [--events <filter>] [--min-level <level>]`.
+/// Handles `hyprslog watch [--events <filter>] [--min-level <level>] [--stdin]`.
 ///
 /// Connects to Hyprland's event socket and streams events through the logger.
-/// Blocks until Ctrl+C.
+/// Blocks until Ctrl+C. With `--stdin`, replays newline-delimited raw events
+/// from stdin instead and returns at EOF; no socket is needed.
 #[must_use]
 pub fn cmd_watch(args: &[&str], config: &Config, logger: &Logger) -> ExitCode {
     let mut hyprland_config = config.hyprland.clone();
 
     // Parse --events filter (comma-separated allowlist)
-    if let Some(idx) = args.iter().position(|&a| a == "--events") {
-        if let Some(&filter) = args.get(idx + 1) {
-            let allowed: Vec<String> = filter.split(',').map(|s| s.trim().to_string()).collect();
-            hyprland_config.event_filter = Some(allowed);
-        }
+    if let Some(idx) = args.iter().position(|&a| a == "--events")
+        && let Some(&filter) = args.get(idx + 1)
+    {
+        let allowed: Vec<String> = filter.split(',').map(|s| s.trim().to_string()).collect();
+        hyprland_config.event_filter = Some(allowed);
     }
 
     // Parse --min-level filter
-    if let Some(idx) = args.iter().position(|&a| a == "--min-level") {
-        if let Some(&level_str) = args.get(idx + 1) {
-            if let Ok(min_level) = level_str.parse::<Level>() {
-                // Add events below this level to the ignore list
-                let defaults = crate::hyprland::level_map::default_level_map();
-                for (&event_name, &default_level) in &defaults {
-                    if default_level < min_level
-                        && !hyprland_config.event_levels.contains_key(event_name)
-                    {
-                        hyprland_config.ignore_events.push(event_name.to_string());
-                    }
-                }
-            } else {
-                internal::error("HYPRLAND", &format!("Invalid level: {level_str}"));
-                return ExitCode::FAILURE;
+    if let Some(idx) = args.iter().position(|&a| a == "--min-level")
+        && let Some(&level_str) = args.get(idx + 1)
+    {
+        let Ok(min_level) = level_str.parse::<Level>() else {
+            internal::error("HYPRLAND", &format!("Invalid level: {level_str}"));
+            return ExitCode::FAILURE;
+        };
+
+        // Add events below this level to the ignore list
+        let defaults = crate::hyprland::level_map::default_level_map();
+        for (&event_name, &default_level) in &defaults {
+            if default_level < min_level && !hyprland_config.event_levels.contains_key(event_name) {
+                hyprland_config.ignore_events.push(event_name.to_string());
             }
         }
     }
 
+    if args.contains(&"--stdin") {
+        listener::replay_events(std::io::stdin().lock(), logger, &hyprland_config);
+        return ExitCode::SUCCESS;
+    }
+
     let Some(socket_dir) = socket::resolve_socket_dir(&hyprland_config) else {
         return ExitCode::FAILURE;
     };
@@ -1,78 +1,257 @@
 //! JSON log command implementation.
 
-use crate::cli::util::parse_level;
+use crate::config::Config;
 use crate::internal;
+use crate::level::Level;
 use crate::logger::Logger;
+use chrono::DateTime;
 use serde::Deserialize;
+use serde_json::{Map, Value};
 use std::io::{self, BufRead};
 use std::process::ExitCode;
 
 /// JSON log entry format.
 #[derive(Debug, Deserialize)]
 struct JsonLogEntry {
-    level: String,
-    scope: String,
+    /// Level name, abbreviation or number; see [`Level::parse_lenient`].
+    #[serde(default)]
+    level: Option<Value>,
+    #[serde(default)]
+    scope: Option<String>,
     msg: String,
+    /// Producer timestamp (RFC 3339) the record is stamped with; kept as a
+    /// `ts` field instead if it doesn't parse.
+    #[serde(default)]
+    ts: Option<String>,
+    /// Structured fields attached to the record.
+    #[serde(default)]
+    fields: Map<String, Value>,
 }
 
-/// Handles `hyprslog json [<json>]`.
-#[must_use]
-pub fn cmd_json(input: Option<&str>, logger: &Logger) -> ExitCode {
-    let mut processed = 0u64;
-    let mut failed = 0u64;
+/// Level and scope for entries that omit them.
+struct Defaults {
+    level: Level,
+    scope: String,
+}
 
-    let process_line = |line: &str| -> Result<(), String> {
-        let entry: JsonLogEntry =
-            serde_json::from_str(line).map_err(|e| format!("invalid JSON: {e}"))?;
+/// Processed/failed entry counts for the summary line.
+#[derive(Default)]
+struct Tally {
+    processed: u64,
+    failed: u64,
+}
 
-        let level =
-            parse_level(&entry.level).ok_or_else(|| format!("invalid level: {}", entry.level))?;
+impl Tally {
+    fn record(&mut self, result: Result<(), String>) {
+        match result {
+            Ok(()) => self.processed += 1,
+            Err(e) => {
+                internal::error("JSON", &e);
+                self.failed += 1;
+            }
+        }
+    }
+
+    fn report(&self) {
+        let entries = if self.processed == 1 {
+            "entry"
+        } else {
+            "entries"
+        };
+        internal::info(
+            "JSON",
+            &format!(
+                "JSON: processed {} {entries}, {} failed",
+                self.processed, self.failed
+            ),
+        );
+    }
+}
+
+/// Handles `hyprslog json [--default-level <level>] [--default-scope <scope>] [<json>]`.
+///
+/// Input is either one JSON object per line or a single top-level array of
+/// objects. Each entry needs `msg`; `level` and `scope` fall back to the flags,
+/// then to `[json] default_level`/`default_scope`. Levels are parsed with
+/// [`Level::parse_lenient`], so `"E"`, `"fatal"` or `3` work too. `ts` and a
+/// `fields` object are optional.
+#[must_use]
+pub fn cmd_json(args: &[&str], config: &Config, logger: &Logger) -> ExitCode {
+    let mut input = None;
+    let mut level_arg = config.json.default_level.as_str();
+    let mut scope_arg = config.json.default_scope.as_str();
+    let mut iter = args.iter();
+    while let Some(&arg) = iter.next() {
+        let target = match arg {
+            "--default-level" => &mut level_arg,
+            "--default-scope" => &mut scope_arg,
+            _ => {
+                input = Some(arg);
+                continue;
+            }
+        };
+        let Some(&value) = iter.next() else {
+            internal::error("JSON", &format!("Missing value for {arg}"));
+            return ExitCode::FAILURE;
+        };
+        *target = value;
+    }
 
-        logger.log(level, &entry.scope, &entry.msg);
-        Ok(())
+    let Ok(level) = Level::parse_lenient(level_arg) else {
+        internal::error("JSON", &format!("Invalid default level: {level_arg}"));
+        return ExitCode::FAILURE;
     };
+    let defaults = Defaults {
+        level,
+        scope: scope_arg.to_string(),
+    };
+    let mut tally = Tally::default();
 
     match input {
         None | Some("-") => {
             internal::debug("JSON", "Reading JSON from stdin");
-            let stdin = io::stdin();
-            for line in stdin.lock().lines() {
-                match line {
-                    Ok(l) if !l.trim().is_empty() => {
-                        internal::trace("JSON", "Processing JSON line");
-                        if let Err(e) = process_line(&l) {
-                            internal::error("JSON", &e);
-                            failed += 1;
-                        } else {
-                            processed += 1;
-                        }
-                    }
-                    Ok(_) => {}
-                    Err(e) => {
-                        internal::error("JSON", &format!("Error reading stdin: {e}"));
-                        return ExitCode::FAILURE;
-                    }
-                }
+            if let Err(e) = process_reader(io::stdin().lock(), logger, &defaults, &mut tally) {
+                internal::error("JSON", &format!("Error reading stdin: {e}"));
+                return ExitCode::FAILURE;
             }
-            internal::info(
-                "JSON",
-                &format!("JSON: processed {processed} entries, {failed} failed"),
-            );
+            tally.report();
             ExitCode::SUCCESS
         }
         Some(json) => {
+            process_argument(json, logger, &defaults, &mut tally);
+            tally.report();
+            if tally.failed == 0 {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
+
+/// Streams NDJSON line by line, or reads the input whole if it starts with `[`.
+fn process_reader(
+    mut reader: impl BufRead,
+    logger: &Logger,
+    defaults: &Defaults,
+    tally: &mut Tally,
+) -> io::Result<()> {
+    if starts_with_array(&mut reader)? {
+        let mut json = String::new();
+        reader.read_to_string(&mut json)?;
+        process_array(&json, logger, defaults, tally);
+        return Ok(());
+    }
+
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
             internal::trace("JSON", "Processing JSON line");
-            match process_line(json) {
-                Ok(()) => {
-                    internal::info("JSON", "JSON: processed 1 entry, 0 failed");
-                    ExitCode::SUCCESS
-                }
-                Err(e) => {
-                    internal::error("JSON", &e);
-                    internal::info("JSON", "JSON: processed 0 entries, 1 failed");
-                    ExitCode::FAILURE
-                }
+            tally.record(process_line(&line, logger, defaults));
+        }
+    }
+    Ok(())
+}
+
+/// Logs a command-line argument: one JSON value (object or array), else NDJSON.
+///
+/// Parsing the whole argument first keeps pretty-printed multi-line objects
+/// working; only input that isn't a single value is split per line.
+fn process_argument(json: &str, logger: &Logger, defaults: &Defaults, tally: &mut Tally) {
+    match serde_json::from_str::<Value>(json) {
+        Ok(Value::Array(entries)) => {
+            for entry in entries {
+                tally.record(process_value(entry, logger, defaults));
             }
         }
+        Ok(value) => tally.record(process_value(value, logger, defaults)),
+        Err(_) if json.trim_start().starts_with('[') => {
+            process_array(json, logger, defaults, tally);
+        }
+        Err(_) => {
+            for line in json.lines().filter(|l| !l.trim().is_empty()) {
+                tally.record(process_line(line, logger, defaults));
+            }
+        }
+    }
+}
+
+/// Skips leading whitespace and reports whether the input is a JSON array.
+fn starts_with_array(reader: &mut impl BufRead) -> io::Result<bool> {
+    loop {
+        let buf = reader.fill_buf()?;
+        if buf.is_empty() {
+            return Ok(false);
+        }
+        if let Some(pos) = buf.iter().position(|b| !b.is_ascii_whitespace()) {
+            let is_array = buf[pos] == b'[';
+            reader.consume(pos);
+            return Ok(is_array);
+        }
+        let len = buf.len();
+        reader.consume(len);
+    }
+}
+
+/// Logs each element of a top-level JSON array.
+fn process_array(json: &str, logger: &Logger, defaults: &Defaults, tally: &mut Tally) {
+    match serde_json::from_str::<Vec<Value>>(json) {
+        Ok(entries) => {
+            internal::trace(
+                "JSON",
+                &format!("Processing {} array entries", entries.len()),
+            );
+            for entry in entries {
+                tally.record(process_value(entry, logger, defaults));
+            }
+        }
+        Err(e) => tally.record(Err(format!("invalid JSON array: {e}"))),
+    }
+}
+
+fn process_line(line: &str, logger: &Logger, defaults: &Defaults) -> Result<(), String> {
+    let value = serde_json::from_str(line).map_err(|e| format!("invalid JSON: {e}"))?;
+    process_value(value, logger, defaults)
+}
+
+fn process_value(value: Value, logger: &Logger, defaults: &Defaults) -> Result<(), String> {
+    let entry: JsonLogEntry =
+        serde_json::from_value(value).map_err(|e| format!("invalid entry: {e}"))?;
+
+    let level = match &entry.level {
+        Some(Value::String(level)) => {
+            Level::parse_lenient(level).map_err(|_| format!("invalid level: {level}"))?
+        }
+        Some(Value::Number(level)) => Level::parse_lenient(&level.to_string())
+            .map_err(|_| format!("invalid level: {level}"))?,
+        Some(level) => return Err(format!("invalid level: {level}")),
+        None => defaults.level,
+    };
+    let scope = entry.scope.as_deref().unwrap_or(&defaults.scope);
+
+    let mut fields: Vec<(String, String)> = Vec::with_capacity(entry.fields.len() + 1);
+    let mut timestamp = None;
+    if let Some(ts) = entry.ts {
+        match DateTime::parse_from_rfc3339(&ts) {
+            Ok(parsed) => timestamp = Some(parsed.to_utc()),
+            Err(_) => fields.push(("ts".to_string(), ts)),
+        }
+    }
+    for (key, value) in entry.fields {
+        let value = match value {
+            Value::String(s) => s,
+            other => other.to_string(),
+        };
+        fields.push((key, value));
+    }
+
+    let pairs: Vec<(&str, &str)> = fields
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+    match timestamp {
+        Some(timestamp) => logger.log_kv_at(timestamp, level, scope, &entry.msg, &pairs),
+        None => logger.log_kv(level, scope, &entry.msg, &pairs),
     }
+    Ok(())
 }
@@ -1,23 +1,51 @@
 //! Log command implementation.
 
 use crate::cli::util::parse_level;
+use crate::config::Config;
 use crate::internal;
 use crate::logger::Logger;
 use std::process::ExitCode;
 
-/// Handles `hyprslog log <app> <level> <scope> <msg>`.
+/// Handles `hyprslog log [--app <name>] [<app>] <level> <scope> <msg>`.
+///
+/// The app is taken from `--app`, else from a leading positional that isn't a
+/// level (the old `log <app> <level> ...` form), else from `general.app_name`.
+/// `--app` only counts before the level, so a message may contain it.
 #[must_use]
-pub fn cmd_log(args: &[&str], logger: &Logger) -> ExitCode {
-    if args.len() < 4 {
-        internal::warn("CLI", "Usage: hyprslog log <app> <level> <scope> <message>");
+pub fn cmd_log(args: &[&str], config: &Config, logger: &Logger) -> ExitCode {
+    const USAGE: &str = "Usage: hyprslog log [--app <name>] <level> <scope> <message>";
+
+    // Only a leading `--app` is the flag; later ones are message text
+    let (flag_app, positional) = match args {
+        ["--app", name, rest @ ..] => (Some(*name), rest),
+        ["--app"] => {
+            internal::warn("CLI", USAGE);
+            return ExitCode::FAILURE;
+        }
+        _ => (None, args),
+    };
+
+    // A leading non-level positional is the app (backward compatible)
+    let (positional_app, rest) = match positional.split_first() {
+        Some((&first, rest)) if flag_app.is_none() && parse_level(first).is_none() => {
+            (Some(first), rest)
+        }
+        _ => (None, positional),
+    };
+
+    if rest.len() < 3 {
+        internal::warn("CLI", USAGE);
         return ExitCode::FAILURE;
     }
-    let app = args[0];
-    let Some(level) = parse_level(args[1]) else {
-        internal::error("CLI", &format!("Invalid level: {}", args[1]));
+    let Some(level) = parse_level(rest[0]) else {
+        internal::error("CLI", &format!("Invalid level: {}", rest[0]));
         return ExitCode::FAILURE;
     };
-    logger.log_full(level, args[2], &args[3..].join(" "), Some(app));
+
+    let app = flag_app
+        .or(positional_app)
+        .or(config.general.app_name.as_deref());
+    logger.log_full(level, rest[1], &rest[2..].join(" "), app);
     ExitCode::SUCCESS
 }
 
@@ -1,10 +1,14 @@
 //! CLI command implementations.
 
 mod cleanup;
+mod config;
+mod export;
 mod json;
 mod log;
 mod preset;
+mod query;
 mod send;
+mod show;
 mod stats;
 mod themes;
 
@@ -15,10 +19,14 @@ mod hyprland;
 mod server;
 
 pub use cleanup::cmd_cleanup;
+pub use config::cmd_config;
+pub use export::cmd_export;
 pub use json::cmd_json;
 pub use log::{cmd_log, cmd_log_shorthand};
 pub use preset::{cmd_preset, cmd_presets};
+pub use query::cmd_query;
 pub use send::cmd_send;
+pub use show::cmd_show;
 pub use stats::cmd_stats;
 pub use themes::cmd_themes;
 
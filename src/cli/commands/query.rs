@@ -0,0 +1,187 @@
+//! Query command implementation.
+
+use crate::cli::util::expand_path;
+use crate::config::Config;
+use crate::internal;
+use crate::level::Level;
+use crate::logger::Logger;
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::Deserialize;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// The fields of a JSON output entry that queries look at.
+#[derive(Debug, Deserialize)]
+struct QueryEntry {
+    ts: String,
+    level: String,
+    scope: String,
+    msg: String,
+    #[serde(default)]
+    app: Option<String>,
+}
+
+/// Filters selected on the command line; unset filters match everything.
+#[derive(Debug, Default)]
+struct QueryFilter {
+    /// Minimum level.
+    level: Option<Level>,
+    /// Scope, compared case-insensitively.
+    scope: Option<String>,
+    app: Option<String>,
+    /// First day to include, in the entry's own UTC offset.
+    since: Option<NaiveDate>,
+    /// Last day to include.
+    until: Option<NaiveDate>,
+    /// Substring of the message.
+    text: Option<String>,
+}
+
+impl QueryFilter {
+    fn matches(&self, entry: &QueryEntry, level: Level, ts: &DateTime<FixedOffset>) -> bool {
+        let day = ts.date_naive();
+        self.level.is_none_or(|min| level >= min)
+            && self
+                .scope
+                .as_ref()
+                .is_none_or(|scope| scope.eq_ignore_ascii_case(&entry.scope))
+            && self
+                .app
+                .as_ref()
+                .is_none_or(|app| entry.app.as_ref() == Some(app))
+            && self.since.is_none_or(|since| day >= since)
+            && self.until.is_none_or(|until| day <= until)
+            && self
+                .text
+                .as_ref()
+                .is_none_or(|text| entry.msg.contains(text.as_str()))
+    }
+}
+
+/// Handles `hyprslog query [options] [<file>]`.
+///
+/// Streams the JSON output file (`[json]` `path`, or `<file>`) and prints
+/// the entries matching every given filter, as stored. `--pretty` renders
+/// them through the terminal output instead. Lines that aren't JSON entries
+/// are skipped.
+#[must_use]
+pub fn cmd_query(args: &[&str], config: &Config) -> ExitCode {
+    let mut filter = QueryFilter::default();
+    let mut limit = None;
+    let mut pretty = false;
+    let mut file = None;
+
+    let mut iter = args.iter();
+    while let Some(&arg) = iter.next() {
+        let result = match arg {
+            "--pretty" => {
+                pretty = true;
+                Ok(())
+            }
+            "--level" => value(iter.next(), arg).and_then(|v| {
+                filter.level = Some(v.parse().map_err(|e| format!("{e}"))?);
+                Ok(())
+            }),
+            "--scope" => value(iter.next(), arg).map(|v| filter.scope = Some(v.to_string())),
+            "--app" => value(iter.next(), arg).map(|v| filter.app = Some(v.to_string())),
+            "--grep" => value(iter.next(), arg).map(|v| filter.text = Some(v.to_string())),
+            "--since" => date(iter.next(), arg).map(|d| filter.since = Some(d)),
+            "--until" => date(iter.next(), arg).map(|d| filter.until = Some(d)),
+            "--limit" => value(iter.next(), arg).and_then(|v| {
+                limit = Some(
+                    v.parse::<usize>()
+                        .map_err(|_| format!("--limit needs a number, got {v}"))?,
+                );
+                Ok(())
+            }),
+            _ => {
+                file = Some(PathBuf::from(arg));
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            internal::error("QUERY", &e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let path = file.unwrap_or_else(|| expand_path(&config.json.path));
+    let reader = match File::open(&path) {
+        Ok(f) => BufReader::new(f),
+        Err(e) => {
+            internal::error("QUERY", &format!("{}: {e}", path.display()));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let renderer = pretty.then(|| pretty_logger(config));
+    let mut stdout = io::stdout().lock();
+    let mut matched = 0;
+    let mut skipped = 0;
+    for line in reader.lines() {
+        if limit.is_some_and(|limit| matched >= limit) {
+            break;
+        }
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                internal::error("QUERY", &format!("{}: {e}", path.display()));
+                return ExitCode::FAILURE;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Some((entry, level, ts)) = parse_entry(&line) else {
+            skipped += 1;
+            continue;
+        };
+        if !filter.matches(&entry, level, &ts) {
+            continue;
+        }
+
+        matched += 1;
+        if let Some(logger) = &renderer {
+            logger.log_at(ts.to_utc(), level, &entry.scope, &entry.msg);
+        } else if writeln!(stdout, "{line}").is_err() {
+            // Reader went away (e.g. `| head`)
+            return ExitCode::SUCCESS;
+        }
+    }
+
+    if skipped > 0 {
+        internal::warn("QUERY", &format!("Skipped {skipped} malformed lines"));
+    }
+    ExitCode::SUCCESS
+}
+
+/// Parses one JSONL line into an entry with its level and timestamp.
+fn parse_entry(line: &str) -> Option<(QueryEntry, Level, DateTime<FixedOffset>)> {
+    let entry: QueryEntry = serde_json::from_str(line).ok()?;
+    let level = entry.level.parse().ok()?;
+    let ts = DateTime::parse_from_rfc3339(&entry.ts).ok()?;
+    Some((entry, level, ts))
+}
+
+/// Builds a terminal-only logger that lets every level through.
+fn pretty_logger(config: &Config) -> Logger {
+    let mut config = config.clone();
+    config.terminal.enabled = true;
+    config.file.enabled = false;
+    config.json.enabled = false;
+    let app = config.general.app_name.clone().unwrap_or_default();
+    Logger::from_config_with(&config, &app).with_level(Level::Trace)
+}
+
+fn value<'a>(next: Option<&&'a str>, flag: &str) -> Result<&'a str, String> {
+    next.copied().ok_or_else(|| format!("{flag} needs a value"))
+}
+
+fn date(next: Option<&&str>, flag: &str) -> Result<NaiveDate, String> {
+    let v = value(next, flag)?;
+    NaiveDate::parse_from_str(v, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date for {flag}: {v} (use YYYY-MM-DD)"))
+}
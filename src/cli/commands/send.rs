@@ -11,8 +11,10 @@ use std::os::unix::net::UnixStream;
 use std::process::ExitCode;
 
 fn default_socket_path() -> String {
-    std::env::var("XDG_RUNTIME_DIR")
-        .map_or_else(|_| "/tmp/hyprslog.sock".to_string(), |r| format!("{r}/hyprslog.sock"))
+    std::env::var("XDG_RUNTIME_DIR").map_or_else(
+        |_| "/tmp/hyprslog.sock".to_string(),
+        |r| format!("{r}/hyprslog.sock"),
+    )
 }
 
 /// `hyprslog send [--app <app>] [--tcp <addr>] <level> <scope> <msg…>`
@@ -23,10 +23,7 @@ pub fn cmd_server(args: &[&str]) -> ExitCode {
         Some("status") => server_status(),
         Some("--foreground") => server_foreground(),
         _ => {
-            internal::warn(
-                "CLI",
-                "usage: hyprslog server <start|stop|status>",
-            );
+            internal::warn("CLI", "usage: hyprslog server <start|stop|status>");
             ExitCode::FAILURE
         }
     }
@@ -96,18 +93,16 @@ fn server_stop() -> ExitCode {
     };
 
     match daemon::read_pid(&config) {
-        Ok(Some(pid)) if daemon::pid_is_running(pid) => {
-            match daemon::send_sigterm(pid) {
-                Ok(()) => {
-                    internal::info("CLI", &format!("SIGTERM sent to PID {pid}"));
-                    ExitCode::SUCCESS
-                }
-                Err(e) => {
-                    internal::error("CLI", &format!("kill failed: {e}"));
-                    ExitCode::FAILURE
-                }
+        Ok(Some(pid)) if daemon::pid_is_running(pid) => match daemon::send_sigterm(pid) {
+            Ok(()) => {
+                internal::info("CLI", &format!("SIGTERM sent to PID {pid}"));
+                ExitCode::SUCCESS
             }
-        }
+            Err(e) => {
+                internal::error("CLI", &format!("kill failed: {e}"));
+                ExitCode::FAILURE
+            }
+        },
         _ => {
             internal::warn("CLI", "server is not running");
             ExitCode::FAILURE
@@ -157,5 +152,3 @@ fn server_foreground() -> ExitCode {
         }
     }
 }
-
-
@@ -0,0 +1,175 @@
+//! Show command implementation.
+
+use crate::cli::util::expand_path;
+use crate::config::Config;
+use crate::fmt::{FormatTemplate, FormatValues, hostname};
+use crate::internal;
+use chrono::Local;
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+/// Lines printed when `--lines` is not given.
+const DEFAULT_LINES: usize = 10;
+
+/// How often `--follow` checks the file for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Handles `hyprslog show [--app <name>] [--lines <N>] [--follow] [<file>]`.
+///
+/// Prints the last lines of `<file>`, or of the app's most recent log file
+/// for today as resolved from `[file]` `base_dir` and `path_structure`. With
+/// `--follow`, keeps printing appended lines until interrupted, reopening the
+/// file when it is truncated or replaced by rotation.
+#[must_use]
+pub fn cmd_show(args: &[&str], config: &Config) -> ExitCode {
+    let mut app = None;
+    let mut lines = DEFAULT_LINES;
+    let mut follow = false;
+    let mut file = None;
+
+    let mut iter = args.iter();
+    while let Some(&arg) = iter.next() {
+        match arg {
+            "--follow" | "-f" => follow = true,
+            "--app" => app = iter.next().copied(),
+            "--lines" | "-n" => {
+                let Some(n) = iter.next().and_then(|n| n.parse().ok()) else {
+                    internal::error("SHOW", "--lines needs a number");
+                    return ExitCode::FAILURE;
+                };
+                lines = n;
+            }
+            _ => file = Some(PathBuf::from(arg)),
+        }
+    }
+
+    let app = app
+        .or(config.general.app_name.as_deref())
+        .unwrap_or("hyprslog");
+    let Some(path) = file.or_else(|| resolve_log_file(config, app)) else {
+        internal::error("SHOW", &format!("No log file found for app {app}"));
+        return ExitCode::FAILURE;
+    };
+
+    let offset = match print_tail(&path, lines) {
+        Ok(offset) => offset,
+        Err(e) => {
+            internal::error("SHOW", &format!("{}: {e}", path.display()));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if follow && let Err(e) = follow_file(&path, offset) {
+        internal::error("SHOW", &format!("{}: {e}", path.display()));
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Finds today's log file for `app`.
+///
+/// Uses the rendered filename when it exists; filenames with `{scope}` or
+/// `{level}` don't resolve without a record, so the newest file in the
+/// rendered directory is used instead.
+fn resolve_log_file(config: &Config, app: &str) -> Option<PathBuf> {
+    let now = Local::now();
+    let values = FormatValues::new().app(app).hostname(hostname()).date(
+        &now.format("%Y").to_string(),
+        &now.format("%m").to_string(),
+        &now.format("%d").to_string(),
+    );
+
+    let dir = expand_path(&config.file.base_dir)
+        .join(FormatTemplate::parse(&config.file.path_structure).render(&values));
+    let exact = dir.join(FormatTemplate::parse(&config.file.filename_structure).render(&values));
+    if exact.is_file() {
+        return Some(exact);
+    }
+
+    internal::debug(
+        "SHOW",
+        &format!("Looking for newest log in {}", dir.display()),
+    );
+    fs::read_dir(&dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            let is_log = entry.path().extension().is_some_and(|ext| ext == "log");
+            (meta.is_file() && is_log).then(|| (meta.modified().ok(), entry.path()))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// Prints the last `lines` lines and returns the file length read.
+fn print_tail(path: &Path, lines: usize) -> io::Result<u64> {
+    let bytes = fs::read(path)?;
+    let content = String::from_utf8_lossy(&bytes);
+    let all: Vec<&str> = content.lines().collect();
+
+    let mut stdout = io::stdout().lock();
+    for line in &all[all.len().saturating_sub(lines)..] {
+        writeln!(stdout, "{line}")?;
+    }
+    stdout.flush()?;
+    Ok(bytes.len() as u64)
+}
+
+/// Prints lines appended after `offset` until the process is interrupted.
+fn follow_file(path: &Path, mut offset: u64) -> io::Result<()> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset))?;
+    let mut identity = file_identity(&file.metadata()?);
+    let mut partial = Vec::new();
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        // Rotated away and not yet recreated: keep waiting
+        let Ok(meta) = fs::metadata(path) else {
+            continue;
+        };
+
+        let replaced = file_identity(&meta) != identity;
+        if replaced || meta.len() < offset {
+            internal::debug("SHOW", "Log file rotated, reopening");
+            file = File::open(path)?;
+            identity = file_identity(&file.metadata()?);
+            offset = 0;
+            partial.clear();
+        }
+
+        let mut appended = Vec::new();
+        offset += file.read_to_end(&mut appended)? as u64;
+        if appended.is_empty() {
+            continue;
+        }
+
+        // Only print complete lines; keep the tail for the next poll
+        partial.extend_from_slice(&appended);
+        if let Some(end) = partial.iter().rposition(|&b| b == b'\n') {
+            let mut stdout = io::stdout().lock();
+            stdout.write_all(&partial[..=end])?;
+            stdout.flush()?;
+            partial.drain(..=end);
+        }
+    }
+}
+
+/// Identifies the file behind a path, to detect replacement on rotation.
+#[cfg(unix)]
+#[allow(clippy::unnecessary_wraps)] // matches the non-unix signature
+fn file_identity(meta: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((meta.dev(), meta.ino()))
+}
+
+#[cfg(not(unix))]
+const fn file_identity(_meta: &fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
@@ -1,15 +1,23 @@
 //! Stats command implementation.
 
-use crate::cleanup::stats;
+use crate::cleanup::{STATS_EXTENSIONS, stats_with_extensions};
 use crate::cli::util::expand_path;
 use crate::config::Config;
 use crate::internal;
 use crate::logger::Logger;
 use std::process::ExitCode;
 
-/// Handles `hyprslog stats [--app <name>]`.
+/// Handles `hyprslog stats [--app <name>] [--ext <list>] [--histogram] [--json|--csv]`.
+///
+/// `--json` and `--csv` print machine-readable output instead of the
+/// human-readable summary; asking for both is an error.
 #[must_use]
 pub fn cmd_stats(args: &[&str], config: &Config, logger: &Logger) -> ExitCode {
+    if args.contains(&"--json") && args.contains(&"--csv") {
+        internal::error("STATS", "--json and --csv can't be used together");
+        return ExitCode::FAILURE;
+    }
+
     let base_dir = expand_path(&config.file.base_dir);
 
     // Parse --app filter
@@ -18,9 +26,41 @@ pub fn cmd_stats(args: &[&str], config: &Config, logger: &Logger) -> ExitCode {
         .position(|&a| a == "--app")
         .and_then(|i| args.get(i + 1).copied());
 
-    match stats(&base_dir, app_filter) {
+    // Parse --ext list (comma-separated, leading dots optional)
+    let extensions: Vec<&str> = args
+        .iter()
+        .position(|&a| a == "--ext")
+        .and_then(|i| args.get(i + 1))
+        .map_or_else(
+            || STATS_EXTENSIONS.to_vec(),
+            |list| {
+                list.split(',')
+                    .map(|e| e.trim().trim_start_matches('.'))
+                    .filter(|e| !e.is_empty())
+                    .collect()
+            },
+        );
+
+    match stats_with_extensions(&base_dir, app_filter, &extensions) {
+        Ok(s) if args.contains(&"--json") => match serde_json::to_string_pretty(&s) {
+            Ok(json) => {
+                println!("{json}");
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                internal::error("STATS", &format!("{e}"));
+                ExitCode::FAILURE
+            }
+        },
+        Ok(s) if args.contains(&"--csv") => {
+            print!("{}", s.to_csv());
+            ExitCode::SUCCESS
+        }
         Ok(s) => {
             s.log(logger);
+            if args.contains(&"--histogram") {
+                s.print_histogram(logger);
+            }
             ExitCode::SUCCESS
         }
         Err(e) => {
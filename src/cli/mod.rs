@@ -30,6 +30,29 @@ impl From<LogLevel> for crate::level::Level {
     }
 }
 
+/// When to color terminal output, for the global `--color` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal.
+    Auto,
+    /// Always color, e.g. when piping to `less -R`.
+    Always,
+    /// Never color.
+    Never,
+}
+
+impl ColorChoice {
+    /// Resolves the choice to whether colors are on.
+    #[must_use]
+    pub fn enabled(self) -> bool {
+        match self {
+            Self::Auto => std::io::IsTerminal::is_terminal(&std::io::stdout()),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
+
 /// Theme action for the themes subcommand.
 #[derive(Debug, Clone, Copy, clap::ValueEnum, Default)]
 pub enum ThemeAction {
@@ -46,6 +69,10 @@ pub enum ThemeAction {
     about = "Log messages from the command line"
 )]
 pub struct Cli {
+    /// Override `terminal.colors` for this invocation
+    #[arg(long, value_enum, value_name = "WHEN")]
+    pub color: Option<ColorChoice>,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -53,10 +80,11 @@ pub struct Cli {
 /// CLI subcommands.
 #[derive(Subcommand)]
 pub enum Command {
-    /// Log a message with explicit app name.
+    /// Log a message, optionally for a specific app.
     Log {
-        /// Application name
-        app: String,
+        /// Application name (defaults to `general.app_name`); goes before the level
+        #[arg(long)]
+        app: Option<String>,
         /// Log level
         #[arg(value_enum)]
         level: LogLevel,
@@ -67,6 +95,12 @@ pub enum Command {
     },
     /// Log from JSON input.
     Json {
+        /// Level for entries without one
+        #[arg(long, value_name = "LEVEL")]
+        default_level: Option<String>,
+        /// Scope for entries without one
+        #[arg(long, value_name = "SCOPE")]
+        default_scope: Option<String>,
         /// JSON string (reads stdin if omitted or "-")
         json: Option<String>,
     },
@@ -77,11 +111,86 @@ pub enum Command {
     },
     /// List available presets.
     Presets,
+    /// Print the tail of an app's log file.
+    Show {
+        /// App whose current log file to show
+        #[arg(short, long)]
+        app: Option<String>,
+        /// Number of lines to print
+        #[arg(short = 'n', long, default_value_t = 10)]
+        lines: usize,
+        /// Keep printing lines as they are appended
+        #[arg(short, long)]
+        follow: bool,
+        /// Log file to show instead of the app's
+        file: Option<String>,
+    },
+    /// Search the JSON output file.
+    Query {
+        /// Minimum level
+        #[arg(long, value_enum)]
+        level: Option<LogLevel>,
+        /// Scope (case-insensitive)
+        #[arg(long)]
+        scope: Option<String>,
+        /// App name
+        #[arg(long)]
+        app: Option<String>,
+        /// First day to include (YYYY-MM-DD)
+        #[arg(long, value_name = "DATE")]
+        since: Option<String>,
+        /// Last day to include (YYYY-MM-DD)
+        #[arg(long, value_name = "DATE")]
+        until: Option<String>,
+        /// Only entries whose message contains this text
+        #[arg(long, value_name = "TEXT")]
+        grep: Option<String>,
+        /// Stop after this many matches
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+        /// Render matches through the terminal output
+        #[arg(long)]
+        pretty: bool,
+        /// JSONL file to search instead of `json.path`
+        file: Option<String>,
+    },
+    /// Convert an ANSI-colored log to HTML.
+    Export {
+        /// Output format
+        #[arg(long, default_value = "html")]
+        format: String,
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+        /// Log file to convert (stdin if omitted)
+        file: Option<String>,
+    },
+    /// Print the effective config after includes and app overrides.
+    Config {
+        /// Apply `[apps.<name>]` overrides
+        #[arg(short, long)]
+        app: Option<String>,
+        /// Print only the fields the app overrides
+        #[arg(long, requires = "app")]
+        diff: bool,
+    },
     /// Show log statistics.
     Stats {
         /// Filter by app name
         #[arg(short, long)]
         app: Option<String>,
+        /// Comma-separated extensions to count (default log,gz,zst)
+        #[arg(long, value_name = "LIST")]
+        ext: Option<String>,
+        /// Show log volume per day
+        #[arg(long)]
+        histogram: bool,
+        /// Print the statistics as JSON
+        #[arg(long, conflicts_with = "csv")]
+        json: bool,
+        /// Print one CSV row per file
+        #[arg(long)]
+        csv: bool,
     },
     /// Clean up old logs.
     Cleanup {
@@ -100,12 +209,18 @@ pub enum Command {
         /// Always keep the N most recent files
         #[arg(long, value_name = "N")]
         keep_last: Option<usize>,
+        /// Always keep the N most recent files of each app
+        #[arg(long, value_name = "N")]
+        keep_last_per_app: Option<usize>,
         /// Filter by app name
         #[arg(long)]
         app: Option<String>,
         /// Compress files instead of deleting
         #[arg(long)]
         compress: bool,
+        /// Move files into DIR instead of deleting, keeping their subpath
+        #[arg(long, value_name = "DIR", conflicts_with = "compress")]
+        move_to: Option<String>,
         /// Delete files modified before DATE (YYYY-MM-DD)
         #[arg(long, value_name = "DATE")]
         before: Option<String>,
@@ -121,13 +236,15 @@ pub enum Command {
     },
 }
 
-#[cfg(feature = "hyprland")]
-pub use commands::cmd_watch;
 #[cfg(feature = "rserver")]
 pub use commands::cmd_server;
+#[cfg(feature = "hyprland")]
+pub use commands::cmd_watch;
 pub use commands::{
-    cmd_cleanup, cmd_json, cmd_log, cmd_log_shorthand, cmd_preset, cmd_presets, cmd_send,
-    cmd_stats, cmd_themes,
+    cmd_cleanup, cmd_config, cmd_export, cmd_json, cmd_log, cmd_log_shorthand, cmd_preset,
+    cmd_presets, cmd_query, cmd_send, cmd_show, cmd_stats, cmd_themes,
 };
 pub use preset::PresetRunner;
-pub use util::{build_logger, expand_path, parse_level, print_help};
+pub use util::{
+    apply_color_choice, build_logger, expand_path, parse_level, print_help, take_color_flag,
+};
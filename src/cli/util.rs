@@ -1,8 +1,10 @@
 //! Utility functions for the CLI.
 
+use super::ColorChoice;
 use crate::config::Config;
 use crate::level::Level;
 use crate::logger::Logger;
+use clap::ValueEnum;
 use std::path::PathBuf;
 
 /// Parses a level string to a Level enum.
@@ -32,6 +34,43 @@ pub fn expand_path(path: &str) -> PathBuf {
     PathBuf::from(path)
 }
 
+/// Removes a global `--color <when>` or `--color=<when>` flag from `args`.
+///
+/// Only a flag before the subcommand counts, so `--color` inside a message
+/// (`hyprslog info NET --color`) is left alone.
+///
+/// # Errors
+/// Returns a message if the value is missing or not `auto`, `always` or `never`.
+pub fn take_color_flag(args: &mut Vec<String>) -> Result<Option<ColorChoice>, String> {
+    let Some(flag) = args.first() else {
+        return Ok(None);
+    };
+    if flag != "--color" && !flag.starts_with("--color=") {
+        return Ok(None);
+    }
+
+    let flag = args.remove(0);
+    let value = match flag.strip_prefix("--color=") {
+        Some(value) => value.to_string(),
+        None if !args.is_empty() => args.remove(0),
+        None => return Err("--color requires a value (auto, always, never)".to_string()),
+    };
+    ColorChoice::from_str(&value, true)
+        .map(Some)
+        .map_err(|_| format!("Invalid --color value: {value} (expected auto, always, never)"))
+}
+
+/// Forces terminal colors on or off per `choice`, including app overrides.
+pub fn apply_color_choice(config: &mut Config, choice: ColorChoice) {
+    let enabled = choice.enabled();
+    config.terminal.colors = enabled;
+    for app in config.apps.values_mut() {
+        if let Some(terminal) = &mut app.terminal {
+            terminal.colors = Some(enabled);
+        }
+    }
+}
+
 /// Builds a logger from config with optional app name override.
 ///
 /// If no override is given, the app name is auto-detected from the binary name.
@@ -48,7 +87,10 @@ fn detect_binary_name() -> String {
         .as_deref()
         .map(std::path::Path::new)
         .and_then(std::path::Path::file_name)
-        .map_or_else(|| "unknown".to_string(), |n| n.to_string_lossy().into_owned())
+        .map_or_else(
+            || "unknown".to_string(),
+            |n| n.to_string_lossy().into_owned(),
+        )
 }
 
 /// Prints the help message.
@@ -56,14 +98,16 @@ pub fn print_help() {
     let hyprland_help = if cfg!(feature = "hyprland") {
         "\n  hyprslog watch [options]                  Listen for Hyprland events\
          \n    --events <e1,e2,...>                  Only show specific events\
-         \n    --min-level <level>                  Minimum event level\n"
+         \n    --min-level <level>                  Minimum event level\
+         \n    --stdin                              Replay raw events from stdin\n"
     } else {
         ""
     };
 
     let hyprland_examples = if cfg!(feature = "hyprland") {
         "\n  hyprslog watch\
-         \n  hyprslog watch --events openwindow,closewindow"
+         \n  hyprslog watch --events openwindow,closewindow\
+         \n  hyprslog watch --stdin < socket2.log"
     } else {
         ""
     };
@@ -73,12 +117,38 @@ pub fn print_help() {
 
 Usage:
   hyprslog                                   Enter interactive shell
-  hyprslog log <app> <level> <scope> <msg>   Log a message for specific app
+  hyprslog log [--app <name>] <level> <scope> <msg>
+                                             Log a message (app defaults to config)
   hyprslog [<app>] <level> <scope> <msg>     Shorthand (app defaults to 'hyprslog')
-  hyprslog json [<json>]                     Log from JSON (or stdin with -)
+  hyprslog json [options] [<json>]           Log from JSON (or stdin with -)
+    --default-level <level>                 Level for entries without one
+    --default-scope <scope>                 Scope for entries without one
   hyprslog preset <name>                     Run a preset
   hyprslog presets                           List available presets
-  hyprslog stats [--app <name>]              Show log statistics
+  hyprslog stats [options]                   Show log statistics
+    --app <name>                            Filter by app name
+    --ext <list>                            Extensions to count (default log,gz,zst)
+    --histogram                             Show log volume per day
+    --json                                  Print the statistics as JSON
+    --csv                                   Print one CSV row per file
+  hyprslog config [--app <name>]             Print the effective config
+    --diff                                  Only the fields --app overrides
+  hyprslog show [options] [<file>]           Print the tail of an app's log file
+    --app <name>                            App whose log to show
+    --lines <N>                             Number of lines (default 10)
+    --follow                                Keep printing appended lines
+  hyprslog query [options] [<file>]          Search the JSON output file
+    --level <level>                         Minimum level
+    --scope <scope>                         Scope (case-insensitive)
+    --app <name>                            App name
+    --since <DATE>                          First day to include (YYYY-MM-DD)
+    --until <DATE>                          Last day to include (YYYY-MM-DD)
+    --grep <text>                           Message contains text
+    --limit <N>                             Stop after N matches
+    --pretty                                Render matches like terminal output
+  hyprslog export [options] [<file>]         Convert an ANSI-colored log to HTML
+    --format html                           Output format (default html)
+    --output <path>                         Write to a file instead of stdout
   hyprslog themes [list|preview]             List or preview prompt themes
   hyprslog cleanup [options]                 Clean up old logs
     --older-than <N>d                       Delete files older than N days
@@ -86,13 +156,19 @@ Usage:
     --after <DATE>                          Delete files modified after DATE (YYYY-MM-DD)
     --max-size <size>                       Keep total size under limit (e.g., 500M, 1G)
     --keep-last <N>                         Always keep the N most recent files
+    --keep-last-per-app <N>                 Always keep the N most recent files of each app
     --compress                              Compress files (gzip) instead of deleting
+    --move-to <dir>                         Move files into <dir> instead of deleting
     --app <name>                            Filter by app name
     --all                                   Delete all files
     --dry-run                               Show what would be done{hyprland_help}
   hyprslog help                              Show this help
   hyprslog version                           Show version
 
+Global options:
+  --color <auto|always|never>                Override terminal.colors (auto: only on a tty);
+                                             goes before the command
+
 Config defaults (in ~/.config/hypr/hyprs/log.conf):
   [cleanup]
   max_age_days = 30
@@ -104,10 +180,16 @@ Levels: trace, debug, info, warn, error
 Examples:
   hyprslog info INIT \"Application started\"
   hyprslog myapp info INIT \"Application started\"
-  hyprslog log myapp error NET \"Connection failed\"
+  hyprslog log --app myapp error NET \"Connection failed\"
   hyprslog cleanup --dry-run
   hyprslog cleanup --compress --older-than 7d --keep-last 5
   hyprslog cleanup --before 2024-01-01 --dry-run
+  hyprslog cleanup --move-to /mnt/archive/logs --older-than 30d
+  hyprslog config --app myapp
+  hyprslog show --app myapp --follow
+  hyprslog query --level error --scope NET --since 2024-01-01 --limit 50
+  hyprslog export --format html session.log > session.html
+  hyprslog --color always info NET \"up\" | less -R
   echo '{{\"level\":\"info\",\"scope\":\"TEST\",\"msg\":\"hello\"}}' | hyprslog json{hyprland_examples}"
     );
 }
@@ -0,0 +1,239 @@
+//! Wildcard expansion for `source = "dir/*.conf"` includes.
+//!
+//! Follows the syntax of the `glob` crate, which isn't a dependency:
+//! `*`, `?`, `[...]` classes, `**` for any depth of directories, and
+//! bracketing (`[*]`) to match a wildcard character literally.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Returns true if `path` contains a `*`, `?` or `[` wildcard.
+pub(super) fn is_pattern(path: &str) -> bool {
+    path.contains(['*', '?', '['])
+}
+
+/// Expands a path pattern into the existing files it matches, sorted.
+///
+/// Wildcards may appear in any component: `*` matches any run of characters,
+/// `?` a single one and `[abc]`, `[a-z]` or `[!abc]` one character from (or
+/// not from) a set. A component that is exactly `**` matches zero or more
+/// directories. Like the shell, wildcards don't match a leading `.`.
+pub(super) fn expand(pattern: &Path) -> Vec<PathBuf> {
+    let mut matches = vec![PathBuf::new()];
+
+    for component in pattern.components() {
+        let part = component.as_os_str().to_string_lossy();
+        if part == "**" {
+            matches = matches
+                .into_iter()
+                .flat_map(|dir| {
+                    let mut found = vec![dir.clone()];
+                    push_subdirs(&dir, &mut found);
+                    found
+                })
+                .collect();
+            continue;
+        }
+        if !is_pattern(&part) {
+            for path in &mut matches {
+                path.push(component);
+            }
+            continue;
+        }
+
+        let tokens = parse(&part);
+        matches = matches
+            .into_iter()
+            .flat_map(|dir| {
+                let mut found: Vec<PathBuf> = entries(&dir)
+                    .filter(|(name, _)| wildcard_match(&tokens, name))
+                    .map(|(_, path)| path)
+                    .collect();
+                found.sort();
+                found
+            })
+            .collect();
+    }
+
+    matches.retain(|path| path.is_file());
+    matches.dedup();
+    matches
+}
+
+/// Lists the named entries of `dir`, treating an empty path as `.`.
+fn entries(dir: &Path) -> impl Iterator<Item = (String, PathBuf)> + '_ {
+    let read_from = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    fs::read_dir(read_from)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter_map(move |entry| {
+            let name = entry.file_name().into_string().ok()?;
+            Some((name, dir.join(entry.file_name())))
+        })
+}
+
+/// Appends every non-hidden directory below `dir`, depth first and sorted.
+fn push_subdirs(dir: &Path, found: &mut Vec<PathBuf>) {
+    let mut subdirs: Vec<PathBuf> = entries(dir)
+        .filter(|(name, path)| !name.starts_with('.') && path.is_dir())
+        .map(|(_, path)| path)
+        .collect();
+    subdirs.sort();
+    for subdir in subdirs {
+        found.push(subdir.clone());
+        push_subdirs(&subdir, found);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Literal(char),
+    AnyChar,
+    AnyRun,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+/// Splits a path component into wildcard tokens.
+///
+/// A `[` without a closing `]` is a literal, as is a `]` straight after the
+/// opening bracket, so `[]]` matches `]` and `[[]` matches `[`.
+fn parse(pattern: &str) -> Vec<Token> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '*' => tokens.push(Token::AnyRun),
+            '?' => tokens.push(Token::AnyChar),
+            '[' => {
+                if let Some((token, end)) = parse_class(&chars, i + 1) {
+                    tokens.push(token);
+                    i = end;
+                } else {
+                    tokens.push(Token::Literal('['));
+                }
+            }
+            c => tokens.push(Token::Literal(c)),
+        }
+        i += 1;
+    }
+
+    tokens
+}
+
+/// Parses a class starting after its `[`, returning it and the index of `]`.
+fn parse_class(chars: &[char], start: usize) -> Option<(Token, usize)> {
+    let negated = chars.get(start) == Some(&'!');
+    let mut i = if negated { start + 1 } else { start };
+    let first = i;
+    let mut ranges = Vec::new();
+
+    while let Some(&c) = chars.get(i) {
+        if c == ']' && i > first {
+            return Some((Token::Class { negated, ranges }, i));
+        }
+        if chars.get(i + 1) == Some(&'-')
+            && let Some(&end) = chars.get(i + 2)
+            && end != ']'
+        {
+            ranges.push((c, end));
+            i += 3;
+        } else {
+            ranges.push((c, c));
+            i += 1;
+        }
+    }
+
+    None
+}
+
+/// Matches `name` against parsed wildcard tokens.
+fn wildcard_match(pattern: &[Token], name: &str) -> bool {
+    if name.starts_with('.') && pattern.first() != Some(&Token::Literal('.')) {
+        return false;
+    }
+
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position after the last `*` and the name index it was tried against
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        let step = match pattern.get(p) {
+            Some(Token::AnyRun) => {
+                backtrack = Some((p + 1, n));
+                p += 1;
+                continue;
+            }
+            Some(Token::AnyChar) => true,
+            Some(Token::Literal(c)) => *c == name[n],
+            Some(Token::Class { negated, ranges }) => {
+                ranges.iter().any(|&(lo, hi)| (lo..=hi).contains(&name[n])) != *negated
+            }
+            None => false,
+        };
+
+        if step {
+            p += 1;
+            n += 1;
+        } else if let Some((star_p, star_n)) = backtrack {
+            p = star_p;
+            n = star_n + 1;
+            backtrack = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|token| *token == Token::AnyRun)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        wildcard_match(&parse(pattern), name)
+    }
+
+    #[test]
+    fn wildcards_match_like_the_shell() {
+        assert!(matches("*.conf", "10-colors.conf"));
+        assert!(matches("?0-*.conf", "10-colors.conf"));
+        assert!(matches("*", "anything"));
+        assert!(!matches("*.conf", "colors.toml"));
+        assert!(!matches("*.conf", ".hidden.conf"));
+        assert!(matches(".*.conf", ".hidden.conf"));
+        assert!(!matches("a?c", "ac"));
+    }
+
+    #[test]
+    fn character_classes_match_one_char() {
+        assert!(matches("[12]0-*.conf", "10-colors.conf"));
+        assert!(matches("[0-9]*.conf", "20-presets.conf"));
+        assert!(!matches("[0-9]*.conf", "colors.conf"));
+        assert!(matches("[!0-9]*.conf", "colors.conf"));
+        assert!(!matches("[!0-9]*.conf", "10-colors.conf"));
+        assert!(matches("[a-]", "-"));
+    }
+
+    #[test]
+    fn brackets_escape_wildcards() {
+        assert!(matches("a[*]b", "a*b"));
+        assert!(!matches("a[*]b", "axb"));
+        assert!(matches("[?]", "?"));
+        assert!(matches("[[]x", "[x"));
+        assert!(matches("[]]", "]"));
+        // An unclosed bracket is just a character
+        assert!(matches("a[b", "a[b"));
+    }
+}
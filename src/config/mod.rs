@@ -1,23 +1,26 @@
 //! Optional TOML configuration for hyprslog.
 
+mod glob;
 mod structs;
 
 pub use structs::{
     AppConfig, AppFileConfig, AppTerminalConfig, CleanupConfig, FileConfig, GeneralConfig,
-    HighlightConfig, HyprlandConfig, IconsConfig, JsonConfig, MessageConfigFile, PatternsConfig,
-    PresetConfig, RetentionConfig, ScopeConfigFile, ShellConfig, TagConfigFile, TerminalConfig,
+    HighlightConfig, HyprlandConfig, IconsConfig, JsonConfig, KeywordRule, MergeStrategy,
+    MessageConfigFile, PatternsConfig, PresetConfig, RetentionConfig, ScopeConfigFile, ShellConfig,
+    TagConfigFile, TerminalConfig,
 };
 
-use crate::fmt::{Alignment, Color, IconType, Transform};
+use crate::fmt::{Alignment, Color, FormatTemplate, IconType, Timezone, Transform};
 use crate::internal;
 use crate::level::Level;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 /// Root configuration structure.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(default)]
 pub struct Config {
     /// General settings.
@@ -48,6 +51,8 @@ pub struct Config {
     pub icons: IconsConfig,
     /// Log presets/dictionary.
     pub presets: HashMap<String, PresetConfig>,
+    /// Per-scope minimum levels (key is the scope, value a level name).
+    pub scopes: HashMap<String, String>,
     /// Per-app configuration overrides.
     /// Key is the app name (e.g., "sysrat"), value contains overrides.
     pub apps: HashMap<String, AppConfig>,
@@ -80,6 +85,75 @@ pub fn extract_sources(content: &str) -> (Vec<String>, String) {
     (sources, remaining)
 }
 
+/// Deep-merges `source` into `main`; `strategy` decides keys set in both.
+///
+/// Nested tables merge recursively; any other value (including arrays) is
+/// taken whole from the winning side.
+fn merge_tables(main: &mut toml::Table, source: toml::Table, strategy: MergeStrategy) {
+    for (key, value) in source {
+        match (main.get_mut(&key), value) {
+            (Some(toml::Value::Table(main_sub)), toml::Value::Table(source_sub)) => {
+                merge_tables(main_sub, source_sub, strategy);
+            }
+            (Some(existing), value) => {
+                if strategy == MergeStrategy::SourceWins {
+                    *existing = value;
+                }
+            }
+            (None, value) => {
+                main.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Collects the differing leaves of two TOML tables under `prefix`.
+fn diff_tables(
+    prefix: &str,
+    old: &toml::Table,
+    new: &toml::Table,
+    changes: &mut Vec<(String, String, String)>,
+) {
+    let empty = toml::Table::new();
+    let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    for key in keys {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match (old.get(key), new.get(key)) {
+            (Some(a), Some(b)) if a == b => {}
+            (Some(toml::Value::Table(a)), Some(toml::Value::Table(b))) => {
+                diff_tables(&path, a, b, changes);
+            }
+            (Some(toml::Value::Table(a)), None) => diff_tables(&path, a, &empty, changes),
+            (None, Some(toml::Value::Table(b))) => diff_tables(&path, &empty, b, changes),
+            (a, b) => {
+                let render =
+                    |v: Option<&toml::Value>| v.map(ToString::to_string).unwrap_or_default();
+                changes.push((path, render(a), render(b)));
+            }
+        }
+    }
+}
+
+impl FromStr for Config {
+    type Err = crate::Error;
+
+    /// Parses configuration from a TOML string without reading a config file.
+    ///
+    /// `source = "path"` lines are still followed; includes that don't
+    /// exist are skipped.
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let table = Self::parse_table(content, &mut HashSet::new(), None)?;
+        Ok(toml::Value::Table(table).try_into()?)
+    }
+}
+
 impl Config {
     /// Loads configuration from the default location.
     ///
@@ -114,6 +188,20 @@ impl Config {
             return Ok(Self::default());
         }
 
+        let table = Self::load_table(path, seen, None)?;
+        Ok(toml::Value::Table(table).try_into()?)
+    }
+
+    /// Reads a config file as a TOML table with its sources merged in.
+    ///
+    /// Works on raw tables so only keys a file actually sets take part in the
+    /// merge. `strategy` is `None` for the root file, which picks it with
+    /// `general.merge_strategy` for the whole include tree.
+    fn load_table(
+        path: &Path,
+        seen: &mut HashSet<PathBuf>,
+        strategy: Option<MergeStrategy>,
+    ) -> Result<toml::Table, crate::Error> {
         let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
 
         if !seen.insert(canonical.clone()) {
@@ -125,25 +213,108 @@ impl Config {
         }
 
         let content = fs::read_to_string(path)?;
-        let (sources, toml_content) = extract_sources(&content);
-        let mut config: Self = toml::from_str(&toml_content)?;
+        Self::parse_table(&content, seen, strategy)
+    }
+
+    /// Parses config content as a TOML table with its sources merged in.
+    ///
+    /// Missing source files are skipped with a warning, so content that
+    /// isn't backed by a file still parses.
+    fn parse_table(
+        content: &str,
+        seen: &mut HashSet<PathBuf>,
+        strategy: Option<MergeStrategy>,
+    ) -> Result<toml::Table, crate::Error> {
+        let (sources, toml_content) = extract_sources(content);
+        let mut table: toml::Table = toml::from_str(&toml_content)?;
+        let strategy = strategy.unwrap_or_else(|| {
+            table
+                .get("general")
+                .and_then(|general| general.get("merge_strategy"))
+                .and_then(|value| value.clone().try_into().ok())
+                .unwrap_or_default()
+        });
 
         for source_path in sources {
             internal::debug("CONFIG", &format!("Processing source: {source_path}"));
             let expanded = shellexpand::tilde(&source_path);
             let source_file = Path::new(expanded.as_ref());
-            if source_file.exists() {
-                let source_config = Self::load_with_sources(source_file, seen)?;
-                config.merge(source_config);
+            if glob::is_pattern(&expanded) {
+                // Matches merge in sorted order; each still goes through cycle detection
+                let matches = glob::expand(source_file);
+                if matches.is_empty() {
+                    internal::debug("CONFIG", &format!("No files match: {source_path}"));
+                }
+                for matched in matches {
+                    let source_table = Self::load_table(&matched, seen, Some(strategy))?;
+                    Self::merge_source(&mut table, source_table, strategy)?;
+                }
+            } else if source_file.exists() {
+                let source_table = Self::load_table(source_file, seen, Some(strategy))?;
+                Self::merge_source(&mut table, source_table, strategy)?;
             } else {
                 internal::warn("CONFIG", &format!("Source file not found: {source_path}"));
             }
         }
 
-        Ok(config)
+        Ok(table)
+    }
+
+    /// Merges an include's table into the including file's table.
+    ///
+    /// [`MergeStrategy::MainWins`] goes through [`merge`](Self::merge), so the
+    /// include only adds map entries; the other strategies merge key by key.
+    fn merge_source(
+        table: &mut toml::Table,
+        source: toml::Table,
+        strategy: MergeStrategy,
+    ) -> Result<(), crate::Error> {
+        if strategy != MergeStrategy::MainWins {
+            merge_tables(table, source, strategy);
+            return Ok(());
+        }
+
+        let mut config: Self = toml::Value::Table(std::mem::take(table)).try_into()?;
+        config.merge(toml::Value::Table(source).try_into()?);
+        *table = toml::Table::try_from(&config).map_err(|e| crate::Error::Format(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Merges another config into self using `strategy` for shared keys.
+    ///
+    /// Both configs are complete, so every scalar counts as set:
+    /// [`MergeStrategy::MainWins`] and [`MergeStrategy::Fill`] keep all of
+    /// self's scalars and add only missing map entries (same as
+    /// [`merge`](Self::merge)); [`MergeStrategy::SourceWins`] takes every
+    /// scalar and shared map entry from `other`, keeping map entries only
+    /// self has.
+    pub fn merge_with(&mut self, other: Self, strategy: MergeStrategy) {
+        if strategy != MergeStrategy::SourceWins {
+            self.merge(other);
+            return;
+        }
+
+        let merged = toml::Table::try_from(&*self)
+            .and_then(|mut table| {
+                merge_tables(&mut table, toml::Table::try_from(&other)?, strategy);
+                Ok(table)
+            })
+            .map_err(|e| e.to_string())
+            .and_then(|table| {
+                toml::Value::Table(table)
+                    .try_into::<Self>()
+                    .map_err(|e| e.to_string())
+            });
+        match merged {
+            Ok(config) => *self = config,
+            Err(e) => internal::warn("CONFIG", &format!("Config merge failed: {e}")),
+        }
     }
 
-    /// Merges another config into self.
+    /// Merges another config into self; self wins.
+    ///
+    /// Only map sections (colors, presets, icons, labels, ...) are merged:
+    /// entries missing from self are added. Scalar settings stay as in self.
     pub fn merge(&mut self, other: Self) {
         for (k, v) in other.colors {
             self.colors.entry(k).or_insert(v);
@@ -151,15 +322,27 @@ impl Config {
         for (k, v) in other.presets {
             self.presets.entry(k).or_insert(v);
         }
+        for (k, v) in other.scopes {
+            self.scopes.entry(k).or_insert(v);
+        }
         for (k, v) in other.icons.nerdfont {
             self.icons.nerdfont.entry(k).or_insert(v);
         }
         for (k, v) in other.icons.ascii {
             self.icons.ascii.entry(k).or_insert(v);
         }
+        for (k, v) in other.icons.emoji {
+            self.icons.emoji.entry(k).or_insert(v);
+        }
+        for (k, v) in other.terminal.structure_overrides {
+            self.terminal.structure_overrides.entry(k).or_insert(v);
+        }
         for (k, v) in other.tag.labels {
             self.tag.labels.entry(k).or_insert(v);
         }
+        for (k, v) in other.scope.colors {
+            self.scope.colors.entry(k).or_insert(v);
+        }
         for (k, v) in other.highlight.keywords {
             self.highlight.keywords.entry(k).or_insert(v);
         }
@@ -212,6 +395,156 @@ impl Config {
         config
     }
 
+    /// Returns a warning for each preset whose level (or `as`) isn't a level name.
+    ///
+    /// [`Logger::preset`](crate::Logger::preset) still runs such presets, at
+    /// info level. Sorted by preset name.
+    #[must_use]
+    pub fn validate_presets(&self) -> Vec<String> {
+        let mut names: Vec<&String> = self.presets.keys().collect();
+        names.sort_unstable();
+        names
+            .into_iter()
+            .filter_map(|name| {
+                self.presets[name]
+                    .validate()
+                    .map(|problem| format!("presets.{name}.{problem}"))
+            })
+            .collect()
+    }
+
+    /// Returns warnings for settings that parse but won't behave as written.
+    ///
+    /// Checks level names, icon types, and template placeholders. Each entry
+    /// names the offending key, e.g. `general.level: unknown level "verbose"`.
+    #[must_use]
+    pub fn validate(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        let mut check_level = |key: &str, value: &str| {
+            if value.parse::<Level>().is_err() {
+                warnings.push(format!("{key}: unknown level {value:?}"));
+            }
+        };
+        check_level("general.level", &self.general.level);
+        check_level("json.default_level", &self.json.default_level);
+        for (scope, level) in &self.scopes {
+            check_level(&format!("scopes.{scope}"), level);
+        }
+        for (app, app_config) in &self.apps {
+            if let Some(level) = &app_config.level {
+                check_level(&format!("apps.{app}.level"), level);
+            }
+        }
+        for (table, map) in [
+            ("tag.labels", &self.tag.labels),
+            ("icons.nerdfont", &self.icons.nerdfont),
+            ("icons.ascii", &self.icons.ascii),
+            ("icons.emoji", &self.icons.emoji),
+            (
+                "terminal.structure_overrides",
+                &self.terminal.structure_overrides,
+            ),
+        ] {
+            for key in map.keys() {
+                check_level(&format!("{table}.{key}"), key);
+            }
+        }
+
+        for level in &self.tag.hide {
+            check_level("tag.hide", level);
+        }
+
+        warnings.extend(self.validate_presets());
+
+        if let Some(size) = &self.json.max_file_size
+            && crate::cleanup::parse_size(size).is_none()
+        {
+            warnings.push(format!("json.max_file_size: invalid size {size:?}"));
+        }
+
+        if let Err(e) = self.file.timezone.parse::<Timezone>() {
+            warnings.push(format!("file.timezone: {e}"));
+        }
+
+        if !matches!(
+            self.terminal.icons.to_lowercase().as_str(),
+            "nerdfont" | "ascii" | "emoji" | "none"
+        ) {
+            warnings.push(format!(
+                "terminal.icons: unknown icon type {:?}",
+                self.terminal.icons
+            ));
+        }
+
+        for (table, map) in [
+            ("colors", &self.colors),
+            ("scope.colors", &self.scope.colors),
+        ] {
+            for (key, value) in map {
+                // scope.colors may also name an entry of [colors]
+                if table == "scope.colors" && self.colors.contains_key(value) {
+                    continue;
+                }
+                if let Err(e) = Color::parse(value) {
+                    warnings.push(format!("{table}.{key}: {e}"));
+                }
+            }
+        }
+
+        let mut templates = vec![
+            ("terminal.structure".to_string(), &self.terminal.structure),
+            ("file.path_structure".to_string(), &self.file.path_structure),
+            (
+                "file.filename_structure".to_string(),
+                &self.file.filename_structure,
+            ),
+            (
+                "file.content_structure".to_string(),
+                &self.file.content_structure,
+            ),
+        ];
+        for (level, structure) in &self.terminal.structure_overrides {
+            templates.push((format!("terminal.structure_overrides.{level}"), structure));
+        }
+        for (key, template) in templates {
+            for name in FormatTemplate::parse(template).validate() {
+                warnings.push(format!("{key}: unknown placeholder {{{name}}}"));
+            }
+        }
+
+        warnings.sort();
+        warnings
+    }
+
+    /// Serializes the config as TOML with tables and keys sorted.
+    ///
+    /// # Errors
+    /// Returns error if serialization fails.
+    pub fn to_toml(&self) -> Result<String, crate::Error> {
+        // Going through a table sorts keys, so HashMap order doesn't leak
+        let table = toml::Table::try_from(self)
+            .map_err(|e| crate::Error::Format(format!("TOML serialization failed: {e}")))?;
+        toml::to_string_pretty(&table)
+            .map_err(|e| crate::Error::Format(format!("TOML serialization failed: {e}")))
+    }
+
+    /// Returns the fields whose values differ from `other`, as
+    /// `(key_path, old_value, new_value)` sorted by key path.
+    ///
+    /// Walks both configs' TOML serialization, so key paths match the config
+    /// file (`terminal.colors`, `tag.labels.warn`). Values are rendered as
+    /// TOML; a key missing on one side renders as an empty string. Arrays
+    /// compare as a whole. Handy for seeing what [`for_app`](Self::for_app)
+    /// overrides.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<(String, String, String)> {
+        let table = |config: &Self| toml::Table::try_from(config).unwrap_or_default();
+        let mut changes = Vec::new();
+        diff_tables("", &table(self), &table(other), &mut changes);
+        changes
+    }
+
     /// Returns the default config file path.
     ///
     /// # Errors
@@ -228,11 +561,27 @@ impl Config {
         self.general.level.parse().unwrap_or(Level::Info)
     }
 
+    /// Parses `[scopes]` into per-scope levels, skipping invalid level names.
+    #[must_use]
+    pub fn parse_scope_levels(&self) -> HashMap<String, Level> {
+        self.scopes
+            .iter()
+            .filter_map(|(scope, level)| Some((scope.clone(), level.parse().ok()?)))
+            .collect()
+    }
+
+    /// Parses the file time zone, falling back to local time.
+    #[must_use]
+    pub fn parse_timezone(&self) -> Timezone {
+        self.file.timezone.parse().unwrap_or_default()
+    }
+
     /// Parses the terminal icon type.
     #[must_use]
     pub fn parse_icon_type(&self) -> IconType {
         match self.terminal.icons.to_lowercase().as_str() {
             "ascii" => IconType::Ascii,
+            "emoji" => IconType::Emoji,
             "none" => IconType::None,
             _ => IconType::NerdFont,
         }
@@ -292,8 +641,13 @@ impl Config {
     }
 
     /// Parses a color from the colors map.
+    ///
+    /// Values use any form accepted by [`Color::parse`]; `None` if `name` is
+    /// missing or its value doesn't parse.
     #[must_use]
     pub fn get_color(&self, name: &str) -> Option<Color> {
-        self.colors.get(name).map(|hex| Color::from_hex(hex))
+        self.colors
+            .get(name)
+            .and_then(|value| Color::parse(value).ok())
     }
 }
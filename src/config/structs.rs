@@ -1,16 +1,19 @@
 //! Configuration struct definitions.
 
-use serde::Deserialize;
+use crate::level::Level;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// General configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct GeneralConfig {
     /// Minimum log level.
     pub level: String,
     /// Application name.
     pub app_name: Option<String>,
+    /// Which side wins when `source` includes set the same key (root file only).
+    pub merge_strategy: MergeStrategy,
 }
 
 impl Default for GeneralConfig {
@@ -18,22 +21,45 @@ impl Default for GeneralConfig {
         Self {
             level: "info".to_string(),
             app_name: None,
+            merge_strategy: MergeStrategy::default(),
         }
     }
 }
 
+/// How conflicting keys are resolved when configs are merged.
+///
+/// With [`Fill`](Self::Fill) and [`SourceWins`](Self::SourceWins), tables
+/// (sections and maps such as `[colors]`) merge key by key and the strategy
+/// only decides keys set on both sides. Arrays are replaced whole, not
+/// concatenated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Includes only add map entries (colors, presets, icons, ...) the main
+    /// file lacks; scalar settings come from the main file alone.
+    #[default]
+    MainWins,
+    /// The main file keeps its values; includes fill every key it leaves
+    /// unset, scalar settings included.
+    Fill,
+    /// Included files override the including file, later includes last.
+    SourceWins,
+}
+
 /// Terminal output configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct TerminalConfig {
     /// Enable terminal output.
     pub enabled: bool,
     /// Enable colors.
     pub colors: bool,
-    /// Icon type (nerdfont, ascii, none).
+    /// Icon type (nerdfont, ascii, emoji, none).
     pub icons: String,
     /// Output structure template.
     pub structure: String,
+    /// Per-level structure templates (key is the level name).
+    pub structure_overrides: HashMap<String, String>,
 }
 
 impl Default for TerminalConfig {
@@ -43,12 +69,13 @@ impl Default for TerminalConfig {
             colors: true,
             icons: "nerdfont".to_string(),
             structure: "{tag} {scope}  {msg}".to_string(),
+            structure_overrides: HashMap::new(),
         }
     }
 }
 
 /// Shell configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct ShellConfig {
     /// Prompt theme.
@@ -64,7 +91,7 @@ impl Default for ShellConfig {
 }
 
 /// File output configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct FileConfig {
     /// Enable file output.
@@ -79,6 +106,10 @@ pub struct FileConfig {
     pub content_structure: String,
     /// Timestamp format.
     pub timestamp_format: String,
+    /// Time zone for timestamps: `local`, `utc` or an offset like `+02:00`.
+    pub timezone: String,
+    /// Pad `{scope}` to `scope.min_width` (disable for files read by log parsers).
+    pub pad_scope: bool,
     /// Retention settings.
     pub retention: RetentionConfig,
 }
@@ -103,13 +134,15 @@ impl Default for FileConfig {
             filename_structure: "{scope}_{level}_{day}.log".to_string(),
             content_structure: "{timestamp} {tag} {scope}  {msg}".to_string(),
             timestamp_format: "%Y-%m-%d %H:%M:%S".to_string(),
+            timezone: "local".to_string(),
+            pad_scope: true,
             retention: RetentionConfig::default(),
         }
     }
 }
 
 /// Log retention configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct RetentionConfig {
     /// Maximum age in days.
@@ -128,7 +161,7 @@ impl Default for RetentionConfig {
 }
 
 /// Cleanup configuration defaults.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(default)]
 pub struct CleanupConfig {
     /// Maximum age in days (None = no age limit).
@@ -137,28 +170,35 @@ pub struct CleanupConfig {
     pub max_total_size: Option<String>,
     /// Always keep the N most recent files.
     pub keep_last: Option<usize>,
+    /// Always keep the N most recent files of each app.
+    pub keep_last_per_app: Option<usize>,
     /// Compress files older than N days instead of deleting.
     pub compress_after_days: Option<u32>,
+    /// Directory levels below the base directory to search.
+    pub max_depth: Option<usize>,
 }
 
 /// Message formatting configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct MessageConfigFile {
     /// Text transform (none, uppercase, lowercase, capitalize).
     pub transform: String,
+    /// Cut terminal messages to this many columns (None = no limit).
+    pub max_len: Option<usize>,
 }
 
 impl Default for MessageConfigFile {
     fn default() -> Self {
         Self {
             transform: "none".to_string(),
+            max_len: None,
         }
     }
 }
 
 /// Scope formatting configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct ScopeConfigFile {
     /// Minimum width (padded if shorter).
@@ -167,6 +207,10 @@ pub struct ScopeConfigFile {
     pub alignment: String,
     /// Text transform (none, uppercase, lowercase, capitalize).
     pub transform: String,
+    /// Derive a stable color per scope from its name.
+    pub hashed_colors: bool,
+    /// Explicit scope colors (scope name -> hex or `[colors]` name).
+    pub colors: HashMap<String, String>,
 }
 
 impl Default for ScopeConfigFile {
@@ -175,12 +219,14 @@ impl Default for ScopeConfigFile {
             min_width: 12,
             alignment: "left".to_string(),
             transform: "none".to_string(),
+            hashed_colors: false,
+            colors: HashMap::new(),
         }
     }
 }
 
 /// Tag formatting configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct TagConfigFile {
     /// Prefix before tag.
@@ -195,6 +241,8 @@ pub struct TagConfigFile {
     pub alignment: String,
     /// Custom labels per level.
     pub labels: HashMap<String, String>,
+    /// Levels that render no tag at all (e.g. `["info"]`).
+    pub hide: Vec<String>,
 }
 
 impl Default for TagConfigFile {
@@ -206,22 +254,25 @@ impl Default for TagConfigFile {
             min_width: 5,
             alignment: "center".to_string(),
             labels: HashMap::new(),
+            hide: Vec::new(),
         }
     }
 }
 
 /// Icons configuration.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(default)]
 pub struct IconsConfig {
     /// Nerd Font icons.
     pub nerdfont: HashMap<String, String>,
     /// ASCII icons.
     pub ascii: HashMap<String, String>,
+    /// Emoji icons.
+    pub emoji: HashMap<String, String>,
 }
 
 /// Preset/dictionary entry.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PresetConfig {
     /// Display label (shown in output).
     pub level: String,
@@ -236,14 +287,49 @@ pub struct PresetConfig {
     pub app_name: Option<String>,
 }
 
+impl PresetConfig {
+    /// Returns the level used for filtering: `as` if set, otherwise `level`.
+    ///
+    /// # Errors
+    /// Returns [`crate::Error::InvalidLevel`] if that string isn't a level name.
+    pub fn filter_level(&self) -> Result<Level, crate::Error> {
+        let value = self.as_level.as_deref().unwrap_or(&self.level);
+        value
+            .parse()
+            .map_err(|_| crate::Error::InvalidLevel(value.to_string()))
+    }
+
+    /// Describes an invalid level string (`level`, or `as` when set), if any.
+    ///
+    /// The description starts with the offending key, e.g. `level: unknown level "inof"`.
+    #[must_use]
+    pub fn validate(&self) -> Option<String> {
+        let key = if self.as_level.is_some() {
+            "as"
+        } else {
+            "level"
+        };
+        self.filter_level().err().map(|_| {
+            format!(
+                "{key}: unknown level {:?}",
+                self.as_level.as_deref().unwrap_or(&self.level)
+            )
+        })
+    }
+}
+
 /// Auto-highlighting configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct HighlightConfig {
     /// Enable auto-highlighting.
     pub enabled: bool,
-    /// Keywords to highlight (keyword -> color name).
-    pub keywords: HashMap<String, String>,
+    /// Keywords to highlight (keyword -> color name or conditional rule).
+    pub keywords: HashMap<String, KeywordRule>,
+    /// Match keywords regardless of case.
+    pub case_insensitive: bool,
+    /// Only match keywords as whole words (`error` won't match `errorless`).
+    pub word_boundary: bool,
     /// Pattern-based highlighting.
     pub patterns: PatternsConfig,
 }
@@ -253,13 +339,85 @@ impl Default for HighlightConfig {
         Self {
             enabled: true,
             keywords: HashMap::new(),
+            case_insensitive: true,
+            word_boundary: true,
             patterns: PatternsConfig::default(),
         }
     }
 }
 
+/// A keyword highlight rule.
+///
+/// A plain color string always applies:
+/// ```toml
+/// [highlight.keywords]
+/// ok = "green"
+/// timeout = { color = "red", levels = ["warn", "error"] }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum KeywordRule {
+    /// Highlight with this color in every record.
+    Color(String),
+    /// Highlight only in records matching the given levels and/or scopes.
+    Conditional {
+        /// Color name or hex value.
+        color: String,
+        /// Levels to apply to (empty = all levels).
+        #[serde(default)]
+        levels: Vec<String>,
+        /// Scopes to apply to, case-insensitive (empty = all scopes).
+        #[serde(default)]
+        scopes: Vec<String>,
+    },
+}
+
+impl KeywordRule {
+    /// Returns the color for this rule.
+    #[must_use]
+    pub fn color(&self) -> &str {
+        match self {
+            Self::Color(color) | Self::Conditional { color, .. } => color,
+        }
+    }
+
+    /// Returns true if the rule applies to a record with this level and scope.
+    ///
+    /// Without a record context, only unconditional rules apply.
+    #[must_use]
+    pub fn applies_to(&self, context: Option<(Level, &str)>) -> bool {
+        match self {
+            Self::Color(_) => true,
+            Self::Conditional { levels, scopes, .. } => {
+                let Some((level, scope)) = context else {
+                    return levels.is_empty() && scopes.is_empty();
+                };
+                let level_ok = levels.is_empty()
+                    || levels
+                        .iter()
+                        .any(|l| l.parse::<Level>().is_ok_and(|l| l == level));
+                let scope_ok =
+                    scopes.is_empty() || scopes.iter().any(|s| s.eq_ignore_ascii_case(scope));
+                level_ok && scope_ok
+            }
+        }
+    }
+}
+
+impl From<String> for KeywordRule {
+    fn from(color: String) -> Self {
+        Self::Color(color)
+    }
+}
+
+impl From<&str> for KeywordRule {
+    fn from(color: &str) -> Self {
+        Self::Color(color.to_string())
+    }
+}
+
 /// Pattern-based highlighting configuration.
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(default)]
 pub struct PatternsConfig {
     /// Color for file paths (/path/to/file, ./relative, ~/home).
@@ -270,10 +428,18 @@ pub struct PatternsConfig {
     pub numbers: Option<String>,
     /// Color for quoted strings ("string" or 'string').
     pub quoted: Option<String>,
+    /// Color for IPv4/IPv6 addresses (192.168.1.10, `fe80::1`).
+    pub ip: Option<String>,
+    /// Color for ISO 8601 timestamps and clock times (2025-01-15T14:30:00Z, 14:30:00).
+    pub timestamp: Option<String>,
 }
 
 /// Hyprland IPC integration configuration.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// Event filtering: when `event_filter` (allowlist) is set, only the events it
+/// names pass; `ignore_events` (denylist) is then applied to what remains. An
+/// event in both lists is therefore dropped. See [`HyprlandConfig::allows_event`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct HyprlandConfig {
     /// Enable Hyprland IPC integration.
@@ -286,8 +452,14 @@ pub struct HyprlandConfig {
     pub event_levels: HashMap<String, String>,
     /// Events to ignore entirely.
     pub ignore_events: Vec<String>,
+    /// Display names per event (event name -> label), e.g. `openwindow = "Window Opened"`.
+    /// Events without an entry are shown by their raw name.
+    pub event_names: HashMap<String, String>,
     /// Scope string used for Hyprland log messages.
     pub scope: String,
+    /// Read/write timeout for command socket requests, in milliseconds.
+    /// `0` disables the timeout.
+    pub command_timeout_ms: u64,
     /// Runtime-only allowlist filter (not deserialized from config).
     /// When set, only events in this list are processed.
     #[serde(skip)]
@@ -302,17 +474,37 @@ impl Default for HyprlandConfig {
             socket_dir: None,
             event_levels: HashMap::new(),
             ignore_events: Vec::new(),
+            event_names: HashMap::new(),
             scope: "HYPR".to_string(),
+            command_timeout_ms: 2000,
             event_filter: None,
         }
     }
 }
 
+impl HyprlandConfig {
+    /// Returns the command socket timeout.
+    #[must_use]
+    pub const fn command_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.command_timeout_ms)
+    }
+
+    /// Returns whether an event passes the allowlist and then the denylist.
+    #[must_use]
+    pub fn allows_event(&self, name: &str) -> bool {
+        let allowed = self
+            .event_filter
+            .as_ref()
+            .is_none_or(|filter| filter.iter().any(|f| f == name));
+        allowed && !self.ignore_events.iter().any(|e| e == name)
+    }
+}
+
 /// Per-app configuration overrides.
 ///
 /// Used in `[apps.X]` sections to override global settings for specific apps.
 /// All fields are optional - only specified fields override the global config.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(default)]
 pub struct AppConfig {
     /// Override log level for this app.
@@ -324,7 +516,7 @@ pub struct AppConfig {
 }
 
 /// Per-app terminal overrides.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(default)]
 pub struct AppTerminalConfig {
     /// Override enabled state.
@@ -338,7 +530,7 @@ pub struct AppTerminalConfig {
 }
 
 /// Per-app file output overrides.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(default)]
 pub struct AppFileConfig {
     /// Override enabled state.
@@ -348,13 +540,21 @@ pub struct AppFileConfig {
 }
 
 /// JSON database output configuration.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(default)]
 pub struct JsonConfig {
     /// Enable JSON database output.
     pub enabled: bool,
     /// Path to the JSONL database file.
     pub path: String,
+    /// Level for `hyprslog json` entries without one.
+    pub default_level: String,
+    /// Scope for `hyprslog json` entries without one.
+    pub default_scope: String,
+    /// Size like "10M" at which the file is rolled to `<path>.1`.
+    pub max_file_size: Option<String>,
+    /// Rolled files to keep.
+    pub max_backups: usize,
 }
 
 impl Default for JsonConfig {
@@ -374,6 +574,10 @@ impl Default for JsonConfig {
         Self {
             enabled: false,
             path,
+            default_level: "info".to_string(),
+            default_scope: "JSON".to_string(),
+            max_file_size: None,
+            max_backups: 5,
         }
     }
 }
@@ -3,7 +3,10 @@
 use std::path::PathBuf;
 
 /// Error type for hyprslog operations.
+///
+/// New variants may be added in minor releases; match with a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// I/O error.
     Io(std::io::Error),
@@ -21,6 +24,10 @@ pub enum Error {
     PresetNotFound(String),
     /// Invalid log level string.
     InvalidLevel(String),
+    /// Conflicting or invalid options.
+    InvalidOptions(String),
+    /// An operation did not complete within its time limit.
+    Timeout(std::time::Duration),
 }
 
 impl std::fmt::Display for Error {
@@ -34,6 +41,8 @@ impl std::fmt::Display for Error {
             Self::InvalidPath(s) => write!(f, "invalid path: {s}"),
             Self::PresetNotFound(name) => write!(f, "preset not found: {name}"),
             Self::InvalidLevel(level) => write!(f, "invalid level in preset: {level}"),
+            Self::InvalidOptions(s) => write!(f, "invalid options: {s}"),
+            Self::Timeout(limit) => write!(f, "timed out after {}ms", limit.as_millis()),
         }
     }
 }
@@ -4,6 +4,7 @@
 
 #![allow(unsafe_code)]
 
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::ffi::{CStr, c_char, c_int};
 use std::path::Path;
@@ -36,6 +37,17 @@ impl HyprslogContext {
     fn clear_error(&self) {
         *self.last_error.borrow_mut() = None;
     }
+
+    /// Converts C string bytes, replacing invalid UTF-8 and noting it as the last error.
+    fn lossy_str<'a>(&self, bytes: &'a [u8], name: &str) -> Cow<'a, str> {
+        let converted = String::from_utf8_lossy(bytes);
+        if let Cow::Owned(_) = converted {
+            self.set_error(format!(
+                "Invalid UTF-8 in {name}, logged with replacement characters"
+            ));
+        }
+        converted
+    }
 }
 
 /// Converts a C int to a Level enum.
@@ -212,9 +224,12 @@ pub unsafe extern "C" fn hyprslog_free(ctx: *mut HyprslogContext) {
 
 /// Logs a message at the specified level.
 ///
+/// Invalid UTF-8 in `scope` or `msg` is replaced with U+FFFD and the message
+/// is still logged; `hyprslog_get_last_error` then reports the lossy conversion.
+///
 /// # Safety
 /// - `ctx` must be a valid context pointer
-/// - `scope` and `msg` must be valid null-terminated UTF-8 strings
+/// - `scope` and `msg` must be valid null-terminated strings
 ///
 /// # Arguments
 /// * `ctx` - Logger context
@@ -236,19 +251,53 @@ pub unsafe extern "C" fn hyprslog_log(
     let context = unsafe { &*ctx };
     context.clear_error();
 
-    // SAFETY: scope is non-null and caller guarantees valid UTF-8
-    let Ok(scope_str) = unsafe { CStr::from_ptr(scope) }.to_str() else {
-        return;
-    };
+    // SAFETY: scope and msg are non-null and null-terminated
+    let (scope_str, msg_str) = unsafe { (CStr::from_ptr(scope), CStr::from_ptr(msg)) };
+    let scope_str = context.lossy_str(scope_str.to_bytes(), "scope");
+    let msg_str = context.lossy_str(msg_str.to_bytes(), "msg");
 
-    // SAFETY: msg is non-null and caller guarantees valid UTF-8
-    let Ok(msg_str) = unsafe { CStr::from_ptr(msg) }.to_str() else {
+    context
+        .logger
+        .log(level_from_int(level), &scope_str, &msg_str);
+}
+
+/// Logs `len` bytes of `msg` at the specified level.
+///
+/// Unlike `hyprslog_log`, `msg` need not be null-terminated: exactly `len`
+/// bytes are read, so slices of larger buffers and embedded NUL bytes are
+/// logged as given. `scope` is still a null-terminated string. Invalid UTF-8
+/// is handled as in `hyprslog_log`.
+///
+/// # Safety
+/// - `ctx` must be a valid context pointer
+/// - `scope` must be a valid null-terminated string
+/// - `msg` must point to at least `len` readable bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hyprslog_log_n(
+    ctx: *mut HyprslogContext,
+    level: c_int,
+    scope: *const c_char,
+    msg: *const c_char,
+    len: usize,
+) {
+    if ctx.is_null() || scope.is_null() || msg.is_null() {
         return;
-    };
+    }
+
+    // SAFETY: ctx is non-null and valid
+    let context = unsafe { &*ctx };
+    context.clear_error();
+
+    // SAFETY: scope is non-null and null-terminated
+    let scope_str = unsafe { CStr::from_ptr(scope) };
+    // SAFETY: msg is non-null and caller guarantees len readable bytes
+    let msg_bytes = unsafe { std::slice::from_raw_parts(msg.cast::<u8>(), len) };
+    let scope_str = context.lossy_str(scope_str.to_bytes(), "scope");
+    let msg_str = context.lossy_str(msg_bytes, "msg");
 
     context
         .logger
-        .log(level_from_int(level), scope_str, msg_str);
+        .log(level_from_int(level), &scope_str, &msg_str);
 }
 
 /// Logs a trace message.
@@ -394,3 +443,29 @@ pub unsafe extern "C" fn hyprslog_flush(ctx: *mut HyprslogContext) -> c_int {
         }
     }
 }
+
+/// Enables or disables flushing all outputs after every log call.
+///
+/// Use for crash-prone callers: lines reach disk before the next call returns,
+/// at the cost of throughput. Off by default.
+///
+/// # Safety
+/// `ctx` must be a valid context pointer.
+///
+/// # Returns
+/// 0 on success, -1 if `ctx` is `NULL`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hyprslog_set_autoflush(
+    ctx: *mut HyprslogContext,
+    enabled: c_int,
+) -> c_int {
+    if ctx.is_null() {
+        return -1;
+    }
+
+    // SAFETY: ctx is non-null and valid
+    let context = unsafe { &*ctx };
+    context.clear_error();
+    context.logger.set_autoflush(enabled != 0);
+    0
+}
@@ -1,6 +1,9 @@
 //! Structure template parsing for log output.
 //!
-//! Templates use placeholders like `{tag}`, `{scope}`, `{msg}`.
+//! Templates use placeholders like `{tag}`, `{scope}`, `{msg}`, plus
+//! `{pad:N}` for N spaces of column spacing.
+
+use crate::internal;
 
 /// Available placeholders in format strings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,6 +15,10 @@ pub enum Placeholder {
     Timestamp,
     Level,
     App,
+    Hostname,
+    Pid,
+    Seq,
+    Location,
     Year,
     Month,
     Day,
@@ -29,6 +36,10 @@ impl Placeholder {
             Self::Timestamp => "timestamp",
             Self::Level => "level",
             Self::App => "app",
+            Self::Hostname => "hostname",
+            Self::Pid => "pid",
+            Self::Seq => "seq",
+            Self::Location => "location",
             Self::Year => "year",
             Self::Month => "month",
             Self::Day => "day",
@@ -44,6 +55,10 @@ impl Placeholder {
         Self::Timestamp,
         Self::Level,
         Self::App,
+        Self::Hostname,
+        Self::Pid,
+        Self::Seq,
+        Self::Location,
         Self::Year,
         Self::Month,
         Self::Day,
@@ -51,18 +66,29 @@ impl Placeholder {
 }
 
 /// A parsed segment of a format string.
+///
+/// New segment kinds may be added, so matches need a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum FormatSegment {
     /// Literal text.
     Literal(String),
     /// A placeholder to be replaced.
     Placeholder(Placeholder),
+    /// An optional placeholder (`{scope?}`): when its value is blank, it is
+    /// dropped together with the whitespace that follows it.
+    Optional(Placeholder),
+    /// Explicit spacing (`{pad:3}`): always that many spaces, never trimmed
+    /// by an optional placeholder's whitespace collapse.
+    Pad(usize),
 }
 
 /// A parsed format template.
 #[derive(Debug, Clone)]
 pub struct FormatTemplate {
     segments: Vec<FormatSegment>,
+    /// Names in braces that matched no [`Placeholder`].
+    unknown: Vec<String>,
 }
 
 impl FormatTemplate {
@@ -70,6 +96,7 @@ impl FormatTemplate {
     #[must_use]
     pub fn parse(template: &str) -> Self {
         let mut segments = Vec::new();
+        let mut unknown = Vec::new();
         let mut current = String::new();
         let mut i = 0;
         let chars: Vec<char> = template.chars().collect();
@@ -87,12 +114,22 @@ impl FormatTemplate {
                         current.clear();
                     }
 
-                    // Check if it's a known placeholder
+                    // Check if it's a known placeholder (optionally `name?`)
                     if let Some(ph) = Self::match_placeholder(&name) {
                         segments.push(FormatSegment::Placeholder(ph));
+                    } else if let Some(width) = name
+                        .strip_prefix("pad:")
+                        .and_then(|n| n.parse::<usize>().ok())
+                    {
+                        segments.push(FormatSegment::Pad(width));
+                    } else if let Some(ph) =
+                        name.strip_suffix('?').and_then(Self::match_placeholder)
+                    {
+                        segments.push(FormatSegment::Optional(ph));
                     } else {
                         // Unknown placeholder, keep as literal
                         segments.push(FormatSegment::Literal(format!("{{{name}}}")));
+                        unknown.push(name);
                     }
 
                     i = end + 1;
@@ -108,7 +145,7 @@ impl FormatTemplate {
             segments.push(FormatSegment::Literal(current));
         }
 
-        Self { segments }
+        Self { segments, unknown }
     }
 
     fn match_placeholder(name: &str) -> Option<Placeholder> {
@@ -120,36 +157,76 @@ impl FormatTemplate {
         None
     }
 
+    /// Returns the names of unknown placeholders (e.g. `scpoe` for `{scpoe}`).
+    ///
+    /// Unknown placeholders render literally. Known names come from
+    /// [`Placeholder::ALL`], so new placeholders are validated automatically.
+    #[must_use]
+    pub fn validate(&self) -> Vec<String> {
+        self.unknown.clone()
+    }
+
+    /// Warns via internal logging about unknown placeholders.
+    pub(crate) fn warn_unknown(&self, context: &str) {
+        for name in &self.unknown {
+            internal::warn(
+                "FORMAT",
+                &format!("Unknown placeholder {{{name}}} in {context}; it will render literally"),
+            );
+        }
+    }
+
     /// Returns the parsed segments.
     #[must_use]
     pub fn segments(&self) -> &[FormatSegment] {
         &self.segments
     }
 
+    /// Returns true if `placeholder` appears, plain or optional.
+    #[must_use]
+    pub fn uses(&self, placeholder: Placeholder) -> bool {
+        self.segments.iter().any(|segment| {
+            matches!(segment, FormatSegment::Placeholder(p) | FormatSegment::Optional(p) if *p == placeholder)
+        })
+    }
+
     /// Renders the template with provided values.
+    ///
+    /// A blank optional placeholder also removes the whitespace after it, or
+    /// the whitespace before it when it ends the template. `{pad:N}` spaces
+    /// are kept either way.
     #[must_use]
     pub fn render(&self, values: &FormatValues) -> String {
         let mut result = String::new();
+        let mut skip_whitespace = false;
 
         for segment in &self.segments {
             match segment {
-                FormatSegment::Literal(s) => result.push_str(s),
-                FormatSegment::Placeholder(ph) => {
-                    let value = match ph {
-                        Placeholder::Tag => &values.tag,
-                        Placeholder::Icon => &values.icon,
-                        Placeholder::Scope => &values.scope,
-                        Placeholder::Msg => &values.msg,
-                        Placeholder::Timestamp => &values.timestamp,
-                        Placeholder::Level => &values.level,
-                        Placeholder::App => &values.app,
-                        Placeholder::Year => &values.year,
-                        Placeholder::Month => &values.month,
-                        Placeholder::Day => &values.day,
-                    };
+                FormatSegment::Literal(s) => {
+                    if skip_whitespace {
+                        result.push_str(s.trim_start());
+                    } else {
+                        result.push_str(s);
+                    }
+                }
+                FormatSegment::Placeholder(ph) => result.push_str(values.get(*ph)),
+                FormatSegment::Optional(ph) => {
+                    let value = values.get(*ph);
+                    if value.trim().is_empty() {
+                        skip_whitespace = true;
+                        continue;
+                    }
                     result.push_str(value);
                 }
+                FormatSegment::Pad(width) => {
+                    result.extend(std::iter::repeat_n(' ', *width));
+                }
             }
+            skip_whitespace = false;
+        }
+
+        if skip_whitespace {
+            result.truncate(result.trim_end().len());
         }
 
         result
@@ -172,9 +249,17 @@ pub struct FormatValues {
     pub timestamp: String,
     pub level: String,
     pub app: String,
+    pub hostname: String,
+    pub pid: String,
+    /// Per-file line sequence number (file output with sequencing enabled).
+    pub seq: String,
+    /// Source `file:line` of the logging call (set by the `hl_*!` macros).
+    pub location: String,
     pub year: String,
     pub month: String,
     pub day: String,
+    /// Structured key-value fields attached to a record.
+    pub fields: Vec<(String, String)>,
 }
 
 impl FormatValues {
@@ -184,6 +269,27 @@ impl FormatValues {
         Self::default()
     }
 
+    /// Returns the value for a placeholder.
+    #[must_use]
+    pub fn get(&self, placeholder: Placeholder) -> &str {
+        match placeholder {
+            Placeholder::Tag => &self.tag,
+            Placeholder::Icon => &self.icon,
+            Placeholder::Scope => &self.scope,
+            Placeholder::Msg => &self.msg,
+            Placeholder::Timestamp => &self.timestamp,
+            Placeholder::Level => &self.level,
+            Placeholder::App => &self.app,
+            Placeholder::Hostname => &self.hostname,
+            Placeholder::Pid => &self.pid,
+            Placeholder::Seq => &self.seq,
+            Placeholder::Location => &self.location,
+            Placeholder::Year => &self.year,
+            Placeholder::Month => &self.month,
+            Placeholder::Day => &self.day,
+        }
+    }
+
     /// Sets the tag value.
     #[must_use]
     pub fn tag(mut self, tag: impl Into<String>) -> Self {
@@ -233,6 +339,79 @@ impl FormatValues {
         self
     }
 
+    /// Sets the hostname value.
+    #[must_use]
+    pub fn hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = hostname.into();
+        self
+    }
+
+    /// Sets the process id value.
+    #[must_use]
+    pub fn pid(mut self, pid: impl Into<String>) -> Self {
+        self.pid = pid.into();
+        self
+    }
+
+    /// Sets the sequence number value.
+    #[must_use]
+    pub fn seq(mut self, seq: impl Into<String>) -> Self {
+        self.seq = seq.into();
+        self
+    }
+
+    /// Sets the source location value.
+    #[must_use]
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = location.into();
+        self
+    }
+
+    /// Appends a structured key-value field.
+    #[must_use]
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.fields.push((key.into(), value.into()));
+        self
+    }
+
+    /// Fills in values from `defaults` that are not set here.
+    ///
+    /// Empty values take the default; fields from `defaults` come first,
+    /// except keys this set already has, which win.
+    #[must_use]
+    pub fn merge(mut self, defaults: &Self) -> Self {
+        for (value, default) in [
+            (&mut self.tag, &defaults.tag),
+            (&mut self.icon, &defaults.icon),
+            (&mut self.scope, &defaults.scope),
+            (&mut self.msg, &defaults.msg),
+            (&mut self.timestamp, &defaults.timestamp),
+            (&mut self.level, &defaults.level),
+            (&mut self.app, &defaults.app),
+            (&mut self.hostname, &defaults.hostname),
+            (&mut self.pid, &defaults.pid),
+            (&mut self.seq, &defaults.seq),
+            (&mut self.location, &defaults.location),
+            (&mut self.year, &defaults.year),
+            (&mut self.month, &defaults.month),
+            (&mut self.day, &defaults.day),
+        ] {
+            if value.is_empty() {
+                value.clone_from(default);
+            }
+        }
+
+        let mut fields: Vec<(String, String)> = defaults
+            .fields
+            .iter()
+            .filter(|(key, _)| !self.fields.iter().any(|(own, _)| own == key))
+            .cloned()
+            .collect();
+        fields.append(&mut self.fields);
+        self.fields = fields;
+        self
+    }
+
     /// Sets date values from year, month, day.
     #[must_use]
     pub fn date(mut self, year: &str, month: &str, day: &str) -> Self {
@@ -2,7 +2,8 @@
 //!
 //! Injects XML-style color tags for keywords and patterns before style parsing.
 
-use crate::config::HighlightConfig;
+use crate::config::{HighlightConfig, KeywordRule};
+use crate::level::Level;
 use regex::Regex;
 use std::sync::LazyLock;
 
@@ -15,6 +16,30 @@ static PATH_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"(?:^|[^<\w])((?:/|~/|\./)[\w./-]+)").expect("Invalid path regex")
 });
 
+/// Regex pattern for ISO 8601 dates/datetimes and `HH:MM:SS` clock times.
+static TIMESTAMP_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\b\d{4}-\d{2}-\d{2}(?:[T ]\d{2}:\d{2}(?::\d{2}(?:\.\d+)?)?(?:Z|[+-]\d{2}:?\d{2})?)?\b|\b\d{2}:\d{2}:\d{2}(?:\.\d+)?\b",
+    )
+    .expect("Invalid timestamp regex")
+});
+
+/// Regex pattern for IPv4 addresses (optionally with a port).
+static IPV4_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\b(?:(?:25[0-5]|2[0-4]\d|1?\d?\d)\.){3}(?:25[0-5]|2[0-4]\d|1?\d?\d)(?::\d{1,5})?\b",
+    )
+    .expect("Invalid IPv4 regex")
+});
+
+/// Regex pattern for IPv6 addresses (full and `::`-compressed forms).
+static IPV6_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:^|[^\w:])((?:[0-9A-Fa-f]{1,4}:){7}[0-9A-Fa-f]{1,4}|(?:[0-9A-Fa-f]{1,4}:){1,6}(?::[0-9A-Fa-f]{1,4}){1,6}|::(?:[0-9A-Fa-f]{1,4}:){0,6}[0-9A-Fa-f]{1,4}|(?:[0-9A-Fa-f]{1,4}:){1,7}:)",
+    )
+    .expect("Invalid IPv6 regex")
+});
+
 /// Regex pattern for quoted strings ("..." or '...').
 static QUOTED_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#""[^"]*"|'[^']*'"#).expect("Invalid quoted regex"));
@@ -46,12 +71,15 @@ struct Match {
     span: Span,
     text: String,
     color: String,
+    /// URL or path that can become a hyperlink.
+    linkable: bool,
 }
 
 /// Injects XML-style color tags into a message for auto-highlighting.
 ///
-/// This function identifies keywords and patterns (URLs, paths, numbers, quoted strings)
-/// and wraps them in color tags before the style parser processes them.
+/// This function identifies keywords and patterns (URLs, paths, timestamps, IP
+/// addresses, numbers, quoted strings) and wraps them in color tags before the
+/// style parser processes them.
 ///
 /// # Arguments
 /// * `msg` - The message to process.
@@ -59,7 +87,172 @@ struct Match {
 ///
 /// # Returns
 /// The message with injected color tags.
+///
+/// Keyword rules restricted to levels or scopes are skipped; use
+/// [`inject_tags_for`] to apply them against a record.
+///
+/// Keyword regexes are compiled on every call. For repeated use, build a
+/// [`Highlighter`] once instead.
+#[must_use]
 pub fn inject_tags(msg: &str, config: &HighlightConfig) -> String {
+    inject(msg, config, &compile_keywords(config), None, false)
+}
+
+/// Injects color tags for a message logged at `level` under `scope`.
+///
+/// Like [`inject_tags`], but also applies keyword rules whose level/scope
+/// conditions match the record.
+#[must_use]
+pub fn inject_tags_for(msg: &str, config: &HighlightConfig, level: Level, scope: &str) -> String {
+    inject(
+        msg,
+        config,
+        &compile_keywords(config),
+        Some((level, scope)),
+        false,
+    )
+}
+
+/// A keyword rule with its regex compiled and its conditions parsed.
+#[derive(Debug, Clone)]
+struct CompiledKeyword {
+    regex: Regex,
+    color: String,
+    /// Levels the rule is limited to; `None` for any level.
+    levels: Option<Vec<Level>>,
+    /// Scopes the rule is limited to, compared case-insensitively; `None`
+    /// for any scope.
+    scopes: Option<Vec<String>>,
+}
+
+impl CompiledKeyword {
+    fn new(regex: Regex, rule: &KeywordRule) -> Self {
+        let (levels, scopes) = match rule {
+            KeywordRule::Color(_) => (None, None),
+            KeywordRule::Conditional { levels, scopes, .. } => (
+                // Unknown level names match nothing, as in KeywordRule::applies_to
+                (!levels.is_empty())
+                    .then(|| levels.iter().filter_map(|l| l.parse().ok()).collect()),
+                (!scopes.is_empty()).then(|| scopes.clone()),
+            ),
+        };
+        Self {
+            regex,
+            color: rule.color().to_string(),
+            levels,
+            scopes,
+        }
+    }
+
+    /// Returns true if the rule applies to a record with this level and scope.
+    ///
+    /// Without a record context, only unconditional rules apply.
+    fn applies_to(&self, context: Option<(Level, &str)>) -> bool {
+        let Some((level, scope)) = context else {
+            return self.levels.is_none() && self.scopes.is_none();
+        };
+        let level_ok = self.levels.as_ref().is_none_or(|l| l.contains(&level));
+        let scope_ok = self
+            .scopes
+            .as_ref()
+            .is_none_or(|s| s.iter().any(|s| s.eq_ignore_ascii_case(scope)));
+        level_ok && scope_ok
+    }
+}
+
+/// Highlight configuration with keyword regexes compiled once.
+///
+/// Pattern regexes are shared statics; keyword regexes depend on the config
+/// and are built in [`Highlighter::new`] so per-message work is matching only.
+#[derive(Debug, Clone)]
+pub struct Highlighter {
+    config: HighlightConfig,
+    keywords: Vec<CompiledKeyword>,
+}
+
+impl Default for Highlighter {
+    fn default() -> Self {
+        Self::new(HighlightConfig::default())
+    }
+}
+
+impl Highlighter {
+    /// Compiles the keyword regexes for a highlight config.
+    #[must_use]
+    pub fn new(config: HighlightConfig) -> Self {
+        let keywords = compile_keywords(&config);
+        Self { config, keywords }
+    }
+
+    /// Returns the underlying highlight config.
+    #[must_use]
+    pub const fn config(&self) -> &HighlightConfig {
+        &self.config
+    }
+
+    /// Injects color tags, skipping level/scope-restricted keyword rules.
+    #[must_use]
+    pub fn inject_tags(&self, msg: &str) -> String {
+        inject(msg, &self.config, &self.keywords, None, false)
+    }
+
+    /// Injects color tags for a message logged at `level` under `scope`.
+    #[must_use]
+    pub fn inject_tags_for(&self, msg: &str, level: Level, scope: &str) -> String {
+        inject(
+            msg,
+            &self.config,
+            &self.keywords,
+            Some((level, scope)),
+            false,
+        )
+    }
+
+    /// Like [`inject_tags_for`](Self::inject_tags_for), but also makes
+    /// highlighted URLs and paths clickable with OSC 8 hyperlink escapes.
+    ///
+    /// Paths link to `file://` URIs, with `~/` and `./` made absolute.
+    #[must_use]
+    pub fn inject_tags_with_links(&self, msg: &str, level: Level, scope: &str) -> String {
+        inject(
+            msg,
+            &self.config,
+            &self.keywords,
+            Some((level, scope)),
+            true,
+        )
+    }
+}
+
+/// Compiles the keyword rules of a config.
+///
+/// Longer keywords are tried first so `connection refused` wins over
+/// `connection`. Keywords that fail to compile are skipped.
+fn compile_keywords(config: &HighlightConfig) -> Vec<CompiledKeyword> {
+    let mut entries: Vec<(&String, &KeywordRule)> = config
+        .keywords
+        .iter()
+        .filter(|(keyword, _)| !keyword.is_empty())
+        .collect();
+    entries.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+
+    entries
+        .into_iter()
+        .filter_map(|(keyword, rule)| {
+            keyword_regex(keyword, config)
+                .ok()
+                .map(|regex| CompiledKeyword::new(regex, rule))
+        })
+        .collect()
+}
+
+fn inject(
+    msg: &str,
+    config: &HighlightConfig,
+    keywords: &[CompiledKeyword],
+    context: Option<(Level, &str)>,
+    links: bool,
+) -> String {
     if !config.enabled || msg.is_empty() {
         return msg.to_string();
     }
@@ -75,106 +268,133 @@ pub fn inject_tags(msg: &str, config: &HighlightConfig) -> String {
 
     let mut matches: Vec<Match> = Vec::new();
 
-    // Match patterns in priority order: URLs > Paths > Quoted > Numbers
-    if let Some(color) = &config.patterns.urls {
-        for m in URL_REGEX.find_iter(msg) {
-            let span = Span {
-                start: m.start(),
-                end: m.end(),
-            };
-            if !overlaps_any(&span, &existing_spans) {
-                matches.push(Match {
-                    span,
-                    text: m.as_str().to_string(),
-                    color: color.clone(),
-                });
-            }
-        }
-    }
-
-    if let Some(color) = &config.patterns.paths {
-        for cap in PATH_REGEX.captures_iter(msg) {
-            if let Some(m) = cap.get(1) {
-                let span = Span {
-                    start: m.start(),
-                    end: m.end(),
-                };
-                if !overlaps_any(&span, &existing_spans) && !overlaps_any_match(&span, &matches) {
-                    matches.push(Match {
-                        span,
-                        text: m.as_str().to_string(),
-                        color: color.clone(),
-                    });
-                }
-            }
-        }
-    }
-
-    if let Some(color) = &config.patterns.quoted {
-        for m in QUOTED_REGEX.find_iter(msg) {
-            let span = Span {
-                start: m.start(),
-                end: m.end(),
-            };
-            if !overlaps_any(&span, &existing_spans) && !overlaps_any_match(&span, &matches) {
-                matches.push(Match {
-                    span,
-                    text: m.as_str().to_string(),
-                    color: color.clone(),
-                });
-            }
-        }
-    }
+    // Match patterns in priority order:
+    // URLs > Paths > IPs > Timestamps > Quoted > Numbers
+    // IPs go first so `fe80::12:34:56` isn't split at a clock time
+    let patterns = &config.patterns;
+    let pattern_regexes: [(&Option<String>, &[&Regex], bool); 6] = [
+        (&patterns.urls, &[&URL_REGEX], true),
+        (&patterns.paths, &[&PATH_REGEX], true),
+        (&patterns.ip, &[&IPV4_REGEX, &IPV6_REGEX], false),
+        (&patterns.timestamp, &[&TIMESTAMP_REGEX], false),
+        (&patterns.quoted, &[&QUOTED_REGEX], false),
+        (&patterns.numbers, &[&NUMBER_REGEX], false),
+    ];
 
-    if let Some(color) = &config.patterns.numbers {
-        for m in NUMBER_REGEX.find_iter(msg) {
-            let span = Span {
-                start: m.start(),
-                end: m.end(),
-            };
-            if !overlaps_any(&span, &existing_spans) && !overlaps_any_match(&span, &matches) {
-                matches.push(Match {
-                    span,
-                    text: m.as_str().to_string(),
-                    color: color.clone(),
-                });
-            }
+    for (color, regexes, linkable) in pattern_regexes {
+        let Some(color) = color else {
+            continue;
+        };
+        for re in regexes {
+            push_regex_matches(re, msg, color, linkable, &existing_spans, &mut matches);
         }
     }
 
-    // Match keywords (case-insensitive word boundaries)
-    for (keyword, color) in &config.keywords {
-        let pattern = format!(r"(?i)\b{}\b", regex::escape(keyword));
-        if let Ok(re) = Regex::new(&pattern) {
-            for m in re.find_iter(msg) {
-                let span = Span {
-                    start: m.start(),
-                    end: m.end(),
-                };
-                if !overlaps_any(&span, &existing_spans) && !overlaps_any_match(&span, &matches) {
-                    matches.push(Match {
-                        span,
-                        text: m.as_str().to_string(),
-                        color: color.clone(),
-                    });
-                }
-            }
+    // Match keywords
+    for keyword in keywords {
+        if keyword.applies_to(context) {
+            push_regex_matches(
+                &keyword.regex,
+                msg,
+                &keyword.color,
+                false,
+                &existing_spans,
+                &mut matches,
+            );
         }
     }
 
     // Sort matches by position (reverse order for replacement)
-    matches.sort_by(|a, b| b.span.start.cmp(&a.span.start));
+    matches.sort_by_key(|m| std::cmp::Reverse(m.span.start));
 
     // Build result by replacing matches
     let mut result = msg.to_string();
     for m in matches {
-        let replacement = format!("<{}>{}</{}>", m.color, m.text, m.color);
+        let text = if links && m.linkable {
+            hyperlink(&m.text)
+        } else {
+            m.text
+        };
+        let replacement = format!("<{}>{text}</{}>", m.color, m.color);
         result.replace_range(m.span.start..m.span.end, &replacement);
     }
 
     result
 }
 
+/// Wraps a URL or path in an OSC 8 hyperlink escape pointing at itself.
+fn hyperlink(text: &str) -> String {
+    let target = if text.starts_with("http://") || text.starts_with("https://") {
+        text.to_string()
+    } else {
+        let expanded = shellexpand::tilde(text);
+        let path = std::path::Path::new(expanded.as_ref());
+        let absolute = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().map_or_else(|_| path.to_path_buf(), |dir| dir.join(path))
+        };
+        format!("file://{}", absolute.display())
+    };
+    format!("\x1b]8;;{target}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
+/// Builds the regex for a highlight keyword.
+///
+/// Word boundaries are only added on sides where the keyword starts/ends with
+/// a word character, so keywords like `[ERR]` still match.
+fn keyword_regex(keyword: &str, config: &HighlightConfig) -> Result<Regex, regex::Error> {
+    let mut pattern = String::new();
+    if config.case_insensitive {
+        pattern.push_str("(?i)");
+    }
+
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let bound_start = config.word_boundary && keyword.starts_with(is_word);
+    let bound_end = config.word_boundary && keyword.ends_with(is_word);
+
+    if bound_start {
+        pattern.push_str(r"\b");
+    }
+    pattern.push_str(&regex::escape(keyword));
+    if bound_end {
+        pattern.push_str(r"\b");
+    }
+
+    Regex::new(&pattern)
+}
+
+/// Adds non-overlapping matches of `re` to `matches`.
+///
+/// Uses capture group 1 when the regex has one (for patterns that need a
+/// leading context character), otherwise the whole match.
+fn push_regex_matches(
+    re: &Regex,
+    msg: &str,
+    color: &str,
+    linkable: bool,
+    existing_spans: &[Span],
+    matches: &mut Vec<Match>,
+) {
+    for cap in re.captures_iter(msg) {
+        let Some(m) = cap.get(1).or_else(|| cap.get(0)) else {
+            continue;
+        };
+        let span = Span {
+            start: m.start(),
+            end: m.end(),
+        };
+        if !overlaps_any(&span, existing_spans) && !overlaps_any_match(&span, matches) {
+            matches.push(Match {
+                span,
+                text: m.as_str().to_string(),
+                color: color.to_string(),
+                linkable,
+            });
+        }
+    }
+}
+
 /// Checks if a span overlaps with any span in the list.
 fn overlaps_any(span: &Span, spans: &[Span]) -> bool {
     spans.iter().any(|s| span.overlaps(s))
@@ -184,3 +404,67 @@ fn overlaps_any(span: &Span, spans: &[Span]) -> bool {
 fn overlaps_any_match(span: &Span, matches: &[Match]) -> bool {
     matches.iter().any(|m| span.overlaps(&m.span))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlighter_compiles_keywords_once_up_front() {
+        let mut config = HighlightConfig::default();
+        config.keywords.clear();
+        config
+            .keywords
+            .insert("connection".to_string(), "red".to_string().into());
+        config
+            .keywords
+            .insert("connection refused".to_string(), "red".to_string().into());
+        config
+            .keywords
+            .insert(String::new(), "red".to_string().into());
+
+        let highlighter = Highlighter::new(config);
+        let compiled: Vec<&str> = highlighter
+            .keywords
+            .iter()
+            .map(|keyword| keyword.regex.as_str())
+            .collect();
+        assert_eq!(compiled.len(), 2);
+        assert!(compiled[0].contains("connection refused"));
+        assert!(compiled[1].ends_with(r"connection\b"));
+    }
+
+    #[test]
+    fn compiled_conditions_match_like_the_rule() {
+        let regex = Regex::new(r"\bx\b").unwrap();
+        let rule = |levels: &[&str], scopes: &[&str]| KeywordRule::Conditional {
+            color: "red".to_string(),
+            levels: levels.iter().map(ToString::to_string).collect(),
+            scopes: scopes.iter().map(ToString::to_string).collect(),
+        };
+        let contexts = [
+            None,
+            Some((Level::Warn, "NET")),
+            Some((Level::Warn, "net")),
+            Some((Level::Info, "NET")),
+            Some((Level::Warn, "DB")),
+        ];
+
+        for rule in [
+            KeywordRule::Color("red".to_string()),
+            rule(&["warn", "error"], &[]),
+            rule(&[], &["Net"]),
+            rule(&["warn"], &["NET"]),
+            rule(&["bogus"], &[]),
+        ] {
+            let compiled = CompiledKeyword::new(regex.clone(), &rule);
+            for context in contexts {
+                assert_eq!(
+                    compiled.applies_to(context),
+                    rule.applies_to(context),
+                    "{rule:?} in {context:?}"
+                );
+            }
+        }
+    }
+}
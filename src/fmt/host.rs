@@ -0,0 +1,36 @@
+//! Hostname lookup for the `{hostname}` placeholder.
+
+use std::sync::OnceLock;
+
+/// Environment variable that overrides the detected hostname.
+pub const HOSTNAME_ENV: &str = "HYPRLOG_HOSTNAME";
+
+/// Cached hostname, resolved on first use.
+static HOSTNAME: OnceLock<String> = OnceLock::new();
+
+/// Returns the hostname to stamp on log records.
+///
+/// `HYPRLOG_HOSTNAME` takes precedence (useful in containers). Otherwise the
+/// kernel hostname is used, falling back to `$HOSTNAME` and finally
+/// `localhost`. Resolved once and cached, so later changes to the
+/// environment are not picked up.
+#[must_use]
+pub fn hostname() -> String {
+    HOSTNAME.get_or_init(resolve_hostname).clone()
+}
+
+fn resolve_hostname() -> String {
+    if let Ok(name) = std::env::var(HOSTNAME_ENV)
+        && !name.trim().is_empty()
+    {
+        return name.trim().to_string();
+    }
+
+    ["/proc/sys/kernel/hostname", "/etc/hostname"]
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .map(|name| name.trim().to_string())
+        .chain(std::env::var("HOSTNAME").ok())
+        .find(|name| !name.is_empty())
+        .unwrap_or_else(|| "localhost".to_string())
+}
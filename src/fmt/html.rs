@@ -0,0 +1,170 @@
+//! ANSI-to-HTML conversion for sharing colored logs.
+
+use super::Color;
+use hyprs_log_core::skip_escape;
+use std::fmt::Write;
+
+/// SGR state carried between escape sequences.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)] // one flag per independent SGR attribute
+struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+}
+
+impl Style {
+    /// Applies the parameters of one `ESC [ ... m` sequence.
+    fn apply(&mut self, params: &str) {
+        let mut codes = params.split(';').map(|p| p.parse::<u16>().unwrap_or(0));
+        while let Some(code) = codes.next() {
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                38 => self.fg = extended_color(&mut codes),
+                39 => self.fg = None,
+                48 => self.bg = extended_color(&mut codes),
+                49 => self.bg = None,
+                _ => {}
+            }
+        }
+    }
+
+    fn css(&self) -> String {
+        let mut css = String::new();
+        if let Some(fg) = self.fg {
+            let _ = write!(css, "color:{fg};");
+        }
+        if let Some(bg) = self.bg {
+            let _ = write!(css, "background-color:{bg};");
+        }
+        if self.bold {
+            css.push_str("font-weight:bold;");
+        }
+        if self.dim {
+            css.push_str("opacity:0.6;");
+        }
+        if self.italic {
+            css.push_str("font-style:italic;");
+        }
+        if self.underline {
+            css.push_str("text-decoration:underline;");
+        }
+        css.pop();
+        css
+    }
+}
+
+/// Reads the rest of a `38`/`48` color: `2;r;g;b` is kept, `5;n` is skipped.
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    match codes.next()? {
+        2 => {
+            let mut channel = || u8::try_from(codes.next().unwrap_or(0)).unwrap_or(u8::MAX);
+            Some(Color::new(channel(), channel(), channel()))
+        }
+        5 => {
+            codes.next();
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Converts ANSI-colored text to an HTML fragment.
+///
+/// Styled runs become `<span style="...">` elements; text is HTML-escaped and
+/// line breaks are kept, so the result belongs inside a `<pre>`. Handles the
+/// SGR codes hyprslog emits (reset, bold, dim, italic, underline and 24-bit
+/// colors); other escape sequences are dropped.
+#[must_use]
+pub fn ansi_to_html(input: &str) -> String {
+    let mut html = String::with_capacity(input.len());
+    let mut style = Style::default();
+    let mut open = false;
+    let mut chars = input.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            let mut lookahead = chars.clone();
+            if lookahead.next() == Some('[') {
+                chars = lookahead;
+                let mut params = String::new();
+                let mut terminator = None;
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        terminator = Some(c);
+                        break;
+                    }
+                    params.push(c);
+                }
+                if terminator == Some('m') {
+                    let previous = style;
+                    style.apply(&params);
+                    if open && style != previous {
+                        html.push_str("</span>");
+                        open = false;
+                    }
+                }
+            } else {
+                skip_escape(&mut chars);
+            }
+            continue;
+        }
+
+        if !open && style != Style::default() {
+            let _ = write!(html, "<span style=\"{}\">", style.css());
+            open = true;
+        }
+        push_escaped(&mut html, c);
+    }
+
+    if open {
+        html.push_str("</span>");
+    }
+    html
+}
+
+/// Wraps [`ansi_to_html`] output in a standalone HTML document.
+#[must_use]
+pub fn ansi_to_html_document(input: &str, title: &str) -> String {
+    let mut escaped_title = String::new();
+    title
+        .chars()
+        .for_each(|c| push_escaped(&mut escaped_title, c));
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <title>{escaped_title}</title>\n\
+         </head>\n\
+         <body style=\"background-color:#282a36;color:#f8f8f2\">\n\
+         <pre>{}</pre>\n\
+         </body>\n\
+         </html>\n",
+        ansi_to_html(input)
+    )
+}
+
+fn push_escaped(html: &mut String, c: char) {
+    match c {
+        '&' => html.push_str("&amp;"),
+        '<' => html.push_str("&lt;"),
+        '>' => html.push_str("&gt;"),
+        '"' => html.push_str("&quot;"),
+        '\'' => html.push_str("&#39;"),
+        _ => html.push(c),
+    }
+}
@@ -1,5 +1,6 @@
 //! Icon sets for log output.
 
+use super::Alignment;
 use crate::level::Level;
 use std::collections::HashMap;
 
@@ -11,6 +12,8 @@ pub enum IconType {
     NerdFont,
     /// ASCII-only icons (universal compatibility).
     Ascii,
+    /// Emoji icons (two columns wide in most terminals).
+    Emoji,
     /// No icons.
     None,
 }
@@ -55,6 +58,24 @@ impl IconSet {
         }
     }
 
+    /// Creates a new icon set with emoji icons.
+    ///
+    /// All glyphs are two columns wide, so the icon column stays aligned.
+    #[must_use]
+    pub fn emoji() -> Self {
+        let mut icons = HashMap::new();
+        icons.insert(Level::Trace, "\u{26aa}".to_string()); // ⚪
+        icons.insert(Level::Debug, "\u{1f7e3}".to_string()); // 🟣
+        icons.insert(Level::Info, "\u{1f535}".to_string()); // 🔵
+        icons.insert(Level::Warn, "\u{1f7e1}".to_string()); // 🟡
+        icons.insert(Level::Error, "\u{1f534}".to_string()); // 🔴
+
+        Self {
+            icons,
+            icon_type: IconType::Emoji,
+        }
+    }
+
     /// Creates an empty icon set (no icons).
     #[must_use]
     pub fn none() -> Self {
@@ -70,6 +91,29 @@ impl IconSet {
         self.icons.get(&level).map_or("", String::as_str)
     }
 
+    /// Gets the icon for a level, padded to the set's [`width`](Self::width).
+    ///
+    /// Keeps a `{icon} {msg}` layout aligned when custom icons differ in
+    /// width. Levels without an icon stay empty.
+    #[must_use]
+    pub fn get_padded(&self, level: Level) -> String {
+        let icon = self.get(level);
+        if icon.is_empty() {
+            return String::new();
+        }
+        super::pad(icon, self.width(), Alignment::Left)
+    }
+
+    /// Returns the display width of the widest icon.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.icons
+            .values()
+            .map(|icon| super::display_width(icon))
+            .max()
+            .unwrap_or(0)
+    }
+
     /// Sets a custom icon for a level.
     pub fn set(&mut self, level: Level, icon: impl Into<String>) {
         self.icons.insert(level, icon.into());
@@ -93,6 +137,7 @@ impl From<IconType> for IconSet {
         match icon_type {
             IconType::NerdFont => Self::nerdfont(),
             IconType::Ascii => Self::ascii(),
+            IconType::Emoji => Self::emoji(),
             IconType::None => Self::none(),
         }
     }
@@ -1,17 +1,25 @@
 //! Formatting and styling utilities for log output.
+//!
+//! Colors, tags, display width and inline style tags come from the `no_std`
+//! `hyprs-log-core` crate.
 
-mod color;
 mod format;
 pub mod highlight;
+mod host;
+mod html;
 mod icon;
 mod scope;
-pub mod style;
-mod tag;
+mod timezone;
 
-pub use color::{Color, colorize, colorize_bg};
 pub use format::{FormatSegment, FormatTemplate, FormatValues, Placeholder};
-pub use highlight::inject_tags;
+pub use highlight::{Highlighter, inject_tags, inject_tags_for};
+pub use host::{HOSTNAME_ENV, hostname};
+pub use html::{ansi_to_html, ansi_to_html_document};
+pub use hyprs_log_core::style;
+pub use hyprs_log_core::{
+    Alignment, Color, ColorMap, ColorParseError, Segment, TagConfig, Transform, WrapMode, colorize,
+    colorize_bg, display_width, pad, parse, render, render_plain, strip_tags, truncate, wrap,
+};
 pub use icon::{IconSet, IconType};
 pub use scope::ScopeConfig;
-pub use style::{Segment, parse, render, render_plain, strip_tags};
-pub use tag::{Alignment, TagConfig, Transform};
+pub use timezone::{ParseTimezoneError, Timezone};
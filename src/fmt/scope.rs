@@ -1,6 +1,6 @@
 //! Scope formatting for log output.
 
-use super::tag::{Alignment, Transform};
+use super::{Alignment, Transform};
 
 /// Configuration for scope formatting.
 #[derive(Debug, Clone)]
@@ -59,20 +59,6 @@ impl ScopeConfig {
     }
 
     fn pad(&self, s: &str) -> String {
-        let len = s.chars().count();
-        if len >= self.min_width {
-            return s.to_string();
-        }
-
-        let padding = self.min_width - len;
-        match self.alignment {
-            Alignment::Left => format!("{}{}", s, " ".repeat(padding)),
-            Alignment::Right => format!("{}{}", " ".repeat(padding), s),
-            Alignment::Center => {
-                let left = padding / 2;
-                let right = padding - left;
-                format!("{}{}{}", " ".repeat(left), s, " ".repeat(right))
-            }
-        }
+        super::pad(s, self.min_width, self.alignment)
     }
 }
@@ -0,0 +1,105 @@
+//! Time zone selection for rendered timestamps.
+
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use std::fmt;
+use std::str::FromStr;
+
+/// Time zone that timestamps are rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Timezone {
+    /// The system's local time zone.
+    #[default]
+    Local,
+    /// Coordinated Universal Time.
+    Utc,
+    /// A fixed offset from UTC, e.g. `+05:30`.
+    Fixed(FixedOffset),
+    /// An IANA zone such as `Europe/Berlin`, following its DST rules.
+    #[cfg(feature = "chrono-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl Timezone {
+    /// Returns the current time in this zone.
+    #[must_use]
+    pub fn now(self) -> DateTime<FixedOffset> {
+        self.convert(Utc::now())
+    }
+
+    /// Converts a UTC instant into this zone.
+    #[must_use]
+    pub fn convert(self, time: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self {
+            Self::Local => time.with_timezone(&Local).fixed_offset(),
+            Self::Utc => time.fixed_offset(),
+            Self::Fixed(offset) => time.with_timezone(&offset),
+            #[cfg(feature = "chrono-tz")]
+            Self::Named(tz) => time.with_timezone(&tz).fixed_offset(),
+        }
+    }
+}
+
+/// Error returned when parsing an invalid time zone string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTimezoneError(String);
+
+impl fmt::Display for ParseTimezoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = if cfg!(feature = "chrono-tz") {
+            ", a zone name like Europe/Berlin"
+        } else {
+            ""
+        };
+        write!(
+            f,
+            "unknown time zone: '{}' (use local, utc{names} or an offset like +02:00)",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseTimezoneError {}
+
+impl FromStr for Timezone {
+    type Err = ParseTimezoneError;
+
+    /// Parses `local`, `utc`/`z`, or an offset as `+HH:MM`, `+HHMM` or `+HH`.
+    /// With the `chrono-tz` feature, IANA names like `Europe/Berlin` too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        match trimmed.to_lowercase().as_str() {
+            "local" => return Ok(Self::Local),
+            "utc" | "z" => return Ok(Self::Utc),
+            _ => {}
+        }
+        #[cfg(feature = "chrono-tz")]
+        if let Ok(tz) = trimmed.parse::<chrono_tz::Tz>() {
+            return Ok(Self::Named(tz));
+        }
+
+        let err = || ParseTimezoneError(s.to_string());
+        let (sign, digits) = match trimmed.split_at_checked(1) {
+            Some(("+", rest)) => (1, rest),
+            Some(("-", rest)) => (-1, rest),
+            _ => return Err(err()),
+        };
+        let digits = digits.replace(':', "");
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+        let (hours, minutes) = match digits.len() {
+            2 => (&digits[..2], "0"),
+            4 => (&digits[..2], &digits[2..]),
+            _ => return Err(err()),
+        };
+        let hours: i32 = hours.parse().map_err(|_| err())?;
+        let minutes: i32 = minutes.parse().map_err(|_| err())?;
+        if minutes >= 60 {
+            return Err(err());
+        }
+
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(Self::Fixed)
+            .ok_or_else(err)
+    }
+}
@@ -0,0 +1,53 @@
+//! Requests to Hyprland's command socket (`.socket.sock`).
+
+use super::socket;
+use crate::Error;
+use crate::config::HyprlandConfig;
+use std::io::{self, Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Returns the path to the command socket.
+#[must_use]
+pub fn socket_path(socket_dir: &Path) -> PathBuf {
+    socket_dir.join(".socket.sock")
+}
+
+/// Sends `request` (e.g. `j/activewindow`) and returns Hyprland's reply.
+///
+/// Both writing the request and reading the reply are bounded by `timeout`,
+/// so a wedged compositor can't block the caller forever. A zero `timeout`
+/// waits indefinitely.
+///
+/// # Errors
+/// Returns [`Error::Timeout`] if Hyprland doesn't take the request or finish
+/// replying within `timeout`, and [`Error::Io`] for other socket failures.
+pub fn query(socket_dir: &Path, request: &str, timeout: Duration) -> Result<String, Error> {
+    let mut stream = UnixStream::connect(socket_path(socket_dir))?;
+    // The socket rejects a zero timeout; treat it as none
+    let limit = (!timeout.is_zero()).then_some(timeout);
+    stream.set_read_timeout(limit)?;
+    stream.set_write_timeout(limit)?;
+
+    let timed_out = |e: io::Error| match e.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => Error::Timeout(timeout),
+        _ => Error::Io(e),
+    };
+
+    stream.write_all(request.as_bytes()).map_err(timed_out)?;
+    let mut reply = String::new();
+    stream.read_to_string(&mut reply).map_err(timed_out)?;
+    Ok(reply)
+}
+
+/// Sends `request` using the socket directory and timeout from `config`.
+///
+/// # Errors
+/// Returns [`Error::InvalidPath`] if the socket directory can't be resolved,
+/// otherwise as [`query`].
+pub fn query_with_config(config: &HyprlandConfig, request: &str) -> Result<String, Error> {
+    let socket_dir = socket::resolve_socket_dir(config)
+        .ok_or_else(|| Error::InvalidPath("Hyprland socket directory not found".to_string()))?;
+    query(&socket_dir, request, config.command_timeout())
+}
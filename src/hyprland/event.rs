@@ -1,5 +1,26 @@
 //! Hyprland event parsing.
 
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+/// Comma-separated payload layouts of common socket2 events.
+///
+/// The last field takes the rest of the payload, so titles may contain commas.
+const PAYLOAD_LAYOUTS: &[(&str, &[&str])] = &[
+    ("openwindow", &["address", "workspace", "class", "title"]),
+    ("closewindow", &["address"]),
+    ("movewindow", &["address", "workspace"]),
+    ("activewindow", &["class", "title"]),
+    ("activewindowv2", &["address"]),
+    ("workspace", &["workspace"]),
+    ("createworkspace", &["workspace"]),
+    ("destroyworkspace", &["workspace"]),
+    ("focusedmon", &["monitor", "workspace"]),
+    ("urgent", &["address"]),
+    ("submap", &["submap"]),
+];
+
 /// A parsed Hyprland IPC event from socket2.
 ///
 /// Events arrive as `EVENT_NAME>>DATA\n` on the event socket.
@@ -31,12 +52,119 @@ impl HyprlandEvent {
     /// Formats the event as a human-readable log message.
     ///
     /// Returns `"eventname: data"` or just `"eventname"` when data is empty.
+    /// Payloads with a known layout are expanded to `key=value` pairs, e.g.
+    /// `movewindowv2: address=80a6f50 workspace_id=2 workspace=dev`.
     #[must_use]
     pub fn format_message(&self) -> String {
+        self.render(&self.name)
+    }
+
+    /// Formats like [`format_message`](Self::format_message), with the event
+    /// name replaced by its entry in `names` (e.g. `openwindow` -> `Window Opened`).
+    ///
+    /// Events without an entry keep their raw name.
+    #[must_use]
+    pub fn format_message_with_names<S: BuildHasher>(
+        &self,
+        names: &HashMap<String, String, S>,
+    ) -> String {
+        self.render(names.get(&self.name).unwrap_or(&self.name))
+    }
+
+    /// Renders the message under `label`.
+    fn render(&self, label: &str) -> String {
+        if self.data.is_empty() {
+            return label.to_string();
+        }
+        self.fields().map_or_else(
+            || format!("{label}: {}", self.data),
+            |fields| {
+                let pairs: Vec<String> = fields.iter().map(|(k, v)| format!("{k}={v}")).collect();
+                format!("{label}: {}", pairs.join(" "))
+            },
+        )
+    }
+
+    /// Returns the payload as named fields for structured output.
+    ///
+    /// Covers the layouts expanded by [`fields`](Self::fields) plus the
+    /// common window, workspace and monitor events (e.g. `openwindow` yields
+    /// `address`, `workspace`, `class`, `title`). Empty for unknown events.
+    #[must_use]
+    pub fn parsed_fields(&self) -> BTreeMap<&'static str, String> {
+        if let Some(fields) = self.fields() {
+            return fields.into_iter().collect();
+        }
+        let Some((_, keys)) = PAYLOAD_LAYOUTS.iter().find(|(name, _)| *name == self.name) else {
+            return BTreeMap::new();
+        };
         if self.data.is_empty() {
-            self.name.clone()
-        } else {
-            format!("{}: {}", self.name, self.data)
+            return BTreeMap::new();
         }
+        keys.iter()
+            .copied()
+            .zip(self.data.splitn(keys.len(), ',').map(str::to_string))
+            .collect()
+    }
+
+    /// Returns the event as JSON: `{"name", "data", "parsed_fields"}`.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::Value::Null)
+    }
+
+    /// Splits the payload into named fields, or `None` if its layout is unknown.
+    ///
+    /// Recognizes `movewindowv2` (`ADDRESS,WORKSPACEID,WORKSPACENAME`) and
+    /// the window geometry payloads (`ADDRESS,X,Y,W,H`) of
+    /// [`GEOMETRY_EVENTS`]. Anything that doesn't match its expected layout
+    /// is left to be shown raw.
+    #[must_use]
+    pub fn fields(&self) -> Option<Vec<(&'static str, String)>> {
+        if self.name == "movewindowv2" {
+            let mut parts = self.data.splitn(3, ',');
+            let (address, id, workspace) = (parts.next()?, parts.next()?, parts.next()?);
+            id.parse::<i64>().ok()?;
+            return Some(vec![
+                ("address", address.to_string()),
+                ("workspace_id", id.to_string()),
+                ("workspace", workspace.to_string()),
+            ]);
+        }
+        if GEOMETRY_EVENTS.contains(&self.name.as_str()) {
+            return parse_geometry(&self.data);
+        }
+        None
+    }
+}
+
+impl Serialize for HyprlandEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut event = serializer.serialize_struct("HyprlandEvent", 3)?;
+        event.serialize_field("name", &self.name)?;
+        event.serialize_field("data", &self.data)?;
+        event.serialize_field("parsed_fields", &self.parsed_fields())?;
+        event.end()
+    }
+}
+
+/// Events whose payload is window geometry, `ADDRESS,X,Y,W,H`.
+pub const GEOMETRY_EVENTS: &[&str] = &["resizewindow"];
+
+/// Parses `ADDRESS,X,Y,W,H` with integer coordinates and size.
+fn parse_geometry(data: &str) -> Option<Vec<(&'static str, String)>> {
+    let parts: Vec<&str> = data.split(',').collect();
+    let [address, x, y, w, h] = parts.as_slice() else {
+        return None;
+    };
+    if address.is_empty() || [x, y, w, h].iter().any(|n| n.parse::<i32>().is_err()) {
+        return None;
     }
+    Some(vec![
+        ("address", (*address).to_string()),
+        ("x", (*x).to_string()),
+        ("y", (*y).to_string()),
+        ("w", (*w).to_string()),
+        ("h", (*h).to_string()),
+    ])
 }
@@ -1,5 +1,6 @@
 //! Event-to-log-level mapping for Hyprland events.
 
+use crate::internal;
 use crate::level::Level;
 use std::collections::HashMap;
 use std::sync::LazyLock;
@@ -57,6 +58,22 @@ pub fn default_level_map() -> HashMap<&'static str, Level> {
     map
 }
 
+/// Warns about `event_levels` entries that aren't valid level names.
+///
+/// [`resolve_level`] ignores those entries and uses the default mapping.
+pub fn warn_invalid_levels<S: ::std::hash::BuildHasher>(
+    user_overrides: &HashMap<String, String, S>,
+) {
+    for (event_name, level_str) in user_overrides {
+        if level_str.parse::<Level>().is_err() {
+            internal::warn(
+                "HYPRLAND",
+                &format!("Invalid level in event_levels.{event_name}: {level_str} (using default)"),
+            );
+        }
+    }
+}
+
 /// Resolves the log level for an event.
 ///
 /// Priority: user overrides (from config) > default map > fallback (Info).
@@ -65,11 +82,11 @@ pub fn resolve_level<S: ::std::hash::BuildHasher>(
     event_name: &str,
     user_overrides: &HashMap<String, String, S>,
 ) -> Level {
-    // Check user overrides first
-    if let Some(level_str) = user_overrides.get(event_name) {
-        if let Ok(level) = level_str.parse() {
-            return level;
-        }
+    // Check user overrides first; invalid ones fall through
+    if let Some(level_str) = user_overrides.get(event_name)
+        && let Ok(level) = level_str.parse()
+    {
+        return level;
     }
 
     // Check default map (cached)
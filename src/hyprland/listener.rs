@@ -1,7 +1,7 @@
 //! Hyprland socket2 event listener.
 
 use super::event::HyprlandEvent;
-use super::level_map::resolve_level;
+use super::level_map::{resolve_level, warn_invalid_levels};
 use super::socket;
 use crate::config::HyprlandConfig;
 use crate::internal;
@@ -44,16 +44,18 @@ impl Drop for EventListenerHandle {
 /// Runs the event listener loop on the current thread (blocking).
 ///
 /// Connects to socket2, reads events line by line, and routes them through the
-/// logger. Reconnects with exponential backoff on disconnect.
+/// logger. Reconnects with exponential backoff on disconnect. Flushes the
+/// logger once `shutdown` is set and the loop exits.
 ///
-/// Respects `config.ignore_events` to skip unwanted events, and applies
-/// per-event level mapping via `config.event_levels`.
+/// Filters events with [`HyprlandConfig::allows_event`] (allowlist, then
+/// denylist), and applies per-event level mapping via `config.event_levels`.
 pub fn run_event_loop(
     socket_dir: &std::path::Path,
     logger: &Logger,
     config: &HyprlandConfig,
     shutdown: &AtomicBool,
 ) {
+    warn_invalid_levels(&config.event_levels);
     let mut backoff = Duration::from_millis(100);
     let max_backoff = Duration::from_secs(30);
 
@@ -79,12 +81,26 @@ pub fn run_event_loop(
         }
     }
 
+    // Don't lose buffered file/JSON tail data on shutdown
+    if let Err(e) = logger.flush() {
+        internal::warn("HYPRLAND", &format!("Flush on shutdown failed: {e}"));
+    }
     internal::debug("HYPRLAND", "Event listener stopped");
 }
 
-/// Processes events from a connected socket2 stream.
+/// Logs events read from a recorded socket2 stream until EOF.
+///
+/// Lines go through the same parsing, filtering and level mapping as the live
+/// listener, so a capture of socket2 (e.g. `socat` output) replays exactly as
+/// it was logged. No socket is needed.
+pub fn replay_events(reader: impl BufRead, logger: &Logger, config: &HyprlandConfig) {
+    warn_invalid_levels(&config.event_levels);
+    process_events(reader, logger, config, &AtomicBool::new(false));
+}
+
+/// Processes events from a socket2 stream (live or recorded).
 fn process_events(
-    mut reader: io::BufReader<std::os::unix::net::UnixStream>,
+    mut reader: impl BufRead,
     logger: &Logger,
     config: &HyprlandConfig,
     shutdown: &AtomicBool,
@@ -99,24 +115,7 @@ fn process_events(
         line.clear();
         match reader.read_line(&mut line) {
             Ok(0) => break, // EOF — socket closed
-            Ok(_) => {
-                if let Some(event) = HyprlandEvent::parse(&line) {
-                    // Skip ignored events
-                    if config.ignore_events.iter().any(|e| e == &event.name) {
-                        continue;
-                    }
-
-                    // If an allowlist filter is set, skip events not in it
-                    if let Some(ref filter) = config.event_filter {
-                        if !filter.iter().any(|f| f == &event.name) {
-                            continue;
-                        }
-                    }
-
-                    let level = resolve_level(&event.name, &config.event_levels);
-                    logger.log(level, &config.scope, &event.format_message());
-                }
-            }
+            Ok(_) => log_event(&line, logger, config),
             Err(ref e)
                 if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut =>
             {
@@ -132,6 +131,25 @@ fn process_events(
     }
 }
 
+/// Parses one raw event line and logs it unless filtered out.
+fn log_event(line: &str, logger: &Logger, config: &HyprlandConfig) {
+    let Some(event) = HyprlandEvent::parse(line) else {
+        return;
+    };
+
+    // Allowlist restricts first, then the denylist removes
+    if !config.allows_event(&event.name) {
+        return;
+    }
+
+    let level = resolve_level(&event.name, &config.event_levels);
+    logger.log(
+        level,
+        &config.scope,
+        &event.format_message_with_names(&config.event_names),
+    );
+}
+
 /// Starts the event listener in a background thread.
 ///
 /// Returns a handle that can be used to stop the listener, or `None` if the
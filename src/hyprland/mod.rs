@@ -1,8 +1,10 @@
 //! Hyprland IPC integration.
 //!
 //! Listens to Hyprland's event socket (socket2) for compositor events
-//! and routes them through the logger.
+//! and routes them through the logger, and sends requests to the command
+//! socket.
 
+pub mod command;
 pub mod event;
 pub mod level_map;
 pub mod listener;
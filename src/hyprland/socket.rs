@@ -7,12 +7,17 @@ use std::os::unix::net::UnixStream;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+/// Socket root used by Hyprland before sockets moved under `$XDG_RUNTIME_DIR`.
+const LEGACY_SOCKET_ROOT: &str = "/tmp/hypr";
+
 /// Resolves the Hyprland socket directory.
 ///
 /// Priority:
 /// 1. `config.socket_dir` (explicit override)
 /// 2. `$XDG_RUNTIME_DIR/hypr/$HYPRLAND_INSTANCE_SIGNATURE/`
+/// 3. `/tmp/hypr/$HYPRLAND_INSTANCE_SIGNATURE/` (older Hyprland versions)
 ///
+/// `config.instance_signature` overrides the environment variable.
 /// Logs errors directly through hyprslog and returns `None` on failure.
 #[must_use]
 pub fn resolve_socket_dir(config: &HyprlandConfig) -> Option<PathBuf> {
@@ -37,19 +42,31 @@ pub fn resolve_socket_dir(config: &HyprlandConfig) -> Option<PathBuf> {
         return None;
     };
 
-    let Ok(runtime_dir) = std::env::var("XDG_RUNTIME_DIR") else {
-        internal::error("HYPRLAND", "XDG_RUNTIME_DIR not set");
-        return None;
-    };
-
-    let socket_dir = PathBuf::from(runtime_dir).join("hypr").join(instance_sig);
+    let runtime_dir = std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from);
+    if runtime_dir.is_none() {
+        internal::debug("HYPRLAND", "XDG_RUNTIME_DIR not set, trying /tmp/hypr");
+    }
 
-    if socket_dir.exists() {
-        Some(socket_dir)
-    } else {
+    let found = find_socket_dir(runtime_dir.as_deref(), &instance_sig);
+    if found.is_none() {
         internal::error("HYPRLAND", "Socket directory not found");
-        None
     }
+    found
+}
+
+/// Finds the socket directory for an instance signature.
+///
+/// Checks `{runtime_dir}/hypr/{sig}` first, then the legacy `/tmp/hypr/{sig}`,
+/// and returns the first that exists.
+#[must_use]
+pub fn find_socket_dir(runtime_dir: Option<&Path>, instance_sig: &str) -> Option<PathBuf> {
+    runtime_dir
+        .map(|dir| dir.join("hypr").join(instance_sig))
+        .into_iter()
+        .chain(std::iter::once(
+            Path::new(LEGACY_SOCKET_ROOT).join(instance_sig),
+        ))
+        .find(|dir| dir.exists())
 }
 
 /// Returns the path to socket2 (event socket).
@@ -1,14 +1,54 @@
 //! Internal logging for hyprslog itself.
 //!
 //! hyprslog uses its own Logger for internal messages.
+//!
+//! Internal messages can be silenced independently of user loggers with
+//! [`set_enabled`] or by setting `HYPRLOG_INTERNAL=0`.
 
 use crate::config::Config;
 use crate::fmt::IconSet;
 use crate::level::Level;
 use crate::logger::Logger;
 use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Environment variable that disables internal logging when set to `0`, `false` or `off`.
+pub const INTERNAL_ENV: &str = "HYPRLOG_INTERNAL";
 
 static INTERNAL_LOGGER: OnceLock<Logger> = OnceLock::new();
+static ENABLED: AtomicBool = AtomicBool::new(true);
+static ENV_DISABLED: OnceLock<bool> = OnceLock::new();
+
+/// Enables or disables internal logging at runtime.
+///
+/// Only affects hyprslog's own diagnostics; user loggers are untouched.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether internal messages are emitted.
+///
+/// False when disabled via [`set_enabled`] or [`INTERNAL_ENV`].
+#[must_use]
+pub fn is_enabled() -> bool {
+    let env_disabled = *ENV_DISABLED.get_or_init(|| {
+        std::env::var(INTERNAL_ENV).is_ok_and(|v| {
+            matches!(
+                v.trim().to_ascii_lowercase().as_str(),
+                "0" | "false" | "off"
+            )
+        })
+    });
+    ENABLED.load(Ordering::Relaxed) && !env_disabled
+}
+
+/// Flushes the internal logger's outputs.
+///
+/// # Errors
+/// Returns error if an output fails to flush.
+pub fn flush() -> Result<(), crate::Error> {
+    INTERNAL_LOGGER.get().map_or(Ok(()), Logger::flush)
+}
 
 /// Initializes the internal logger from config.
 ///
@@ -86,6 +126,9 @@ fn build_internal_logger(config: &Config) -> Logger {
 
 /// Logs an internal message.
 fn log(level: Level, scope: &str, msg: &str) {
+    if !is_enabled() {
+        return;
+    }
     if let Some(logger) = INTERNAL_LOGGER.get() {
         logger.log(level, scope, msg);
     }
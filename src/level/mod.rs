@@ -1,91 +1,5 @@
 //! Log level definitions.
+//!
+//! Defined in the `no_std` `hyprs-log-core` crate and re-exported here.
 
-use std::fmt;
-use std::str::FromStr;
-
-/// Log severity levels, ordered from most to least verbose.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub enum Level {
-    /// Fine-grained debugging information.
-    Trace = 0,
-    /// Debugging information.
-    Debug = 1,
-    /// Informational messages.
-    #[default]
-    Info = 2,
-    /// Warning messages.
-    Warn = 3,
-    /// Error messages.
-    Error = 4,
-}
-
-impl Level {
-    /// Returns the canonical lowercase name.
-    #[must_use]
-    pub const fn as_str(self) -> &'static str {
-        match self {
-            Self::Trace => "trace",
-            Self::Debug => "debug",
-            Self::Info => "info",
-            Self::Warn => "warn",
-            Self::Error => "error",
-        }
-    }
-
-    /// Returns all levels in order of verbosity.
-    #[must_use]
-    pub const fn all() -> [Self; 5] {
-        [
-            Self::Trace,
-            Self::Debug,
-            Self::Info,
-            Self::Warn,
-            Self::Error,
-        ]
-    }
-}
-
-impl fmt::Display for Level {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.as_str())
-    }
-}
-
-/// Error returned when parsing an invalid level string.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ParseLevelError(String);
-
-impl fmt::Display for ParseLevelError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "unknown log level: '{}'", self.0)
-    }
-}
-
-impl std::error::Error for ParseLevelError {}
-
-impl From<u8> for Level {
-    fn from(v: u8) -> Self {
-        match v {
-            0 => Self::Trace,
-            1 => Self::Debug,
-            2 => Self::Info,
-            3 => Self::Warn,
-            _ => Self::Error,
-        }
-    }
-}
-
-impl FromStr for Level {
-    type Err = ParseLevelError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
-            "trace" => Ok(Self::Trace),
-            "debug" => Ok(Self::Debug),
-            "info" => Ok(Self::Info),
-            "warn" | "warning" => Ok(Self::Warn),
-            "error" | "err" => Ok(Self::Error),
-            _ => Err(ParseLevelError(s.to_string())),
-        }
-    }
-}
+pub use hyprs_log_core::level::{Level, ParseLevelError, STATIC_MAX_LEVEL};
@@ -27,6 +27,9 @@
 //! logger.debug("NET", "Connecting to server...");
 //! logger.warn("NET", "Connection timeout");
 //! logger.error("NET", "Connection <bold>failed</bold>");
+//!
+//! // Formatted only when the level is enabled
+//! hyprs_log::hl_info!(logger, "NET", "retry {} of {}", 1, 3);
 //! ```
 //!
 //! # Features
@@ -44,6 +47,8 @@ pub mod level;
 pub mod logger;
 pub mod output;
 
+mod macros;
+
 // CLI module (feature-gated)
 #[cfg(feature = "cli")]
 pub mod cli;
@@ -66,14 +71,17 @@ pub mod ffi;
 
 // Re-exports for convenience
 pub use cleanup::{
-    CleanupOptions, CleanupResult, LogFileInfo, LogStats, cleanup, format_size, parse_size, stats,
+    CleanupOptions, CleanupResult, LogFileInfo, LogStats, SizeUnit, cleanup, cleanup_with_progress,
+    format_size, format_size_with, parse_size, stats, stats_with_extensions,
 };
 pub use config::Config;
 pub use error::Error;
-pub use fmt::{Alignment, Color, FormatValues, IconSet, IconType, TagConfig, Transform};
+pub use fmt::{Alignment, Color, FormatValues, IconSet, IconType, TagConfig, Transform, WrapMode};
 pub use level::Level;
-pub use logger::{Logger, LoggerBuilder};
-pub use output::{FileOutput, Output, TerminalOutput};
+pub use logger::{Clock, Logger, LoggerBuilder, ScopedLogger, SystemClock, Timer};
+pub use output::{
+    AsyncOutput, FileOutput, LineEnding, LogfmtOutput, NullOutput, Output, SyncMode, TerminalOutput,
+};
 
 // CLI re-exports
 #[cfg(feature = "cli")]
@@ -95,7 +103,8 @@ pub use server::ServerConfig;
 #[cfg(feature = "ffi")]
 pub use ffi::{
     HYPRSLOG_LEVEL_DEBUG, HYPRSLOG_LEVEL_ERROR, HYPRSLOG_LEVEL_INFO, HYPRSLOG_LEVEL_TRACE,
-    HYPRSLOG_LEVEL_WARN, HyprslogContext, hyprslog_debug, hyprslog_error, hyprslog_flush, hyprslog_free,
-    hyprslog_get_last_error, hyprslog_info, hyprslog_init, hyprslog_init_simple, hyprslog_init_with_app,
-    hyprslog_init_with_config, hyprslog_log, hyprslog_trace, hyprslog_warn,
+    HYPRSLOG_LEVEL_WARN, HyprslogContext, hyprslog_debug, hyprslog_error, hyprslog_flush,
+    hyprslog_free, hyprslog_get_last_error, hyprslog_info, hyprslog_init, hyprslog_init_simple,
+    hyprslog_init_with_app, hyprslog_init_with_config, hyprslog_log, hyprslog_log_n,
+    hyprslog_set_autoflush, hyprslog_trace, hyprslog_warn,
 };
@@ -1,21 +1,37 @@
 //! Logger builder types.
 
-use super::Logger;
 use super::json_builder::JsonBuilder;
+use super::logfmt_builder::LogfmtBuilder;
 #[cfg(feature = "rserver")]
 use super::remote_builder::RemoteBuilder;
+use super::{Clock, Logger, OutputErrorHandler, SystemClock};
 use crate::config::{HighlightConfig, PresetConfig};
-use crate::fmt::{Color, IconSet, ScopeConfig, TagConfig, Transform};
+use crate::fmt::{
+    Color, FormatValues, IconSet, ScopeConfig, TagConfig, Timezone, Transform, WrapMode,
+};
+use crate::internal;
 use crate::level::Level;
-use crate::output::{FileOutput, JsonOutput, Output, TerminalOutput};
+use crate::output::{
+    AsyncOutput, FileOutput, JsonOutput, LineEnding, LogfmtOutput, NullOutput, Output, OutputError,
+    SyncMode, TerminalOutput,
+};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Builder for configuring a logger.
 #[derive(Default)]
 pub struct LoggerBuilder {
     pub(super) min_level: Level,
-    pub(super) outputs: Vec<Box<dyn Output>>,
+    pub(super) outputs: Vec<Arc<dyn Output>>,
     pub(super) presets: HashMap<String, PresetConfig>,
+    pub(super) on_output_error: Option<OutputErrorHandler>,
+    pub(super) fallback_terminal: bool,
+    pub(super) default_fields: Option<FormatValues>,
+    pub(super) message_prefix: Option<String>,
+    pub(super) scope_levels: HashMap<String, Level>,
+    pub(super) autoflush: bool,
+    pub(super) named_outputs: HashMap<String, usize>,
+    pub(super) clock: Option<Arc<dyn Clock>>,
 }
 
 impl LoggerBuilder {
@@ -26,6 +42,14 @@ impl LoggerBuilder {
             min_level: Level::Info,
             outputs: Vec::new(),
             presets: HashMap::new(),
+            on_output_error: None,
+            fallback_terminal: false,
+            default_fields: None,
+            message_prefix: None,
+            scope_levels: HashMap::new(),
+            autoflush: false,
+            named_outputs: HashMap::new(),
+            clock: None,
         }
     }
 
@@ -36,13 +60,86 @@ impl LoggerBuilder {
         self
     }
 
+    /// Sets a minimum level for one scope, e.g. `NET` at trace.
+    ///
+    /// Records in that scope pass if they meet either this or the global
+    /// level, whichever is lower. Scopes match case-insensitively.
+    #[must_use]
+    pub fn scope_level(mut self, scope: &str, level: Level) -> Self {
+        self.scope_levels.insert(scope.to_ascii_lowercase(), level);
+        self
+    }
+
     /// Sets the presets.
+    ///
+    /// Presets with an invalid level are kept (they log at info) and
+    /// reported through an internal warning.
     #[must_use]
     pub fn presets(mut self, presets: HashMap<String, PresetConfig>) -> Self {
+        for (name, preset) in &presets {
+            if let Some(problem) = preset.validate() {
+                internal::warn(
+                    "PRESETS",
+                    &format!("presets.{name}.{problem} (defaults to info)"),
+                );
+            }
+        }
         self.presets = presets;
         self
     }
 
+    /// Sets a callback for failed output writes (e.g. disk full).
+    ///
+    /// Without a callback, write errors are ignored. Repeated identical errors
+    /// from the same output are reported once until that output succeeds again.
+    #[must_use]
+    pub fn on_output_error(
+        mut self,
+        handler: impl Fn(&OutputError) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_output_error = Some(Arc::new(handler));
+        self
+    }
+
+    /// Sends records to a stderr terminal output when a file output finds
+    /// its base directory unwritable on the first write.
+    ///
+    /// Must be set before the file output is added.
+    #[must_use]
+    pub const fn fallback_terminal(mut self, enabled: bool) -> Self {
+        self.fallback_terminal = enabled;
+        self
+    }
+
+    /// Flushes every output after each record it writes.
+    ///
+    /// Trades throughput for durability: buffered lines reach disk even if
+    /// the process crashes right after logging. Off by default.
+    #[must_use]
+    pub const fn autoflush(mut self, enabled: bool) -> Self {
+        self.autoflush = enabled;
+        self
+    }
+
+    /// Sets values merged into every record, e.g. `service=mytool env=prod`.
+    ///
+    /// Fields passed at the call site win over defaults with the same key.
+    #[must_use]
+    pub fn default_fields(mut self, values: FormatValues) -> Self {
+        self.default_fields = Some(values);
+        self
+    }
+
+    /// Prepends `prefix` to every message, e.g. `[tenant:42] `.
+    ///
+    /// Added before outputs parse style tags, so tags in the prefix work.
+    /// [`Logger::raw`] lines are left as they are.
+    #[must_use]
+    pub fn message_prefix(mut self, prefix: &str) -> Self {
+        self.message_prefix = Some(prefix.to_string());
+        self
+    }
+
     /// Adds a terminal output with default configuration.
     #[must_use]
     pub fn terminal(self) -> TerminalBuilder {
@@ -70,6 +167,23 @@ impl LoggerBuilder {
         }
     }
 
+    /// Adds a logfmt output (stdout by default).
+    #[must_use]
+    pub fn logfmt(self) -> LogfmtBuilder {
+        LogfmtBuilder {
+            parent: self,
+            output: LogfmtOutput::new(),
+        }
+    }
+
+    /// Adds an output that discards every record.
+    ///
+    /// See [`NullOutput`].
+    #[must_use]
+    pub fn null(self) -> Self {
+        self.output(NullOutput::new())
+    }
+
     /// Adds a remote output (Unix socket or TCP) to a running hyprslog server.
     ///
     /// Call `.socket(path)` or `.tcp(addr)` on the returned builder,
@@ -83,7 +197,52 @@ impl LoggerBuilder {
     /// Adds a custom output.
     #[must_use]
     pub fn output(mut self, output: impl Output + 'static) -> Self {
-        self.outputs.push(Box::new(output));
+        self.outputs.push(Arc::new(output));
+        self
+    }
+
+    /// Adds an output that the caller keeps a handle to.
+    ///
+    /// The same instance is written by the logger and can be inspected
+    /// through the caller's `Arc` (e.g. an in-memory capture).
+    #[must_use]
+    pub fn shared_output(mut self, output: Arc<dyn Output>) -> Self {
+        self.outputs.push(output);
+        self
+    }
+
+    /// Adds a custom output written from a background thread.
+    ///
+    /// Wraps `inner` in an [`AsyncOutput`] with default capacity that blocks
+    /// when full. Use [`output`](Self::output) with
+    /// [`AsyncOutput::with_options`] to drop records instead.
+    #[must_use]
+    pub fn async_output(mut self, inner: impl Output + 'static) -> Self {
+        self.outputs.push(Arc::new(AsyncOutput::new(inner)));
+        self
+    }
+
+    /// Sets the time source for record timestamps (default [`SystemClock`]).
+    ///
+    /// [`Logger::log_at`] still uses the timestamp it is given.
+    #[must_use]
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Names the most recently added output.
+    ///
+    /// Named outputs can be muted at runtime with
+    /// [`Logger::set_output_enabled`]. Naming a second output with the same
+    /// name moves the name; naming an output again replaces its name. Does
+    /// nothing if no output was added yet.
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        if let Some(index) = self.outputs.len().checked_sub(1) {
+            self.named_outputs.retain(|_, named| *named != index);
+            self.named_outputs.insert(name.to_string(), index);
+        }
         self
     }
 
@@ -92,9 +251,24 @@ impl LoggerBuilder {
     pub fn build(self) -> Logger {
         Logger {
             min_level: std::sync::atomic::AtomicU8::new(self.min_level as u8),
-            outputs: self.outputs,
             presets: self.presets,
             app_name: None,
+            on_output_error: self.on_output_error,
+            last_errors: std::sync::Mutex::new(Vec::new()),
+            sample_counters: std::sync::Mutex::new(HashMap::new()),
+            warned_keys: Arc::default(),
+            default_fields: self.default_fields,
+            message_prefix: self.message_prefix,
+            scope_levels: self.scope_levels,
+            autoflush: std::sync::atomic::AtomicBool::new(self.autoflush),
+            output_enabled: self
+                .outputs
+                .iter()
+                .map(|_| std::sync::atomic::AtomicBool::new(true))
+                .collect(),
+            outputs: self.outputs,
+            named_outputs: self.named_outputs,
+            clock: self.clock.unwrap_or_else(|| Arc::new(SystemClock)),
         }
     }
 }
@@ -127,6 +301,14 @@ impl TerminalBuilder {
         self
     }
 
+    /// Sets the output template for one level, falling back to
+    /// [`structure`](Self::structure) for the others.
+    #[must_use]
+    pub fn structure_for(mut self, level: Level, template: &str) -> Self {
+        self.output = self.output.template_for(level, template);
+        self
+    }
+
     /// Sets the tag configuration.
     #[must_use]
     pub fn tag_config(mut self, config: TagConfig) -> Self {
@@ -162,6 +344,20 @@ impl TerminalBuilder {
         self
     }
 
+    /// Sets the color for a scope.
+    #[must_use]
+    pub fn scope_color(mut self, scope: impl Into<String>, color: Color) -> Self {
+        self.output = self.output.scope_color(scope, color);
+        self
+    }
+
+    /// Colors scopes without an explicit color by hashing their name.
+    #[must_use]
+    pub fn hashed_scope_colors(mut self, enabled: bool) -> Self {
+        self.output = self.output.hashed_scope_colors(enabled);
+        self
+    }
+
     /// Sets the highlight configuration.
     #[must_use]
     pub fn highlight_config(mut self, config: HighlightConfig) -> Self {
@@ -169,10 +365,49 @@ impl TerminalBuilder {
         self
     }
 
+    /// Cuts messages longer than `len` columns, ending them with `…`.
+    #[must_use]
+    pub fn max_message_len(mut self, len: usize) -> Self {
+        self.output = self.output.max_message_len(len);
+        self
+    }
+
+    /// Makes highlighted URLs and paths clickable (OSC 8) on a terminal.
+    #[must_use]
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.output = self.output.hyperlinks(enabled);
+        self
+    }
+
+    /// Indents continuation lines of multi-line messages to align under `{msg}`.
+    ///
+    /// File and other outputs keep the raw newlines.
+    #[must_use]
+    pub fn wrap_continuation(mut self, enabled: bool) -> Self {
+        self.output = self.output.wrap_continuation(enabled);
+        self
+    }
+
+    /// Wraps long messages to the terminal width (`COLUMNS`, the tty, or 80).
+    ///
+    /// Continuation lines are indented under `{msg}`.
+    #[must_use]
+    pub fn wrap(mut self, mode: WrapMode) -> Self {
+        self.output = self.output.wrap(mode);
+        self
+    }
+
+    /// Wraps at a fixed width instead of the detected terminal width.
+    #[must_use]
+    pub fn wrap_width(mut self, width: usize) -> Self {
+        self.output = self.output.wrap_width(width);
+        self
+    }
+
     /// Finishes terminal configuration and returns to the logger builder.
     #[must_use]
     pub fn done(mut self) -> LoggerBuilder {
-        self.parent.outputs.push(Box::new(self.output));
+        self.parent.outputs.push(Arc::new(self.output));
         self.parent
     }
 }
@@ -226,10 +461,81 @@ impl FileBuilder {
         self
     }
 
+    /// Sets the scope configuration used when expanding `{scope}` in content.
+    #[must_use]
+    pub fn scope_config(mut self, config: ScopeConfig) -> Self {
+        self.output = self.output.scope_config(config);
+        self
+    }
+
+    /// Flushes to disk after every `lines` log lines.
+    #[must_use]
+    pub fn buffer_lines(mut self, lines: usize) -> Self {
+        self.output = self.output.buffer_lines(lines);
+        self
+    }
+
+    /// Sets the Unix permission bits for newly created log files.
+    #[must_use]
+    pub fn file_mode(mut self, mode: u32) -> Self {
+        self.output = self.output.file_mode(mode);
+        self
+    }
+
+    /// Syncs files to disk on each flush (default [`SyncMode::None`]).
+    ///
+    /// See [`FileOutput::sync_mode`] for the durability tradeoff.
+    #[must_use]
+    pub fn sync_mode(mut self, mode: SyncMode) -> Self {
+        self.output = self.output.sync_mode(mode);
+        self
+    }
+
+    /// Sets the line terminator (default LF).
+    #[must_use]
+    pub fn line_ending(mut self, ending: LineEnding) -> Self {
+        self.output = self.output.line_ending(ending);
+        self
+    }
+
+    /// Sets the zone file timestamps and date path segments use (default local).
+    #[must_use]
+    pub fn timezone(mut self, timezone: Timezone) -> Self {
+        self.output = self.output.timezone(timezone);
+        self
+    }
+
+    /// Numbers lines per file for the `{seq}` placeholder.
+    ///
+    /// Counters restart at 1 with the process.
+    #[must_use]
+    pub fn sequence(mut self, enabled: bool) -> Self {
+        self.output = self.output.sequence(enabled);
+        self
+    }
+
+    /// Flushes buffered lines every `ms` milliseconds from a background thread.
+    #[must_use]
+    pub fn buffer_flush_ms(mut self, ms: u64) -> Self {
+        self.output = self.output.buffer_flush_ms(ms);
+        self
+    }
+
     /// Finishes file configuration and returns to the logger builder.
+    ///
+    /// Nothing touches the filesystem yet. The output probes its base
+    /// directory on the first write; on failure the error is logged
+    /// internally and, if [`LoggerBuilder::fallback_terminal`] is set, records
+    /// also go to stderr so they are not lost.
     #[must_use]
     pub fn done(mut self) -> LoggerBuilder {
-        self.parent.outputs.push(Box::new(self.output));
+        if self.parent.fallback_terminal {
+            self.output = self
+                .output
+                .fallback(TerminalOutput::new().stderr_only(true));
+        }
+
+        self.parent.outputs.push(Arc::new(self.output));
         self.parent
     }
 }
@@ -0,0 +1,25 @@
+//! Time source for record timestamps.
+
+use std::time::SystemTime;
+
+/// Source of the time stamped on each record.
+///
+/// The logger asks it once per record, see [`LoggerBuilder::clock`]. Swap
+/// in a fixed clock to make rendered timestamps deterministic in tests or
+/// to replay events at their original time.
+///
+/// [`LoggerBuilder::clock`]: super::LoggerBuilder::clock
+pub trait Clock: Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// Wall-clock time; the default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
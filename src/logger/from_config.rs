@@ -19,6 +19,33 @@ impl Logger {
         Self::from_config_with(&config, app_name)
     }
 
+    /// Creates a logger from inline TOML config content.
+    ///
+    /// Parses `content` like a config file (see [`Config::from_str`]) and
+    /// builds the logger with [`Logger::from_config_with`], so no config
+    /// file has to exist.
+    ///
+    /// [`Config::from_str`]: crate::config::Config#impl-FromStr-for-Config
+    ///
+    /// # Errors
+    /// Returns error if the content fails to parse.
+    pub fn from_config_str(content: &str, app_name: &str) -> Result<Self, crate::Error> {
+        let config: crate::config::Config = content.parse()?;
+        Ok(Self::from_config_with(&config, app_name))
+    }
+
+    /// Creates a logger for `app_name` with its `[apps.{app_name}]` overrides.
+    ///
+    /// Precedence, highest first: `[apps.{app_name}]` values, then the global
+    /// sections (`[general]`, `[terminal]`, `[file]`, ...), then defaults. The
+    /// app name also replaces the configured `general.app_name` for records
+    /// that don't set one. Equivalent to [`Logger::from_config_with`], named
+    /// for call sites that want the override step to be explicit.
+    #[must_use]
+    pub fn from_config_for_app(config: &crate::config::Config, app_name: &str) -> Self {
+        Self::from_config_with(config, app_name)
+    }
+
     /// Creates a logger from a given config.
     ///
     /// # Arguments
@@ -26,6 +53,8 @@ impl Logger {
     /// * `app_name` - Application name override.
     ///
     /// This method applies app-specific overrides from `[apps.{app_name}]` sections.
+    /// Outputs are named `terminal`, `file` and `json` for
+    /// [`Logger::set_output_enabled`].
     #[must_use]
     pub fn from_config_with(config: &crate::config::Config, app_name: &str) -> Self {
         internal::debug("LOGGER", &format!("Initializing logger for app={app_name}"));
@@ -34,21 +63,24 @@ impl Logger {
         let config = config.for_app(app_name);
         internal::debug("LOGGER", &format!("Log level: {}", config.general.level));
 
-        let mut builder = LoggerBuilder::new().level(config.parse_level());
+        // Without a terminal, an unwritable log dir would swallow everything
+        let mut builder = LoggerBuilder::new()
+            .level(config.parse_level())
+            .fallback_terminal(!config.terminal.enabled);
         let mut outputs: Vec<&str> = Vec::new();
 
         if config.terminal.enabled {
-            builder = Self::configure_terminal(builder, &config);
+            builder = Self::configure_terminal(builder, &config).name("terminal");
             outputs.push("terminal");
         }
 
         if config.file.enabled {
-            builder = Self::configure_file(builder, &config, app_name);
+            builder = Self::configure_file(builder, &config, app_name).name("file");
             outputs.push("file");
         }
 
         if config.json.enabled {
-            builder = Self::configure_json(builder, &config, app_name);
+            builder = Self::configure_json(builder, &config, app_name).name("json");
             outputs.push("json");
         }
 
@@ -61,6 +93,11 @@ impl Logger {
             );
         }
 
+        for (scope, level) in config.parse_scope_levels() {
+            internal::debug("LOGGER", &format!("Scope level: {scope}={level}"));
+            builder = builder.scope_level(&scope, level);
+        }
+
         if !config.presets.is_empty() {
             internal::debug(
                 "PRESETS",
@@ -95,6 +132,13 @@ impl Logger {
                 config.highlight.patterns.urls.as_ref().map(|_| "urls"),
                 config.highlight.patterns.paths.as_ref().map(|_| "paths"),
                 config.highlight.patterns.quoted.as_ref().map(|_| "quoted"),
+                config.highlight.patterns.ip.as_ref().map(|_| "ip"),
+                config
+                    .highlight
+                    .patterns
+                    .timestamp
+                    .as_ref()
+                    .map(|_| "timestamp"),
                 config
                     .highlight
                     .patterns
@@ -129,6 +173,20 @@ impl Logger {
             .scope_config(scope_config)
             .message_transform(message_transform)
             .highlight_config(config.highlight.clone());
+        if let Some(max_len) = config.message.max_len {
+            terminal = terminal.max_message_len(max_len);
+        }
+
+        for (level_str, structure) in &config.terminal.structure_overrides {
+            if let Ok(level) = level_str.parse::<Level>() {
+                terminal = terminal.structure_for(level, structure);
+            } else {
+                internal::warn(
+                    "LOGGER",
+                    &format!("Invalid level in terminal.structure_overrides: {level_str}"),
+                );
+            }
+        }
 
         // Apply custom colors from config
         for name in config.colors.keys() {
@@ -137,6 +195,18 @@ impl Logger {
             }
         }
 
+        // Apply scope colors (hex or a name from [colors])
+        terminal = terminal.hashed_scope_colors(config.scope.hashed_colors);
+        for (scope, value) in &config.scope.colors {
+            match config
+                .get_color(value)
+                .map_or_else(|| crate::fmt::Color::parse(value), Ok)
+            {
+                Ok(color) => terminal = terminal.scope_color(scope, color),
+                Err(e) => internal::warn("LOGGER", &format!("scope.colors.{scope}: {e}")),
+            }
+        }
+
         // Apply level colors from config (e.g., colors.info = "#50fa7b")
         for level in [
             Level::Trace,
@@ -156,20 +226,29 @@ impl Logger {
 
     /// Builds icon set from config.
     fn build_icon_set(config: &crate::config::Config) -> crate::fmt::IconSet {
-        let mut icon_set = match config.parse_icon_type() {
-            crate::fmt::IconType::NerdFont => crate::fmt::IconSet::nerdfont(),
-            crate::fmt::IconType::Ascii => crate::fmt::IconSet::ascii(),
-            crate::fmt::IconType::None => crate::fmt::IconSet::none(),
-        };
+        let icon_type = config.parse_icon_type();
+        let mut icon_set = crate::fmt::IconSet::from(icon_type);
 
-        let overrides = match config.parse_icon_type() {
+        let overrides = match icon_type {
             crate::fmt::IconType::NerdFont => &config.icons.nerdfont,
             crate::fmt::IconType::Ascii => &config.icons.ascii,
+            crate::fmt::IconType::Emoji => &config.icons.emoji,
             crate::fmt::IconType::None => return icon_set,
         };
 
         for (level_str, icon) in overrides {
             if let Ok(level) = level_str.parse::<Level>() {
+                // Built-in emoji are two columns; a narrower override misaligns
+                if icon_type == crate::fmt::IconType::Emoji
+                    && unicode_width::UnicodeWidthStr::width(icon.as_str()) != 2
+                {
+                    internal::warn(
+                        "LOGGER",
+                        &format!(
+                            "Emoji icon for {level_str} is not two columns wide: {icon:?} (columns may misalign)"
+                        ),
+                    );
+                }
                 icon_set.set(level, icon);
             } else {
                 internal::warn(
@@ -202,6 +281,14 @@ impl Logger {
             }
         }
 
+        for level_str in &config.tag.hide {
+            if let Ok(level) = level_str.parse::<Level>() {
+                tag_config = tag_config.hide_for(level);
+            } else {
+                internal::warn("LOGGER", &format!("Invalid level in tag.hide: {level_str}"));
+            }
+        }
+
         tag_config
     }
 
@@ -229,6 +316,11 @@ impl Logger {
             ),
         );
 
+        let mut scope_config = Self::build_scope_config(config);
+        if !config.file.pad_scope {
+            scope_config = scope_config.min_width(0);
+        }
+
         builder
             .file()
             .base_dir(&config.file.base_dir)
@@ -236,6 +328,8 @@ impl Logger {
             .filename_structure(&config.file.filename_structure)
             .content_structure(&config.file.content_structure)
             .timestamp_format(&config.file.timestamp_format)
+            .timezone(config.parse_timezone())
+            .scope_config(scope_config)
             .app_name(config.general.app_name.as_deref().unwrap_or(app_name))
             .done()
     }
@@ -249,10 +343,17 @@ impl Logger {
         internal::debug("JSON", "Configuring JSON database output...");
         internal::debug("JSON", &format!("Path: {}", config.json.path));
 
-        builder
+        let mut json = builder
             .json()
             .path(&config.json.path)
             .app_name(config.general.app_name.as_deref().unwrap_or(app_name))
-            .done()
+            .max_backups(config.json.max_backups);
+        if let Some(size) = &config.json.max_file_size {
+            match crate::cleanup::parse_size(size) {
+                Some(bytes) => json = json.max_file_size(bytes),
+                None => internal::warn("JSON", &format!("Invalid json.max_file_size: {size}")),
+            }
+        }
+        json.done()
     }
 }
@@ -1,8 +1,9 @@
 //! JSON database output builder.
 
 use super::LoggerBuilder;
-use crate::output::JsonOutput;
+use crate::output::{JsonOutput, LineEnding};
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Builder for JSON database output configuration.
 pub struct JsonBuilder {
@@ -25,10 +26,31 @@ impl JsonBuilder {
         self
     }
 
+    /// Sets the line terminator (default LF).
+    #[must_use]
+    pub fn line_ending(mut self, ending: LineEnding) -> Self {
+        self.output = self.output.line_ending(ending);
+        self
+    }
+
+    /// Rotates the file once it would grow past `bytes`.
+    #[must_use]
+    pub fn max_file_size(mut self, bytes: u64) -> Self {
+        self.output = self.output.max_file_size(bytes);
+        self
+    }
+
+    /// Sets how many rolled files to keep (default 5).
+    #[must_use]
+    pub fn max_backups(mut self, count: usize) -> Self {
+        self.output = self.output.max_backups(count);
+        self
+    }
+
     /// Finishes JSON configuration and returns to the logger builder.
     #[must_use]
     pub fn done(mut self) -> LoggerBuilder {
-        self.parent.outputs.push(Box::new(self.output));
+        self.parent.outputs.push(Arc::new(self.output));
         self.parent
     }
 }
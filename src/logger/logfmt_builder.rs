@@ -0,0 +1,35 @@
+//! logfmt output builder.
+
+use super::LoggerBuilder;
+use crate::output::LogfmtOutput;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Builder for logfmt output configuration.
+pub struct LogfmtBuilder {
+    pub(super) parent: LoggerBuilder,
+    pub(super) output: LogfmtOutput,
+}
+
+impl LogfmtBuilder {
+    /// Appends to a file instead of writing to stdout.
+    #[must_use]
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.output = self.output.path(path);
+        self
+    }
+
+    /// Sets the application name.
+    #[must_use]
+    pub fn app_name(mut self, name: impl Into<String>) -> Self {
+        self.output = self.output.app_name(name);
+        self
+    }
+
+    /// Finishes logfmt configuration and returns to the logger builder.
+    #[must_use]
+    pub fn done(mut self) -> LoggerBuilder {
+        self.parent.outputs.push(Arc::new(self.output));
+        self.parent
+    }
+}
@@ -1,14 +1,22 @@
 //! Main logger struct with builder pattern.
 
 mod builder;
+mod clock;
 mod from_config;
 mod json_builder;
+mod logfmt_builder;
+mod scoped;
+mod timer;
 
 #[cfg(feature = "rserver")]
 mod remote_builder;
 
 pub use builder::{FileBuilder, LoggerBuilder, TerminalBuilder};
+pub use clock::{Clock, SystemClock};
 pub use json_builder::JsonBuilder;
+pub use logfmt_builder::LogfmtBuilder;
+pub use scoped::ScopedLogger;
+pub use timer::Timer;
 
 #[cfg(feature = "rserver")]
 pub use remote_builder::RemoteBuilder;
@@ -17,16 +25,67 @@ use crate::config::PresetConfig;
 use crate::fmt::FormatValues;
 use crate::internal;
 use crate::level::Level;
-use crate::output::{LogRecord, Output};
-use std::collections::HashMap;
-use std::sync::atomic::{AtomicU8, Ordering};
+use crate::output::{LogRecord, Output, OutputError};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Callback invoked when an output fails to write a record.
+///
+/// Shared so that loggers derived with [`Logger::with_level`] report through it too.
+pub type OutputErrorHandler = Arc<dyn Fn(&OutputError) + Send + Sync>;
 
 /// The main logger.
 pub struct Logger {
     min_level: AtomicU8,
-    outputs: Vec<Box<dyn Output>>,
+    outputs: Vec<Arc<dyn Output>>,
     presets: HashMap<String, PresetConfig>,
     pub(crate) app_name: Option<String>,
+    on_output_error: Option<OutputErrorHandler>,
+    /// Last reported error message per output, for debouncing.
+    last_errors: Mutex<Vec<Option<String>>>,
+    /// Calls suppressed since the last emission, per `(level, scope)`.
+    sample_counters: Mutex<HashMap<(Level, String), u32>>,
+    /// Keys already emitted by [`Logger::warn_once`], shared with derived loggers.
+    warned_keys: Arc<Mutex<HashSet<String>>>,
+    /// Values merged into every record, see [`LoggerBuilder::default_fields`].
+    default_fields: Option<FormatValues>,
+    /// Prepended to every non-raw message, see [`LoggerBuilder::message_prefix`].
+    message_prefix: Option<String>,
+    /// Per-scope thresholds keyed by lowercase scope, see [`LoggerBuilder::scope_level`].
+    scope_levels: HashMap<String, Level>,
+    /// Flush each output after writing, see [`Logger::set_autoflush`].
+    autoflush: AtomicBool,
+    /// Output index per name, see [`LoggerBuilder::name`].
+    named_outputs: HashMap<String, usize>,
+    /// Per-output switch shared with derived loggers, see
+    /// [`Logger::set_output_enabled`].
+    output_enabled: Arc<[AtomicBool]>,
+    /// Time source for record timestamps, see [`LoggerBuilder::clock`].
+    clock: Arc<dyn Clock>,
+}
+
+/// Serializes `value` into flat string fields for [`Logger::log_serialize`].
+fn flatten_fields<T: Serialize + ?Sized>(value: &T) -> Result<Vec<(String, String)>, crate::Error> {
+    let json = serde_json::to_value(value)
+        .map_err(|e| crate::Error::Format(format!("serialization failed: {e}")))?;
+    let serde_json::Value::Object(map) = json else {
+        return Err(crate::Error::Format(
+            "log_serialize expects a struct or map".to_string(),
+        ));
+    };
+
+    map.into_iter()
+        .map(|(key, value)| match value {
+            serde_json::Value::String(s) => Ok((key, s)),
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => Err(
+                crate::Error::Format(format!("field `{key}` is nested; only scalars are logged")),
+            ),
+            scalar => Ok((key, scalar.to_string())),
+        })
+        .collect()
 }
 
 impl Default for Logger {
@@ -36,6 +95,17 @@ impl Default for Logger {
             outputs: Vec::new(),
             presets: HashMap::new(),
             app_name: None,
+            on_output_error: None,
+            last_errors: Mutex::new(Vec::new()),
+            sample_counters: Mutex::new(HashMap::new()),
+            warned_keys: Arc::default(),
+            default_fields: None,
+            message_prefix: None,
+            scope_levels: HashMap::new(),
+            autoflush: AtomicBool::new(false),
+            named_outputs: HashMap::new(),
+            output_enabled: Arc::new([]),
+            clock: Arc::new(SystemClock),
         }
     }
 }
@@ -52,13 +122,93 @@ impl Logger {
         self.min_level.store(level as u8, Ordering::Relaxed);
     }
 
+    /// Enables or disables flushing every output after each record at runtime.
+    ///
+    /// See [`LoggerBuilder::autoflush`].
+    pub fn set_autoflush(&self, enabled: bool) {
+        self.autoflush.store(enabled, Ordering::Relaxed);
+    }
+
     fn level(&self) -> Level {
         Level::from(self.min_level.load(Ordering::Relaxed))
     }
 
+    /// Returns whether a record at `level` would be emitted.
+    ///
+    /// Use it to skip building expensive messages:
+    /// `if logger.is_enabled(Level::Debug) { logger.debug("X", &dump()); }`
+    #[must_use]
+    pub fn is_enabled(&self, level: Level) -> bool {
+        level >= self.level()
+    }
+
+    /// Returns whether a record at `level` under `scope` would be emitted.
+    ///
+    /// A per-scope threshold only lowers the bar: the lower of it and the
+    /// global level applies. Scopes match case-insensitively.
+    #[must_use]
+    pub fn is_enabled_for(&self, level: Level, scope: &str) -> bool {
+        if self.is_enabled(level) {
+            return true;
+        }
+        !self.scope_levels.is_empty()
+            && self
+                .scope_levels
+                .get(&scope.to_ascii_lowercase())
+                .is_some_and(|&min| level >= min)
+    }
+
+    /// Returns a logger sharing this one's outputs but filtering at `level`.
+    ///
+    /// Outputs, presets, default fields and the error handler are shared, not
+    /// rebuilt, so the derived logger is cheap. Use it to raise verbosity for
+    /// one operation; changing its level later doesn't affect the parent.
+    /// Output switches ([`set_output_enabled`](Self::set_output_enabled)) and
+    /// [`warn_once`](Self::warn_once) keys are shared both ways.
+    #[must_use]
+    pub fn with_level(&self, level: Level) -> Self {
+        Self {
+            min_level: AtomicU8::new(level as u8),
+            outputs: self.outputs.clone(),
+            presets: self.presets.clone(),
+            app_name: self.app_name.clone(),
+            on_output_error: self.on_output_error.clone(),
+            last_errors: Mutex::new(Vec::new()),
+            sample_counters: Mutex::new(HashMap::new()),
+            warned_keys: Arc::clone(&self.warned_keys),
+            default_fields: self.default_fields.clone(),
+            message_prefix: self.message_prefix.clone(),
+            scope_levels: self.scope_levels.clone(),
+            autoflush: AtomicBool::new(self.autoflush.load(Ordering::Relaxed)),
+            named_outputs: self.named_outputs.clone(),
+            output_enabled: Arc::clone(&self.output_enabled),
+            clock: Arc::clone(&self.clock),
+        }
+    }
+
+    /// Returns a logger sharing this one's outputs and level but prepending
+    /// `prefix` to every message.
+    ///
+    /// Replaces any prefix this logger has. Like [`with_level`](Self::with_level),
+    /// the derived logger is cheap; make one per tenant or request.
+    #[must_use]
+    pub fn with_prefix(&self, prefix: &str) -> Self {
+        let mut logger = self.with_level(self.level());
+        logger.message_prefix = Some(prefix.to_string());
+        logger
+    }
+
+    /// Returns a sub-logger that logs everything under `scope`.
+    ///
+    /// The child borrows this logger, so it shares outputs and level.
+    #[must_use]
+    pub const fn child<'a>(&'a self, scope: &'a str) -> ScopedLogger<'a> {
+        ScopedLogger::new(self, scope)
+    }
+
     /// Logs a message at the given level.
     pub fn log(&self, level: Level, scope: &str, msg: &str) {
-        if level < self.level() {
+        if !self.is_enabled_for(level, scope) {
             return;
         }
 
@@ -70,16 +220,218 @@ impl Logger {
             label_override: None,
             app_name: self.app_name.clone(),
             raw: false,
+            timestamp: Some(self.now()),
         };
 
-        for output in &self.outputs {
-            let _ = output.write(&record);
+        self.dispatch(&record);
+    }
+
+    /// Logs a message stamped with `timestamp` instead of the current time.
+    ///
+    /// For replaying recorded events: file, JSON, logfmt and terminal output
+    /// render the given instant rather than the time of the call.
+    pub fn log_at(&self, timestamp: DateTime<Utc>, level: Level, scope: &str, msg: &str) {
+        if !self.is_enabled_for(level, scope) {
+            return;
+        }
+
+        let record = LogRecord {
+            level,
+            scope: scope.to_string(),
+            message: msg.to_string(),
+            values: FormatValues::new(),
+            label_override: None,
+            app_name: self.app_name.clone(),
+            raw: false,
+            timestamp: Some(timestamp),
+        };
+
+        self.dispatch(&record);
+    }
+
+    /// Logs a message with structured key-value fields.
+    ///
+    /// Text outputs append the fields as `key=value`; JSON output stores them
+    /// under `"fields"`.
+    pub fn log_kv(&self, level: Level, scope: &str, msg: &str, fields: &[(&str, &str)]) {
+        if !self.is_enabled_for(level, scope) {
+            return;
+        }
+
+        let values = fields
+            .iter()
+            .fold(FormatValues::new(), |values, (key, value)| {
+                values.field(*key, *value)
+            });
+
+        let record = LogRecord {
+            level,
+            scope: scope.to_string(),
+            message: msg.to_string(),
+            values,
+            label_override: None,
+            app_name: self.app_name.clone(),
+            raw: false,
+            timestamp: Some(self.now()),
+        };
+
+        self.dispatch(&record);
+    }
+
+    /// Logs like [`Logger::log_kv`], stamped with `timestamp` as in
+    /// [`Logger::log_at`].
+    pub fn log_kv_at(
+        &self,
+        timestamp: DateTime<Utc>,
+        level: Level,
+        scope: &str,
+        msg: &str,
+        fields: &[(&str, &str)],
+    ) {
+        if !self.is_enabled_for(level, scope) {
+            return;
+        }
+
+        let values = fields
+            .iter()
+            .fold(FormatValues::new(), |values, (key, value)| {
+                values.field(*key, *value)
+            });
+
+        let record = LogRecord {
+            level,
+            scope: scope.to_string(),
+            message: msg.to_string(),
+            values,
+            label_override: None,
+            app_name: self.app_name.clone(),
+            raw: false,
+            timestamp: Some(timestamp),
+        };
+
+        self.dispatch(&record);
+    }
+
+    /// Logs like [`Logger::log_kv`], recording the caller's `file:line`.
+    ///
+    /// The location fills the `{location}` placeholder. The `hl_*!` macros
+    /// call this, so the location is that of the macro invocation.
+    #[track_caller]
+    pub fn log_located(&self, level: Level, scope: &str, msg: &str, fields: &[(&str, &str)]) {
+        if !self.is_enabled_for(level, scope) {
+            return;
+        }
+
+        let caller = std::panic::Location::caller();
+        let values = fields.iter().fold(
+            FormatValues::new().location(format!("{}:{}", caller.file(), caller.line())),
+            |values, (key, value)| values.field(*key, *value),
+        );
+
+        let record = LogRecord {
+            level,
+            scope: scope.to_string(),
+            message: msg.to_string(),
+            values,
+            label_override: None,
+            app_name: self.app_name.clone(),
+            raw: false,
+            timestamp: Some(self.now()),
+        };
+
+        self.dispatch(&record);
+    }
+
+    /// Logs a message with the fields of a serializable value.
+    ///
+    /// `value` must serialize to a flat map (e.g. a struct of scalars); each
+    /// entry becomes a structured field, with strings unquoted and other
+    /// scalars in their JSON form. `Option::None` fields become `null`.
+    ///
+    /// # Errors
+    /// Returns [`crate::Error::Format`] without logging if `value` doesn't
+    /// serialize, isn't a map, or has a nested map or sequence field.
+    pub fn log_serialize<T: Serialize + ?Sized>(
+        &self,
+        level: Level,
+        scope: &str,
+        msg: &str,
+        value: &T,
+    ) -> Result<(), crate::Error> {
+        if !self.is_enabled_for(level, scope) {
+            return Ok(());
+        }
+
+        let fields = flatten_fields(value)?;
+        let pairs: Vec<(&str, &str)> = fields
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        self.log_kv(level, scope, msg, &pairs);
+        Ok(())
+    }
+
+    /// Logs only one in every `every` calls for this level and scope.
+    ///
+    /// The first call is emitted; the next `every - 1` are counted and
+    /// dropped. Emissions after suppressed calls get a `(suppressed N)`
+    /// suffix. Use for noisy sites such as retry loops.
+    pub fn log_sampled(&self, level: Level, scope: &str, msg: &str, every: u32) {
+        if !self.is_enabled_for(level, scope) {
+            return;
+        }
+
+        let suppressed = {
+            let mut counters = self
+                .sample_counters
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let key = (level, scope.to_string());
+            let suppressed = match counters.get_mut(&key) {
+                Some(count) if *count + 1 < every => {
+                    *count += 1;
+                    return;
+                }
+                Some(count) => *count,
+                None => 0,
+            };
+            counters.insert(key, 0);
+            drop(counters);
+            suppressed
+        };
+
+        if suppressed == 0 {
+            self.log(level, scope, msg);
+        } else {
+            self.log(level, scope, &format!("{msg} (suppressed {suppressed})"));
+        }
+    }
+
+    /// Logs a warning the first time `key` is seen, and never again.
+    ///
+    /// Meant for deprecation notices from library code: give each deprecated
+    /// item its own key and call this on every use. Keys are shared by this
+    /// logger and every logger derived from it with
+    /// [`with_level`](Self::with_level) or [`with_prefix`](Self::with_prefix);
+    /// a key isn't used up while warnings are filtered out.
+    pub fn warn_once(&self, key: &str, scope: &str, msg: &str) {
+        if !self.is_enabled_for(Level::Warn, scope) {
+            return;
+        }
+
+        let first = self
+            .warned_keys
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(key.to_string());
+        if first {
+            self.warn(scope, msg);
         }
     }
 
     /// Logs a message with a custom label override.
     pub fn log_with_label(&self, level: Level, scope: &str, msg: &str, label: &str) {
-        if level < self.level() {
+        if !self.is_enabled_for(level, scope) {
             return;
         }
 
@@ -91,16 +443,15 @@ impl Logger {
             label_override: Some(label.to_string()),
             app_name: self.app_name.clone(),
             raw: false,
+            timestamp: Some(self.now()),
         };
 
-        for output in &self.outputs {
-            let _ = output.write(&record);
-        }
+        self.dispatch(&record);
     }
 
     /// Logs a message with full control options, including app name override.
     pub fn log_full(&self, level: Level, scope: &str, msg: &str, app_name: Option<&str>) {
-        if level < self.level() {
+        if !self.is_enabled_for(level, scope) {
             return;
         }
 
@@ -114,13 +465,95 @@ impl Logger {
                 .map(ToString::to_string)
                 .or_else(|| self.app_name.clone()),
             raw: false,
+            timestamp: Some(self.now()),
         };
 
-        for output in &self.outputs {
-            let _ = output.write(&record);
+        self.dispatch(&record);
+    }
+
+    /// Writes a record to every output, reporting failures.
+    ///
+    /// Merges default fields and adds the message prefix (not to raw lines).
+    fn dispatch(&self, record: &LogRecord) {
+        let prefix = self.message_prefix.as_deref().filter(|_| !record.raw);
+        if self.default_fields.is_none() && prefix.is_none() {
+            self.write_outputs(record);
+            return;
+        }
+
+        let mut record = record.clone();
+        if let Some(defaults) = &self.default_fields {
+            record.values = std::mem::take(&mut record.values).merge(defaults);
+        }
+        if let Some(prefix) = prefix {
+            record.message.insert_str(0, prefix);
+        }
+        self.write_outputs(&record);
+    }
+
+    /// Reads the configured clock.
+    fn now(&self) -> DateTime<Utc> {
+        self.clock.now().into()
+    }
+
+    fn write_outputs(&self, record: &LogRecord) {
+        let autoflush = self.autoflush.load(Ordering::Relaxed);
+        for (index, output) in self.outputs.iter().enumerate() {
+            if !self.output_enabled[index].load(Ordering::Relaxed) {
+                continue;
+            }
+            let result = output
+                .write(record)
+                .and_then(|()| if autoflush { output.flush() } else { Ok(()) });
+            match result {
+                Ok(()) => self.clear_output_error(index),
+                Err(error) => self.report_output_error(index, &error, record),
+            }
         }
     }
 
+    /// Invokes the error callback unless this output just reported the same error.
+    fn report_output_error(&self, index: usize, error: &crate::Error, record: &LogRecord) {
+        let Some(handler) = &self.on_output_error else {
+            return;
+        };
+
+        let message = error.to_string();
+        {
+            let mut last = self.last_errors.lock().unwrap();
+            if last.len() <= index {
+                last.resize(index + 1, None);
+            }
+            if last[index].as_ref() == Some(&message) {
+                return;
+            }
+            last[index] = Some(message);
+        }
+
+        handler(&OutputError {
+            output_index: index,
+            error,
+            record,
+        });
+    }
+
+    /// Re-arms error reporting for an output after a successful write.
+    fn clear_output_error(&self, index: usize) {
+        if self.on_output_error.is_none() {
+            return;
+        }
+        if let Some(last) = self.last_errors.lock().unwrap().get_mut(index) {
+            *last = None;
+        }
+    }
+
+    /// Starts a timer that logs `"{label} completed in {N}ms"` when dropped.
+    ///
+    /// Logs at debug level by default; see [`Timer::level`].
+    pub fn timer(&self, scope: &str, label: &str) -> Timer<'_> {
+        Timer::new(self, scope, label)
+    }
+
     /// Logs a trace message.
     pub fn trace(&self, scope: &str, msg: &str) {
         self.log(Level::Trace, scope, msg);
@@ -146,6 +579,24 @@ impl Logger {
         self.log(Level::Error, scope, msg);
     }
 
+    /// Logs an error message followed by the error and its `source()` chain.
+    ///
+    /// Emits `"{msg}: {err}"` with one `caused by: ...` line per source.
+    pub fn error_with_source(&self, scope: &str, msg: &str, err: &dyn std::error::Error) {
+        if !self.is_enabled_for(Level::Error, scope) {
+            return;
+        }
+
+        let mut text = format!("{msg}: {err}");
+        let mut source = err.source();
+        while let Some(cause) = source {
+            text.push_str("\ncaused by: ");
+            text.push_str(&cause.to_string());
+            source = cause.source();
+        }
+        self.log(Level::Error, scope, &text);
+    }
+
     /// Prints a message that bypasses level filtering.
     ///
     /// Use this for command output (stats, themes, etc.) that should always be
@@ -160,11 +611,10 @@ impl Logger {
             label_override: None,
             app_name: self.app_name.clone(),
             raw: false,
+            timestamp: Some(self.now()),
         };
 
-        for output in &self.outputs {
-            let _ = output.write(&record);
-        }
+        self.dispatch(&record);
     }
 
     /// Outputs raw text without log formatting (no tag, icon, scope).
@@ -179,11 +629,10 @@ impl Logger {
             label_override: None,
             app_name: None,
             raw: true,
+            timestamp: Some(self.now()),
         };
 
-        for output in &self.outputs {
-            let _ = output.write(&record);
-        }
+        self.dispatch(&record);
     }
 
     /// Logs a message using a preset.
@@ -194,7 +643,7 @@ impl Logger {
             return false;
         };
 
-        let level: Level = preset.level.parse().unwrap_or(Level::Info);
+        let level = preset.filter_level().unwrap_or(Level::Info);
         let scope = preset.scope.as_deref().unwrap_or("LOG");
 
         self.log_full(level, scope, &preset.msg, preset.app_name.as_deref());
@@ -213,6 +662,56 @@ impl Logger {
         self.presets.len()
     }
 
+    /// Returns the presets as `(name, preset)` pairs, sorted by name.
+    ///
+    /// Lets embedding tools list presets (e.g. for a menu) with their level,
+    /// scope and app without keeping the config around.
+    pub fn presets(&self) -> impl Iterator<Item = (&str, &PresetConfig)> {
+        let mut presets: Vec<(&str, &PresetConfig)> = self
+            .presets
+            .iter()
+            .map(|(name, preset)| (name.as_str(), preset))
+            .collect();
+        presets.sort_unstable_by_key(|(name, _)| *name);
+        presets.into_iter()
+    }
+
+    /// Installs a process-wide panic hook that logs panics through this logger.
+    ///
+    /// Panics are logged at error level under scope `PANIC` with their
+    /// thread, message and location, then outputs are flushed and the hook
+    /// that was installed before this call runs. The hook is global and
+    /// applies to every thread. A hook installed after this one replaces it,
+    /// so panics stop being logged unless that hook chains to the previous one.
+    pub fn install_panic_hook(self: &Arc<Self>) {
+        let logger = Arc::clone(self);
+        let previous = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            let payload = info
+                .payload()
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| info.payload().downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("Box<dyn Any>");
+            let location = info
+                .location()
+                .map(|loc| format!(" at {}:{}:{}", loc.file(), loc.line(), loc.column()))
+                .unwrap_or_default();
+            let thread = std::thread::current();
+            let thread = thread.name().unwrap_or("<unnamed>");
+
+            logger.log(
+                Level::Error,
+                "PANIC",
+                &format!("thread '{thread}' panicked{location}: {payload}"),
+            );
+            let _ = logger.flush();
+
+            previous(info);
+        }));
+    }
+
     /// Flushes all outputs.
     ///
     /// # Errors
@@ -230,6 +729,41 @@ impl Logger {
         self.level()
     }
 
+    /// Enables or disables the output registered under `name`.
+    ///
+    /// `name` is one given with [`LoggerBuilder::name`], else the first
+    /// output whose [`Output::name`] matches. A disabled output is skipped
+    /// when records are written but stays open, keeping its handle and
+    /// buffers; [`flush`](Self::flush) still reaches it. The switch also
+    /// applies to loggers derived with [`with_level`](Self::with_level) or
+    /// [`with_prefix`](Self::with_prefix). Returns `false` if no output has
+    /// that name.
+    pub fn set_output_enabled(&self, name: &str, enabled: bool) -> bool {
+        let index = self
+            .named_outputs
+            .get(name)
+            .copied()
+            .or_else(|| self.outputs.iter().position(|output| output.name() == name));
+        let Some(index) = index else {
+            return false;
+        };
+        self.output_enabled[index].store(enabled, Ordering::Relaxed);
+        true
+    }
+
+    /// Returns the name of each output, in the order they were added.
+    ///
+    /// A name given with [`LoggerBuilder::name`] wins over the output's own
+    /// [`Output::name`].
+    #[must_use]
+    pub fn output_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.outputs.iter().map(|output| output.name()).collect();
+        for (name, &index) in &self.named_outputs {
+            names[index] = name;
+        }
+        names
+    }
+
     /// Returns the number of outputs.
     #[must_use]
     pub fn output_count(&self) -> usize {
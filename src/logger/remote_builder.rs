@@ -2,6 +2,7 @@
 
 use crate::logger::builder::LoggerBuilder;
 use crate::output::RemoteOutput;
+use std::sync::Arc;
 
 enum Target {
     Unix(String),
@@ -53,7 +54,7 @@ impl RemoteBuilder {
             Target::Unix(path) => RemoteOutput::unix(path),
             Target::Tcp(addr) => RemoteOutput::tcp(addr),
         };
-        self.parent.outputs.push(Box::new(output));
+        self.parent.outputs.push(Arc::new(output));
         self.parent
     }
 }
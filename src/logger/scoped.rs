@@ -0,0 +1,67 @@
+//! Scoped sub-logger with a fixed scope.
+
+use super::Logger;
+use crate::level::Level;
+
+/// A borrowed view of a [`Logger`] that logs under a fixed scope.
+///
+/// Created with [`Logger::child`]. Shares the parent's outputs and level;
+/// nothing is copied except the scope name.
+#[derive(Clone, Copy)]
+pub struct ScopedLogger<'a> {
+    logger: &'a Logger,
+    scope: &'a str,
+}
+
+impl<'a> ScopedLogger<'a> {
+    pub(super) const fn new(logger: &'a Logger, scope: &'a str) -> Self {
+        Self { logger, scope }
+    }
+
+    /// Returns the scope used for every record.
+    #[must_use]
+    pub const fn scope(&self) -> &str {
+        self.scope
+    }
+
+    /// Returns the parent logger.
+    #[must_use]
+    pub const fn logger(&self) -> &'a Logger {
+        self.logger
+    }
+
+    /// Logs a message at the given level.
+    pub fn log(&self, level: Level, msg: &str) {
+        self.logger.log(level, self.scope, msg);
+    }
+
+    /// Logs a message with a custom label override.
+    pub fn log_with_label(&self, level: Level, msg: &str, label: &str) {
+        self.logger.log_with_label(level, self.scope, msg, label);
+    }
+
+    /// Logs a trace message.
+    pub fn trace(&self, msg: &str) {
+        self.log(Level::Trace, msg);
+    }
+
+    /// Logs a debug message.
+    pub fn debug(&self, msg: &str) {
+        self.log(Level::Debug, msg);
+    }
+
+    /// Logs an info message.
+    pub fn info(&self, msg: &str) {
+        self.log(Level::Info, msg);
+    }
+
+    /// Logs a warning message.
+    pub fn warn(&self, msg: &str) {
+        self.log(Level::Warn, msg);
+    }
+
+    /// Logs an error message.
+    pub fn error(&self, msg: &str) {
+        self.log(Level::Error, msg);
+    }
+}
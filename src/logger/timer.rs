@@ -0,0 +1,70 @@
+//! Timer guard that logs elapsed time.
+
+use super::Logger;
+use crate::level::Level;
+use std::time::{Duration, Instant};
+
+/// Guard returned by [`Logger::timer`].
+///
+/// Logs `"{label} completed in {N}ms"` with an `elapsed_ms` field when
+/// dropped, or earlier via [`Timer::done`].
+#[must_use = "the timer logs when dropped; binding it to `_` drops it immediately"]
+pub struct Timer<'a> {
+    logger: &'a Logger,
+    scope: String,
+    label: String,
+    level: Level,
+    start: Instant,
+    finished: bool,
+}
+
+impl<'a> Timer<'a> {
+    pub(super) fn new(logger: &'a Logger, scope: &str, label: &str) -> Self {
+        Self {
+            logger,
+            scope: scope.to_string(),
+            label: label.to_string(),
+            level: Level::Debug,
+            start: Instant::now(),
+            finished: false,
+        }
+    }
+
+    /// Sets the level the completion message is logged at.
+    pub const fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Returns the time since the timer started.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Logs the completion message now instead of on drop.
+    pub fn done(mut self) {
+        self.finish();
+    }
+
+    fn finish(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+
+        let elapsed_ms = self.elapsed().as_millis().to_string();
+        self.logger.log_kv(
+            self.level,
+            &self.scope,
+            &format!("{} completed in {elapsed_ms}ms", self.label),
+            &[("elapsed_ms", &elapsed_ms)],
+        );
+    }
+}
+
+impl Drop for Timer<'_> {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
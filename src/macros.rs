@@ -0,0 +1,83 @@
+//! Logging macros with `format!`-style arguments.
+//!
+//! The message is only formatted when the level is enabled. Levels below
+//! [`STATIC_MAX_LEVEL`](crate::level::STATIC_MAX_LEVEL), set with the
+//! `max_level_*` Cargo features, compile to nothing.
+
+/// Logs at `level` with format arguments, optionally followed by
+/// `; "key" => value, ...` structured fields.
+///
+/// The call site's `file:line` is recorded for the `{location}` placeholder.
+///
+/// ```
+/// use hyprs_log::{Level, Logger, hl_log};
+///
+/// let logger = Logger::builder().build();
+/// let port = 8080;
+/// hl_log!(logger, Level::Info, "NET", "listening on {}", port);
+/// hl_log!(logger, Level::Info, "NET", "listening"; "port" => port);
+/// ```
+#[macro_export]
+macro_rules! hl_log {
+    ($logger:expr, $level:expr, $scope:expr, $fmt:literal $(, $arg:expr)* $(,)? ; $($key:literal => $value:expr),+ $(,)?) => {{
+        let logger = &$logger;
+        let level = $level;
+        let scope = $scope;
+        if $crate::Level::is_statically_enabled(level) && logger.is_enabled_for(level, scope) {
+            logger.log_located(
+                level,
+                scope,
+                &::std::format!($fmt $(, $arg)*),
+                &[$(($key, &*::std::string::ToString::to_string(&$value))),+],
+            );
+        }
+    }};
+    ($logger:expr, $level:expr, $scope:expr, $($arg:tt)+) => {{
+        let logger = &$logger;
+        let level = $level;
+        let scope = $scope;
+        if $crate::Level::is_statically_enabled(level) && logger.is_enabled_for(level, scope) {
+            logger.log_located(level, scope, &::std::format!($($arg)+), &[]);
+        }
+    }};
+}
+
+/// Logs at trace level. See [`hl_log!`] for the argument forms.
+#[macro_export]
+macro_rules! hl_trace {
+    ($logger:expr, $scope:expr, $($arg:tt)+) => {
+        $crate::hl_log!($logger, $crate::Level::Trace, $scope, $($arg)+)
+    };
+}
+
+/// Logs at debug level. See [`hl_log!`] for the argument forms.
+#[macro_export]
+macro_rules! hl_debug {
+    ($logger:expr, $scope:expr, $($arg:tt)+) => {
+        $crate::hl_log!($logger, $crate::Level::Debug, $scope, $($arg)+)
+    };
+}
+
+/// Logs at info level. See [`hl_log!`] for the argument forms.
+#[macro_export]
+macro_rules! hl_info {
+    ($logger:expr, $scope:expr, $($arg:tt)+) => {
+        $crate::hl_log!($logger, $crate::Level::Info, $scope, $($arg)+)
+    };
+}
+
+/// Logs at warn level. See [`hl_log!`] for the argument forms.
+#[macro_export]
+macro_rules! hl_warn {
+    ($logger:expr, $scope:expr, $($arg:tt)+) => {
+        $crate::hl_log!($logger, $crate::Level::Warn, $scope, $($arg)+)
+    };
+}
+
+/// Logs at error level. See [`hl_log!`] for the argument forms.
+#[macro_export]
+macro_rules! hl_error {
+    ($logger:expr, $scope:expr, $($arg:tt)+) => {
+        $crate::hl_log!($logger, $crate::Level::Error, $scope, $($arg)+)
+    };
+}
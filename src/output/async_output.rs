@@ -0,0 +1,153 @@
+//! Async output: moves writes to a background thread.
+
+use crate::internal;
+use crate::output::{LogRecord, Output};
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::thread;
+
+/// Default queue capacity for [`AsyncOutput`].
+pub const DEFAULT_CAPACITY: usize = 1024;
+
+/// What [`AsyncOutput::write`] does when the queue is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Wait for the worker to free a slot. No records are lost, but a stalled
+    /// inner output eventually blocks the logging thread.
+    #[default]
+    Block,
+    /// Discard the record and return immediately. The logging thread never
+    /// waits; see [`AsyncOutput::dropped`] for how many records were lost.
+    Drop,
+}
+
+/// Message sent to the worker thread.
+enum Message {
+    Record(Box<LogRecord>),
+    Flush(SyncSender<Result<(), crate::Error>>),
+}
+
+/// Output wrapper that writes to an inner output on a background thread.
+///
+/// `write()` enqueues the record in a bounded channel and returns; the worker
+/// writes records to the inner output in order. `flush()` blocks until every
+/// record queued before it has been written and the inner output flushed.
+pub struct AsyncOutput {
+    sender: Option<SyncSender<Message>>,
+    policy: OverflowPolicy,
+    dropped: AtomicU64,
+    worker: Option<thread::JoinHandle<()>>,
+    /// Name of the wrapped output.
+    name: String,
+}
+
+impl AsyncOutput {
+    /// Wraps `inner` with the default capacity and [`OverflowPolicy::Block`].
+    #[must_use]
+    pub fn new(inner: impl Output + 'static) -> Self {
+        Self::with_options(inner, DEFAULT_CAPACITY, OverflowPolicy::default())
+    }
+
+    /// Wraps `inner` with a queue of `capacity` records and the given policy.
+    ///
+    /// # Panics
+    /// Panics if the worker thread cannot be spawned.
+    #[must_use]
+    pub fn with_options(
+        inner: impl Output + 'static,
+        capacity: usize,
+        policy: OverflowPolicy,
+    ) -> Self {
+        let name = inner.name().to_string();
+        let (tx, rx) = mpsc::sync_channel(capacity.max(1));
+        let handle = thread::Builder::new()
+            .name("hyprslog-async".into())
+            .spawn(move || worker(&inner, &rx))
+            .expect("failed to spawn async output thread");
+
+        Self {
+            sender: Some(tx),
+            policy,
+            dropped: AtomicU64::new(0),
+            worker: Some(handle),
+            name,
+        }
+    }
+
+    /// Returns the number of records discarded because the queue was full.
+    #[must_use]
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn sender(&self) -> Result<&SyncSender<Message>, crate::Error> {
+        self.sender.as_ref().ok_or_else(worker_stopped)
+    }
+}
+
+/// Background thread: writes queued records until the sender is dropped.
+fn worker(inner: &dyn Output, rx: &mpsc::Receiver<Message>) {
+    for message in rx {
+        match message {
+            Message::Record(record) => {
+                if let Err(e) = inner.write(&record) {
+                    internal::warn("ASYNC", &format!("Inner output write failed: {e}"));
+                }
+            }
+            Message::Flush(reply) => {
+                let _ = reply.send(inner.flush());
+            }
+        }
+    }
+
+    if let Err(e) = inner.flush() {
+        internal::warn("ASYNC", &format!("Inner output flush failed: {e}"));
+    }
+}
+
+fn worker_stopped() -> crate::Error {
+    io::Error::other("async output worker stopped").into()
+}
+
+impl Output for AsyncOutput {
+    fn write(&self, record: &LogRecord) -> Result<(), crate::Error> {
+        let message = Message::Record(Box::new(record.clone()));
+        let sender = self.sender()?;
+
+        match self.policy {
+            OverflowPolicy::Block => sender.send(message).map_err(|_| worker_stopped()),
+            OverflowPolicy::Drop => match sender.try_send(message) {
+                Ok(()) => Ok(()),
+                Err(TrySendError::Full(_)) => {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                }
+                Err(TrySendError::Disconnected(_)) => Err(worker_stopped()),
+            },
+        }
+    }
+
+    fn flush(&self) -> Result<(), crate::Error> {
+        // Always waits for a slot, even under the drop policy
+        let (reply_tx, reply_rx) = mpsc::sync_channel(1);
+        self.sender()?
+            .send(Message::Flush(reply_tx))
+            .map_err(|_| worker_stopped())?;
+        reply_rx.recv().map_err(|_| worker_stopped())?
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Drop for AsyncOutput {
+    fn drop(&mut self) {
+        // Closing the channel lets the worker drain, flush, and exit
+        self.sender = None;
+        if let Some(handle) = self.worker.take() {
+            let _ = handle.join();
+        }
+    }
+}
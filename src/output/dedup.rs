@@ -0,0 +1,112 @@
+//! Dedup output: collapses consecutive identical records.
+
+use crate::internal;
+use crate::output::{LogRecord, Output};
+use std::sync::Mutex;
+
+/// Output wrapper that collapses back-to-back identical records, syslog style.
+///
+/// The first record is written through; exact repeats are counted instead.
+/// When a different record arrives, or on `flush()`, a
+/// `last message repeated N times` record is written first (same level and
+/// scope as the repeated one). Wrap each output separately to dedup only
+/// some of them.
+pub struct DedupOutput<O: Output> {
+    inner: O,
+    /// Last record written and how many repeats were swallowed since.
+    last: Mutex<Option<(LogRecord, usize)>>,
+}
+
+impl<O: Output> DedupOutput<O> {
+    /// Wraps `inner`.
+    pub const fn new(inner: O) -> Self {
+        Self {
+            inner,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Returns the wrapped output.
+    pub const fn inner(&self) -> &O {
+        &self.inner
+    }
+
+    /// Writes the repeat notice for `last`, if any repeats were swallowed.
+    fn write_repeats(&self, last: &mut Option<(LogRecord, usize)>) -> Result<(), crate::Error> {
+        if let Some((record, repeats)) = last
+            && *repeats > 0
+        {
+            let notice = repeat_notice(record, *repeats);
+            *repeats = 0;
+            self.inner.write(&notice)?;
+        }
+        Ok(())
+    }
+}
+
+/// Builds the `last message repeated N times` record.
+fn repeat_notice(record: &LogRecord, repeats: usize) -> LogRecord {
+    let times = if repeats == 1 { "time" } else { "times" };
+    LogRecord {
+        level: record.level,
+        scope: record.scope.clone(),
+        message: format!("last message repeated {repeats} {times}"),
+        values: crate::fmt::FormatValues::new(),
+        label_override: None,
+        app_name: record.app_name.clone(),
+        raw: false,
+        timestamp: None,
+    }
+}
+
+/// Returns true if two records would render identically.
+fn same_record(a: &LogRecord, b: &LogRecord) -> bool {
+    a.level == b.level
+        && a.raw == b.raw
+        && a.scope == b.scope
+        && a.message == b.message
+        && a.label_override == b.label_override
+        && a.app_name == b.app_name
+        && a.values.fields == b.values.fields
+}
+
+impl<O: Output> Output for DedupOutput<O> {
+    fn write(&self, record: &LogRecord) -> Result<(), crate::Error> {
+        let mut last = self.last.lock().unwrap();
+
+        if let Some((previous, repeats)) = last.as_mut()
+            && same_record(previous, record)
+        {
+            *repeats += 1;
+            return Ok(());
+        }
+
+        self.write_repeats(&mut last)?;
+        *last = Some((record.clone(), 0));
+
+        // Keep the lock while writing so notices and records stay ordered
+        let result = self.inner.write(record);
+        drop(last);
+        result
+    }
+
+    fn flush(&self) -> Result<(), crate::Error> {
+        let mut last = self.last.lock().unwrap();
+        self.write_repeats(&mut last)?;
+        drop(last);
+        self.inner.flush()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+impl<O: Output> Drop for DedupOutput<O> {
+    /// Writes any pending repeat notice so it isn't lost on shutdown.
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            internal::error("DEDUP", &format!("Flush on drop failed: {e}"));
+        }
+    }
+}
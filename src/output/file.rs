@@ -1,15 +1,36 @@
 //! File output with path templates.
 
-use crate::fmt::{FormatTemplate, FormatValues, TagConfig, style};
+use crate::fmt::{
+    FormatTemplate, FormatValues, Placeholder, ScopeConfig, TagConfig, Timezone, hostname, style,
+};
 use crate::internal;
 
-use super::{LogRecord, Output};
-use chrono::Local;
+use super::{LineEnding, LogRecord, Output, SyncMode, TerminalOutput};
 use std::cell::Cell;
-use std::fs::{self, OpenOptions};
-use std::io::Write;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
 use std::path::PathBuf;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Maximum number of file handles kept open at once.
+///
+/// Templates with `{level}`/`{scope}`/`{day}` fan out to many files; past this
+/// limit all writers are flushed and closed before opening a new one.
+const MAX_OPEN_FILES: usize = 32;
+
+/// Extra attempts for a write or flush that failed with a transient error.
+const WRITE_RETRIES: u32 = 2;
+
+/// Pause before the first retry; doubled for each one after.
+const RETRY_BACKOFF: Duration = Duration::from_millis(10);
+
+/// `EIO`, the same on Linux, macOS and the BSDs.
+const EIO: i32 = 5;
 
 // Thread-local recursion guard to prevent deadlock when internal logging
 // triggers file output which tries to log again.
@@ -18,6 +39,13 @@ thread_local! {
 }
 
 /// File output configuration.
+///
+/// Pending lines are flushed when the output is dropped, but errors there can
+/// only be logged; prefer an explicit [`Output::flush`] before shutdown.
+///
+/// Writes and flushes that fail with `EINTR`, `EAGAIN` or `EIO` (common on
+/// flaky network mounts) are retried twice with a short backoff before the
+/// error is returned. Other errors, such as a full disk, fail right away.
 pub struct FileOutput {
     /// Base directory for log files.
     base_dir: String,
@@ -29,12 +57,26 @@ pub struct FileOutput {
     content_template: FormatTemplate,
     /// Timestamp format (strftime).
     timestamp_format: String,
+    /// Zone for `{timestamp}` and the date placeholders.
+    timezone: Timezone,
     /// Application name for templates.
     app_name: String,
     /// Tag formatting config.
     tag_config: TagConfig,
-    /// Buffered line (header + raw items collected).
-    buffer: Mutex<Option<BufferedLine>>,
+    /// Scope formatting config applied to `{scope}` in content lines.
+    scope_config: ScopeConfig,
+    /// Number lines per file for `{seq}`.
+    sequence: bool,
+    /// Pending line and open writers, shared with the flush thread.
+    state: Arc<Mutex<FileState>>,
+    /// Keeps the periodic flush thread alive; dropping it stops the thread.
+    flush_ticker: Option<mpsc::Sender<()>>,
+    /// Set once the base directory has been probed on the first write.
+    probed: AtomicBool,
+    /// Set when that probe failed.
+    unwritable: AtomicBool,
+    /// Receives records too while the base directory is unwritable.
+    fallback: Option<TerminalOutput>,
 }
 
 /// A buffered log line with collected raw items.
@@ -45,6 +87,163 @@ struct BufferedLine {
     path: PathBuf,
     /// Collected raw items.
     items: Vec<String>,
+    /// When the header was buffered.
+    created: Instant,
+}
+
+/// Mutable file output state guarded by a single lock.
+struct FileState {
+    /// Line waiting for raw continuation items.
+    pending: Option<BufferedLine>,
+    /// Open writers keyed by file path.
+    writers: HashMap<PathBuf, BufWriter<File>>,
+    /// Lines written since the last flush.
+    unflushed: usize,
+    /// Flush writers after this many lines (1 = after every line).
+    buffer_lines: usize,
+    /// Unix permission bits for newly created files.
+    file_mode: Option<u32>,
+    /// Terminator appended to each line.
+    line_ending: LineEnding,
+    /// Last sequence number handed out per file path.
+    ///
+    /// Kept when writers are closed at [`MAX_OPEN_FILES`], so a reopened file
+    /// continues its numbering.
+    sequences: HashMap<PathBuf, u64>,
+    /// Disk sync applied to each writer when flushing.
+    sync_mode: SyncMode,
+    /// Sync calls made so far.
+    syncs: u64,
+}
+
+impl Default for FileState {
+    fn default() -> Self {
+        Self {
+            pending: None,
+            writers: HashMap::new(),
+            unflushed: 0,
+            buffer_lines: 1,
+            file_mode: None,
+            line_ending: LineEnding::Lf,
+            sequences: HashMap::new(),
+            sync_mode: SyncMode::None,
+            syncs: 0,
+        }
+    }
+}
+
+impl FileState {
+    /// Writes the pending line (if any) to its writer.
+    fn write_pending(&mut self) -> Result<(), crate::Error> {
+        if let Some(buf) = self.pending.take() {
+            self.write_line(&buf)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a buffered line, flushing once `buffer_lines` lines accumulate.
+    fn write_line(&mut self, buf: &BufferedLine) -> Result<(), crate::Error> {
+        // Build single line: header + items joined
+        let mut line = buf.content.clone();
+        if !buf.items.is_empty() {
+            line.push(' ');
+            line.push_str(&buf.items.join(", "));
+        }
+        line.push_str(self.line_ending.as_str());
+
+        write_all_retrying(self.writer(&buf.path)?, line.as_bytes())?;
+        self.unflushed += 1;
+
+        if self.unflushed >= self.buffer_lines {
+            self.flush_writers()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the open writer for `path`, opening it if needed.
+    fn writer(&mut self, path: &PathBuf) -> Result<&mut BufWriter<File>, crate::Error> {
+        if !self.writers.contains_key(path) {
+            if self.writers.len() >= MAX_OPEN_FILES {
+                self.flush_writers()?;
+                self.writers.clear();
+            }
+
+            // Directories may have been removed since the line was buffered;
+            // create_dir_all tolerates concurrent creation
+            if let Some(parent) = path.parent()
+                && !parent.exists()
+            {
+                fs::create_dir_all(parent)?;
+            }
+
+            // create + append (no O_EXCL): threads racing to create the same
+            // new file both end up appending to it
+            let mut options = OpenOptions::new();
+            options.create(true).append(true);
+            #[cfg(unix)]
+            if let Some(mode) = self.file_mode {
+                use std::os::unix::fs::OpenOptionsExt;
+                options.mode(mode);
+            }
+            let file = options.open(path)?;
+            self.writers.insert(path.clone(), BufWriter::new(file));
+        }
+        Ok(self.writers.get_mut(path).expect("writer inserted above"))
+    }
+
+    /// Flushes all open writers, keeping them open.
+    ///
+    /// Syncs them per [`SyncMode`] if lines were written since the last flush.
+    fn flush_writers(&mut self) -> Result<(), crate::Error> {
+        let dirty = self.unflushed > 0;
+        self.unflushed = 0;
+        for writer in self.writers.values_mut() {
+            retry_transient(|| writer.flush())?;
+            // Not retried: after a failed fsync the kernel may already have
+            // dropped the dirty pages, so a second success proves nothing
+            if dirty && self.sync_mode.apply(writer.get_ref())? {
+                self.syncs += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether `e` may go away if the same call is made again.
+fn is_transient(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock
+    ) || (cfg!(unix) && e.raw_os_error() == Some(EIO))
+}
+
+/// Runs `op`, retrying transient failures up to [`WRITE_RETRIES`] times.
+fn retry_transient<T>(mut op: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+    let mut backoff = RETRY_BACKOFF;
+    let mut retries = 0;
+    loop {
+        match op() {
+            Err(e) if retries < WRITE_RETRIES && is_transient(&e) => {
+                retries += 1;
+                thread::sleep(backoff);
+                backoff *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Like [`Write::write_all`], retrying each failed `write` via [`retry_transient`].
+///
+/// A failed `write` writes nothing, so a retry never duplicates bytes.
+fn write_all_retrying(writer: &mut impl Write, mut buf: &[u8]) -> io::Result<()> {
+    while !buf.is_empty() {
+        match retry_transient(|| writer.write(buf))? {
+            0 => return Err(io::ErrorKind::WriteZero.into()),
+            written => buf = &buf[written..],
+        }
+    }
+    Ok(())
 }
 
 impl Default for FileOutput {
@@ -74,9 +273,16 @@ impl FileOutput {
             filename_template: FormatTemplate::parse("{scope}_{level}_{day}.log"),
             content_template: FormatTemplate::parse("{timestamp} {tag} {scope}  {msg}"),
             timestamp_format: "%Y-%m-%d %H:%M:%S".to_string(),
+            timezone: Timezone::Local,
             app_name: "hyprslog".to_string(),
             tag_config: TagConfig::default(),
-            buffer: Mutex::new(None),
+            scope_config: ScopeConfig::new().min_width(0),
+            sequence: false,
+            state: Arc::new(Mutex::new(FileState::default())),
+            flush_ticker: None,
+            probed: AtomicBool::new(false),
+            unwritable: AtomicBool::new(false),
+            fallback: None,
         }
     }
 
@@ -91,6 +297,7 @@ impl FileOutput {
     #[must_use]
     pub fn path_structure(mut self, template: &str) -> Self {
         self.path_template = FormatTemplate::parse(template);
+        self.path_template.warn_unknown("file path_structure");
         self
     }
 
@@ -98,6 +305,8 @@ impl FileOutput {
     #[must_use]
     pub fn filename_structure(mut self, template: &str) -> Self {
         self.filename_template = FormatTemplate::parse(template);
+        self.filename_template
+            .warn_unknown("file filename_structure");
         self
     }
 
@@ -105,6 +314,16 @@ impl FileOutput {
     #[must_use]
     pub fn content_structure(mut self, template: &str) -> Self {
         self.content_template = FormatTemplate::parse(template);
+        self.content_template.warn_unknown("file content_structure");
+        self
+    }
+
+    /// Sets the zone timestamps and date path segments are rendered in.
+    ///
+    /// Defaults to [`Timezone::Local`].
+    #[must_use]
+    pub const fn timezone(mut self, timezone: Timezone) -> Self {
+        self.timezone = timezone;
         self
     }
 
@@ -129,6 +348,150 @@ impl FileOutput {
         self
     }
 
+    /// Sets the scope configuration.
+    ///
+    /// Defaults to no padding and no transform so plain file lines stay
+    /// parser-friendly; pass the terminal's config to align columns.
+    #[must_use]
+    pub const fn scope_config(mut self, config: ScopeConfig) -> Self {
+        self.scope_config = config;
+        self
+    }
+
+    /// Flushes to disk after every `lines` log lines.
+    ///
+    /// Files stay open between writes either way; the default of 1 flushes
+    /// each line immediately. Larger values trade durability for throughput.
+    #[must_use]
+    pub fn buffer_lines(self, lines: usize) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.buffer_lines = lines.max(1);
+        }
+        self
+    }
+
+    /// Sets the permission bits for newly created log files (e.g. `0o600`).
+    ///
+    /// Applied on Unix only, subject to the process umask. Existing files keep
+    /// their permissions.
+    #[must_use]
+    pub fn file_mode(self, mode: u32) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.file_mode = Some(mode);
+        }
+        self
+    }
+
+    /// Sets the terminator appended to each line (default [`LineEnding::Lf`]).
+    #[must_use]
+    pub fn line_ending(self, ending: LineEnding) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.line_ending = ending;
+        }
+        self
+    }
+
+    /// Syncs files to disk whenever lines are flushed (default [`SyncMode::None`]).
+    ///
+    /// The sync follows the flush, so with [`buffer_lines`](Self::buffer_lines)
+    /// at 1 every line is durable once written; larger batches are synced
+    /// together, trading the last few lines on a crash for far fewer syncs.
+    #[must_use]
+    pub fn sync_mode(self, mode: SyncMode) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.sync_mode = mode;
+        }
+        self
+    }
+
+    /// Returns how many sync calls were made, see [`sync_mode`](Self::sync_mode).
+    #[must_use]
+    pub fn syncs(&self) -> u64 {
+        self.state.lock().map_or(0, |state| state.syncs)
+    }
+
+    /// Flushes buffered lines every `ms` milliseconds from a background thread.
+    ///
+    /// Combine with [`buffer_lines`](Self::buffer_lines) so bursts are batched
+    /// but quiet periods still reach disk. `0` disables the timer.
+    #[must_use]
+    pub fn buffer_flush_ms(mut self, ms: u64) -> Self {
+        // Dropping the old sender stops any previously started thread
+        self.flush_ticker = None;
+        if ms == 0 {
+            return self;
+        }
+
+        let (tx, rx) = mpsc::channel::<()>();
+        let state = Arc::clone(&self.state);
+        let interval = Duration::from_millis(ms);
+
+        let spawned = thread::Builder::new()
+            .name("hyprslog-file-flush".into())
+            .spawn(move || {
+                while rx.recv_timeout(interval) == Err(RecvTimeoutError::Timeout) {
+                    let Ok(mut state) = state.lock() else {
+                        break;
+                    };
+                    // Leave fresh lines pending so raw items can still attach
+                    if state
+                        .pending
+                        .as_ref()
+                        .is_some_and(|p| p.created.elapsed() >= interval)
+                    {
+                        let _ = state.write_pending();
+                    }
+                    let _ = state.flush_writers();
+                }
+            });
+
+        match spawned {
+            Ok(_) => self.flush_ticker = Some(tx),
+            Err(e) => {
+                internal::warn("FILE", &format!("Failed to start flush thread: {e}"));
+            }
+        }
+        self
+    }
+
+    /// Numbers lines per file, exposed as the `{seq}` placeholder.
+    ///
+    /// Each file path gets its own counter starting at 1, so a missing number
+    /// shows a dropped or removed line. Counters live in memory and restart at
+    /// 1 when the process restarts; `{pid}` or `{timestamp}` next to `{seq}`
+    /// tells runs apart.
+    #[must_use]
+    pub const fn sequence(mut self, enabled: bool) -> Self {
+        self.sequence = enabled;
+        self
+    }
+
+    /// Also writes records to `fallback` if the first write finds the base
+    /// directory unwritable, so they are not lost.
+    #[must_use]
+    pub fn fallback(mut self, fallback: TerminalOutput) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Checks that log files can be created under the base directory.
+    ///
+    /// Creates the base directory if needed and writes (then removes) a probe
+    /// file. Path and filename templates are not evaluated. The output runs
+    /// this itself on its first write; call it to check earlier.
+    ///
+    /// # Errors
+    /// Returns the I/O error if the directory or probe file can't be created.
+    pub fn probe_writable(&self) -> Result<(), crate::Error> {
+        let base = self.resolve_base_dir();
+        fs::create_dir_all(&base)?;
+
+        let probe = base.join(format!(".hyprslog-probe-{}", std::process::id()));
+        OpenOptions::new().create(true).append(true).open(&probe)?;
+        fs::remove_file(&probe)?;
+        Ok(())
+    }
+
     /// Resolves the base directory (expands ~).
     fn resolve_base_dir(&self) -> PathBuf {
         let expanded = shellexpand::tilde(&self.base_dir);
@@ -143,12 +506,13 @@ impl FileOutput {
     /// Builds the full file path for a record.
     fn build_path(&self, record: &LogRecord) -> PathBuf {
         let base = self.resolve_base_dir();
-        let now = Local::now();
+        let now = self.timezone.convert(record.time());
 
         let values = FormatValues::new()
             .level(record.level.as_str())
             .scope(&record.scope)
             .app(record.app_name.as_deref().unwrap_or(&self.app_name))
+            .hostname(hostname())
             .date(
                 &now.format("%Y").to_string(),
                 &now.format("%m").to_string(),
@@ -162,70 +526,56 @@ impl FileOutput {
     }
 
     /// Formats the content line.
-    fn format_content(&self, record: &LogRecord) -> String {
-        let now = Local::now();
-        let timestamp = now.format(&self.timestamp_format).to_string();
+    fn format_content(&self, record: &LogRecord, seq: &str) -> String {
+        let template = &self.content_template;
         let tag = record.format_tag(&self.tag_config);
 
+        let scope = self.scope_config.format(&record.scope);
+
         // Strip styling tags from message for file output
-        let clean_msg = style::strip_tags(&record.message);
+        let clean_msg = style::strip_tags(&record.message_with_fields());
 
-        let values = FormatValues::new()
-            .timestamp(&timestamp)
+        // Per-record lookups only if the template uses them
+        let mut values = FormatValues::new()
             .tag(&tag)
-            .scope(&record.scope)
+            .scope(&scope)
             .msg(&clean_msg)
             .level(record.level.as_str())
-            .app(record.app_name.as_deref().unwrap_or(&self.app_name));
+            .app(record.app_name.as_deref().unwrap_or(&self.app_name))
+            .location(&record.values.location)
+            .seq(seq);
+        if template.uses(Placeholder::Timestamp) {
+            let now = self.timezone.convert(record.time());
+            values = values.timestamp(now.format(&self.timestamp_format).to_string());
+        }
+        if template.uses(Placeholder::Hostname) {
+            values = values.hostname(hostname());
+        }
+        if template.uses(Placeholder::Pid) {
+            values = values.pid(std::process::id().to_string());
+        }
 
-        self.content_template.render(&values)
+        template.render(&values)
     }
 }
 
 impl FileOutput {
-    /// Writes a buffered line to file.
-    fn write_buffered(buf: &BufferedLine) -> Result<(), crate::Error> {
-        // Create directories
-        if let Some(parent) = buf.path.parent()
-            && !parent.exists()
-        {
-            fs::create_dir_all(parent)?;
-        }
-
-        let mut file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&buf.path)?;
-
-        // Build single line: header + items joined
-        let mut line = buf.content.clone();
-        if !buf.items.is_empty() {
-            line.push(' ');
-            line.push_str(&buf.items.join(", "));
-        }
-        line.push('\n');
-        file.write_all(line.as_bytes())?;
-        Ok(())
-    }
-
     /// Inner write implementation (called with recursion guard set).
     fn write_inner(&self, record: &LogRecord) -> Result<(), crate::Error> {
-        let mut buffer = self.buffer.lock().unwrap();
+        let mut state = self.state.lock().unwrap();
 
         if record.raw {
             // Raw message: collect into buffer items
             let clean = style::strip_tags(&record.message).trim().to_string();
-            if let Some(ref mut buf) = *buffer {
+            if let Some(ref mut buf) = state.pending {
                 buf.items.push(clean);
             }
             // If no buffer exists, raw message is orphaned - ignore it
             return Ok(());
         }
 
-        // Normal message: flush existing buffer first
-        if let Some(ref buf) = *buffer {
-            Self::write_buffered(buf)?;
-        }
+        // Normal message: write existing pending line first
+        state.write_pending()?;
 
         // Build new buffered line
         let path = self.build_path(record);
@@ -238,14 +588,23 @@ impl FileOutput {
             internal::debug("FILE", &format!("Created directory: {}", parent.display()));
         }
 
-        let content = self.format_content(record);
-
-        *buffer = Some(BufferedLine {
+        // Numbered under the state lock, so numbers follow write order
+        let seq = if self.sequence {
+            let next = state.sequences.entry(path.clone()).or_insert(0);
+            *next += 1;
+            next.to_string()
+        } else {
+            String::new()
+        };
+        let content = self.format_content(record, &seq);
+
+        state.pending = Some(BufferedLine {
             content,
             path,
             items: Vec::new(),
+            created: Instant::now(),
         });
-        drop(buffer);
+        drop(state);
 
         Ok(())
     }
@@ -255,24 +614,131 @@ impl Output for FileOutput {
     fn write(&self, record: &LogRecord) -> Result<(), crate::Error> {
         // Set recursion guard to prevent deadlock from internal logging
         IN_FILE_WRITE.with(|flag| flag.set(true));
-        let result = self.write_inner(record);
+        self.probe_once();
+        let result = match &self.fallback {
+            Some(fallback) if self.unwritable.load(Ordering::Relaxed) => fallback
+                .write(record)
+                .and_then(|()| self.write_inner(record)),
+            _ => self.write_inner(record),
+        };
         IN_FILE_WRITE.with(|flag| flag.set(false));
         result
     }
 
     fn flush(&self) -> Result<(), crate::Error> {
-        let mut buffer = self.buffer.lock().unwrap();
-        if let Some(ref buf) = *buffer {
-            Self::write_buffered(buf)?;
+        if let Some(fallback) = &self.fallback {
+            fallback.flush()?;
         }
-        *buffer = None;
-        drop(buffer);
+        let mut state = self.state.lock().unwrap();
+        state.write_pending()?;
+        state.flush_writers()?;
+        drop(state);
         Ok(())
     }
+
+    fn name(&self) -> &'static str {
+        "file"
+    }
+}
+
+impl FileOutput {
+    /// Probes the base directory on the first write and reports a failure.
+    ///
+    /// The flag is set before probing, so internal logging that ends up
+    /// back in this output doesn't probe again.
+    fn probe_once(&self) {
+        if self.probed.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        if let Err(e) = self.probe_writable() {
+            self.unwritable.store(true, Ordering::Relaxed);
+            internal::error("FILE", &format!("Log directory is not writable: {e}"));
+            if self.fallback.is_some() {
+                internal::warn("FILE", "Falling back to stderr terminal output");
+            }
+        }
+    }
 }
 
 impl Drop for FileOutput {
+    /// Best-effort flush of pending and buffered lines.
+    ///
+    /// Errors are reported via internal logging only; call
+    /// [`Output::flush`] explicitly to handle them.
     fn drop(&mut self) {
-        let _ = self.flush();
+        // Stop the flush thread before the final flush
+        self.flush_ticker = None;
+        if let Err(e) = self.flush() {
+            internal::error("FILE", &format!("Flush on drop failed: {e}"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writer failing its first `failures` writes with `error`.
+    struct Flaky {
+        failures: usize,
+        error: fn() -> io::Error,
+        attempts: usize,
+        data: Vec<u8>,
+    }
+
+    impl Flaky {
+        fn new(failures: usize, error: fn() -> io::Error) -> Self {
+            Self {
+                failures,
+                error,
+                attempts: 0,
+                data: Vec::new(),
+            }
+        }
+    }
+
+    impl Write for Flaky {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.attempts += 1;
+            if self.attempts <= self.failures {
+                return Err((self.error)());
+            }
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn eio() -> io::Error {
+        io::Error::from_raw_os_error(EIO)
+    }
+
+    #[test]
+    fn transient_errors_are_retried_until_the_line_lands() {
+        let mut writer = Flaky::new(2, eio);
+        write_all_retrying(&mut writer, b"line\n").unwrap();
+
+        assert_eq!(writer.attempts, 3);
+        assert_eq!(writer.data, b"line\n");
+    }
+
+    #[test]
+    fn retries_are_bounded() {
+        let mut writer = Flaky::new(usize::MAX, eio);
+        let err = write_all_retrying(&mut writer, b"line\n").unwrap_err();
+
+        assert_eq!(err.raw_os_error(), Some(EIO));
+        assert_eq!(writer.attempts, 3);
+        assert!(writer.data.is_empty());
+    }
+
+    #[test]
+    fn full_disk_is_not_retried() {
+        let mut writer = Flaky::new(1, || io::ErrorKind::StorageFull.into());
+        assert!(write_all_retrying(&mut writer, b"line\n").is_err());
+        assert_eq!(writer.attempts, 1);
     }
 }
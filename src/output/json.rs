@@ -1,14 +1,16 @@
 //! JSON output for structured log database.
 
-use super::{LogRecord, Output};
-use crate::fmt::style;
+use super::{LineEnding, LogRecord, Output};
+use crate::fmt::{hostname, style};
 use crate::internal;
 
 use chrono::Local;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use ulid::Ulid;
 
 /// A single JSON log entry for the database.
@@ -27,6 +29,11 @@ struct JsonEntry {
     /// Application name.
     #[serde(skip_serializing_if = "Option::is_none")]
     app: Option<String>,
+    /// Host that produced the entry.
+    host: String,
+    /// Structured key-value fields.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<String, String>,
     /// Custom label override (e.g., "SUCCESS" instead of "INFO").
     #[serde(skip_serializing_if = "Option::is_none")]
     label: Option<String>,
@@ -36,14 +43,32 @@ struct JsonEntry {
 ///
 /// Writes log entries as JSON Lines (one JSON object per line) to a file,
 /// creating a queryable log database.
+///
+/// With [`max_file_size`](Self::max_file_size) set, a file that would grow
+/// past the limit is rolled to `<path>.1` (older backups shift to `.2`, ...)
+/// before the entry is written, so every file holds complete lines.
+///
+/// Dropping the output flushes it best-effort; prefer an explicit
+/// [`Output::flush`] to observe errors.
 #[derive(Debug, Clone)]
 pub struct JsonOutput {
     /// Path to the JSONL file.
     file_path: PathBuf,
     /// Application name for entries.
     app_name: Option<String>,
+    /// Terminator appended to each entry.
+    line_ending: LineEnding,
+    /// Size in bytes that triggers rotation; `None` never rotates.
+    max_file_size: Option<u64>,
+    /// Rolled files kept next to the active one.
+    max_backups: usize,
+    /// Serializes the size check, rotation and append.
+    write_lock: Arc<Mutex<()>>,
 }
 
+/// Rolled files kept when [`JsonOutput::max_backups`] is not set.
+const DEFAULT_MAX_BACKUPS: usize = 5;
+
 impl Default for JsonOutput {
     fn default() -> Self {
         Self::new()
@@ -69,6 +94,10 @@ impl JsonOutput {
         Self {
             file_path,
             app_name: None,
+            line_ending: LineEnding::Lf,
+            max_file_size: None,
+            max_backups: DEFAULT_MAX_BACKUPS,
+            write_lock: Arc::new(Mutex::new(())),
         }
     }
 
@@ -86,6 +115,57 @@ impl JsonOutput {
         self
     }
 
+    /// Sets the terminator appended to each entry (default [`LineEnding::Lf`]).
+    #[must_use]
+    pub const fn line_ending(mut self, ending: LineEnding) -> Self {
+        self.line_ending = ending;
+        self
+    }
+
+    /// Rotates the file once it would grow past `bytes`.
+    #[must_use]
+    pub const fn max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = Some(bytes);
+        self
+    }
+
+    /// Sets how many rolled files to keep (default 5); 0 discards the full file.
+    #[must_use]
+    pub const fn max_backups(mut self, count: usize) -> Self {
+        self.max_backups = count;
+        self
+    }
+
+    /// Rolls `path` over when appending `incoming` bytes would pass the limit.
+    ///
+    /// An empty file always takes the entry, so one oversized entry can't
+    /// rotate forever.
+    fn rotate_if_needed(&self, path: &Path, incoming: u64) -> Result<(), crate::Error> {
+        let Some(max) = self.max_file_size else {
+            return Ok(());
+        };
+        let Ok(size) = fs::metadata(path).map(|m| m.len()) else {
+            return Ok(());
+        };
+        if size == 0 || size + incoming <= max {
+            return Ok(());
+        }
+
+        if self.max_backups == 0 {
+            fs::remove_file(path)?;
+        } else {
+            for index in (1..self.max_backups).rev() {
+                let from = backup_path(path, index);
+                if from.exists() {
+                    fs::rename(&from, backup_path(path, index + 1))?;
+                }
+            }
+            fs::rename(path, backup_path(path, 1))?;
+        }
+        internal::debug("JSON", &format!("Rotated {}", path.display()));
+        Ok(())
+    }
+
     /// Resolves the file path (expands ~).
     fn resolve_path(&self) -> PathBuf {
         let path_str = self.file_path.to_string_lossy();
@@ -97,7 +177,7 @@ impl JsonOutput {
 
     /// Creates a JSON entry from a log record.
     fn create_entry(&self, record: &LogRecord) -> JsonEntry {
-        let now = Local::now();
+        let now = record.time().with_timezone(&Local);
         let clean_msg = style::strip_tags(&record.message);
         let app = record.app_name.clone().or_else(|| self.app_name.clone());
 
@@ -108,6 +188,8 @@ impl JsonOutput {
             scope: record.scope.clone(),
             msg: clean_msg,
             app,
+            host: hostname(),
+            fields: record.values.fields.iter().cloned().collect(),
             label: record.label_override.clone(),
         }
     }
@@ -146,10 +228,16 @@ impl Output for JsonOutput {
         let json = serde_json::to_string(&entry)
             .map_err(|e| crate::Error::Format(format!("JSON serialization failed: {e}")))?;
 
+        let line = format!("{json}{}", self.line_ending.as_str());
+        let _guard = self
+            .write_lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        self.rotate_if_needed(&path, line.len() as u64)?;
+
         // Append to file (JSONL format: one JSON object per line)
         let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
-
-        writeln!(file, "{json}")?;
+        file.write_all(line.as_bytes())?;
 
         Ok(())
     }
@@ -157,4 +245,23 @@ impl Output for JsonOutput {
     fn flush(&self) -> Result<(), crate::Error> {
         Ok(())
     }
+
+    fn name(&self) -> &'static str {
+        "json"
+    }
+}
+
+/// Returns `<path>.<index>`.
+fn backup_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{index}"));
+    PathBuf::from(name)
+}
+
+impl Drop for JsonOutput {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            internal::error("JSON", &format!("Flush on drop failed: {e}"));
+        }
+    }
 }
@@ -0,0 +1,181 @@
+//! logfmt output (`key=value` pairs, one record per line).
+
+use super::{LogRecord, Output};
+use crate::fmt::style;
+use crate::internal;
+
+use chrono::Local;
+use std::borrow::Cow;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// logfmt output configuration.
+///
+/// Writes lines like `ts=... level=info scope=NET msg="..." k=v` to stdout,
+/// or appends them to a file when a path is set. Structured fields from the
+/// record follow the fixed keys in insertion order.
+///
+/// The file is opened on the first write and kept open; clones share it.
+#[derive(Debug, Clone, Default)]
+pub struct LogfmtOutput {
+    /// Path to append to; stdout when `None`.
+    file_path: Option<PathBuf>,
+    /// Application name for records without one.
+    app_name: Option<String>,
+    /// Open writer for `file_path`, created on first write.
+    writer: Arc<Mutex<Option<BufWriter<File>>>>,
+}
+
+impl LogfmtOutput {
+    /// Creates a new logfmt output writing to stdout.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends to a file instead of writing to stdout.
+    ///
+    /// A leading `~` expands to the home directory.
+    #[must_use]
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        self.file_path = Some(PathBuf::from(
+            shellexpand::tilde(&path.to_string_lossy()).as_ref(),
+        ));
+        self.writer = Arc::default();
+        self
+    }
+
+    /// Sets the application name.
+    #[must_use]
+    pub fn app_name(mut self, name: impl Into<String>) -> Self {
+        self.app_name = Some(name.into());
+        self
+    }
+
+    /// Renders a log record as a logfmt line (without newline).
+    #[must_use]
+    pub fn render(&self, record: &LogRecord) -> String {
+        let app = record.app_name.as_ref().or(self.app_name.as_ref());
+        let msg = style::strip_tags(&record.message);
+        let ts = record.time().with_timezone(&Local).to_rfc3339();
+
+        let mut line = String::new();
+        push_pair(&mut line, "ts", &ts);
+        push_pair(&mut line, "level", record.level.as_str());
+        push_pair(&mut line, "scope", &record.scope);
+        push_pair(&mut line, "msg", &msg);
+        if let Some(app) = app {
+            push_pair(&mut line, "app", app);
+        }
+        if let Some(label) = &record.label_override {
+            push_pair(&mut line, "label", label);
+        }
+        for (key, value) in &record.values.fields {
+            push_pair(&mut line, key, value);
+        }
+        line
+    }
+}
+
+/// Appends ` key=value`, quoting the value when needed.
+fn push_pair(line: &mut String, key: &str, value: &str) {
+    if !line.is_empty() {
+        line.push(' ');
+    }
+    line.push_str(&sanitize_key(key));
+    line.push('=');
+    line.push_str(&quote(value));
+}
+
+/// Replaces characters a logfmt key can't hold (spaces, `=`, quotes,
+/// control characters) with `_`; an empty key becomes `_`.
+fn sanitize_key(key: &str) -> Cow<'_, str> {
+    let invalid = |c: char| c.is_whitespace() || c == '=' || c == '"' || c.is_control();
+    if key.is_empty() {
+        Cow::Borrowed("_")
+    } else if key.contains(invalid) {
+        Cow::Owned(key.replace(invalid, "_"))
+    } else {
+        Cow::Borrowed(key)
+    }
+}
+
+/// Quotes a value containing spaces, `=`, quotes or control characters.
+fn quote(value: &str) -> String {
+    let needs_quotes = value.is_empty()
+        || value
+            .chars()
+            .any(|c| c == ' ' || c == '=' || c == '"' || c == '\\' || c.is_control());
+    if !needs_quotes {
+        return value.to_string();
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+impl Output for LogfmtOutput {
+    fn write(&self, record: &LogRecord) -> Result<(), crate::Error> {
+        // Raw lines aren't records; they have no keys to emit
+        if record.raw {
+            return Ok(());
+        }
+
+        let line = self.render(record);
+
+        let Some(path) = &self.file_path else {
+            writeln!(io::stdout(), "{line}")?;
+            return Ok(());
+        };
+
+        let mut writer = self.writer.lock().unwrap();
+        if writer.is_none() {
+            if let Some(parent) = path.parent()
+                && !parent.as_os_str().is_empty()
+                && !parent.exists()
+            {
+                fs::create_dir_all(parent)?;
+                internal::debug(
+                    "LOGFMT",
+                    &format!("Created directory: {}", parent.display()),
+                );
+            }
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+            *writer = Some(BufWriter::new(file));
+        }
+
+        let file = writer.as_mut().expect("writer opened above");
+        writeln!(file, "{line}")?;
+        file.flush()?;
+        drop(writer);
+        Ok(())
+    }
+
+    fn flush(&self) -> Result<(), crate::Error> {
+        if self.file_path.is_none() {
+            io::stdout().flush()?;
+        } else if let Some(writer) = self.writer.lock().unwrap().as_mut() {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "logfmt"
+    }
+}
@@ -1,14 +1,22 @@
 //! Output backends for log messages.
 
+mod async_output;
+mod dedup;
 mod file;
 mod json;
+mod logfmt;
+mod null;
 mod terminal;
 
 #[cfg(feature = "rserver")]
 mod remote;
 
+pub use async_output::{AsyncOutput, OverflowPolicy};
+pub use dedup::DedupOutput;
 pub use file::FileOutput;
 pub use json::JsonOutput;
+pub use logfmt::LogfmtOutput;
+pub use null::NullOutput;
 pub use terminal::TerminalOutput;
 
 #[cfg(feature = "rserver")]
@@ -16,6 +24,57 @@ pub use remote::RemoteOutput;
 
 use crate::fmt::{FormatValues, TagConfig};
 use crate::level::Level;
+use chrono::{DateTime, Utc};
+
+/// Line terminator appended by file-based outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// `\n`, the Unix convention.
+    #[default]
+    Lf,
+    /// `\r\n`, for Windows consumers.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the terminator.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+/// How far [`FileOutput`] pushes lines to disk when it flushes.
+///
+/// Flushing hands lines to the OS; without a sync they may sit in the page
+/// cache and be lost on power failure or kernel crash. Each sync blocks until
+/// the disk confirms, often milliseconds, so pair `Data` or `Full` with
+/// [`FileOutput::buffer_lines`] when throughput matters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SyncMode {
+    /// Leave lines to the OS page cache.
+    #[default]
+    None,
+    /// `fdatasync`: file contents plus the metadata needed to read them back.
+    Data,
+    /// `fsync`: contents and all metadata, such as the modification time.
+    Full,
+}
+
+impl SyncMode {
+    /// Syncs `file` as selected; returns whether a sync call was made.
+    pub(crate) fn apply(self, file: &std::fs::File) -> std::io::Result<bool> {
+        match self {
+            Self::None => return Ok(false),
+            Self::Data => file.sync_data()?,
+            Self::Full => file.sync_all()?,
+        }
+        Ok(true)
+    }
+}
 
 /// A log record ready for output.
 #[derive(Debug, Clone)]
@@ -30,9 +89,20 @@ pub struct LogRecord {
     pub app_name: Option<String>,
     /// If true, output raw message without formatting (no tag, icon, scope).
     pub raw: bool,
+    /// When the event happened; outputs use the current time if None.
+    ///
+    /// [`Logger`](crate::Logger) sets it once when it creates the record, so
+    /// every output renders the same time for the same event.
+    pub timestamp: Option<DateTime<Utc>>,
 }
 
 impl LogRecord {
+    /// Returns the record's timestamp, or the current time if it has none.
+    #[must_use]
+    pub fn time(&self) -> DateTime<Utc> {
+        self.timestamp.unwrap_or_else(Utc::now)
+    }
+
     /// Returns the formatted tag string, using `label_override` if set.
     #[must_use]
     pub fn format_tag(&self, tag_config: &TagConfig) -> String {
@@ -41,6 +111,30 @@ impl LogRecord {
             |label| tag_config.format_with_label(self.level, label),
         )
     }
+
+    /// Returns the message with structured fields appended as `key=value`.
+    #[must_use]
+    pub fn message_with_fields(&self) -> String {
+        let mut msg = self.message.clone();
+        for (key, value) in &self.values.fields {
+            msg.push(' ');
+            msg.push_str(key);
+            msg.push('=');
+            msg.push_str(value);
+        }
+        msg
+    }
+}
+
+/// A failed [`Output::write`], passed to the logger's error callback.
+#[derive(Debug)]
+pub struct OutputError<'a> {
+    /// Position of the output in the order it was added to the builder.
+    pub output_index: usize,
+    /// The error returned by the output.
+    pub error: &'a crate::Error,
+    /// The record that could not be written.
+    pub record: &'a LogRecord,
 }
 
 /// Trait for log output backends.
@@ -56,4 +150,13 @@ pub trait Output: Send + Sync {
     /// # Errors
     /// Returns an error if flushing fails.
     fn flush(&self) -> Result<(), crate::Error>;
+
+    /// Returns a short name for the kind of output, such as `"file"`.
+    ///
+    /// Shown by [`Logger::output_names`](crate::Logger::output_names).
+    /// Wrappers report the name of the output they wrap.
+    #[allow(clippy::unnecessary_literal_bound)] // overrides may borrow from self
+    fn name(&self) -> &str {
+        "custom"
+    }
 }
@@ -0,0 +1,47 @@
+//! Null output: accepts every record and writes nothing.
+
+use crate::output::{LogRecord, Output};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Output that discards every record.
+///
+/// Useful as a benchmark baseline for the formatting pipeline and as a
+/// test double for a logger that must accept calls without writing. Counts
+/// the records it was given; keep a handle through
+/// [`LoggerBuilder::shared_output`](crate::logger::LoggerBuilder::shared_output)
+/// to read the count.
+#[derive(Debug, Default)]
+pub struct NullOutput {
+    writes: AtomicU64,
+}
+
+impl NullOutput {
+    /// Creates a null output.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            writes: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns how many records were written.
+    #[must_use]
+    pub fn writes(&self) -> u64 {
+        self.writes.load(Ordering::Relaxed)
+    }
+}
+
+impl Output for NullOutput {
+    fn write(&self, _record: &LogRecord) -> Result<(), crate::Error> {
+        self.writes.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn flush(&self) -> Result<(), crate::Error> {
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "null"
+    }
+}
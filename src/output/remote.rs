@@ -154,6 +154,10 @@ impl Output for RemoteOutput {
         // Fire-and-forget: no flush guarantee across the network.
         Ok(())
     }
+
+    fn name(&self) -> &'static str {
+        "remote"
+    }
 }
 
 // ── Tests ─────────────────────────────────────────────────────────────────────
@@ -177,6 +181,7 @@ mod tests {
             label_override: None,
             app_name: None,
             raw: false,
+            timestamp: None,
         }
     }
 
@@ -189,7 +194,10 @@ mod tests {
 
         thread::spawn(move || {
             let (stream, _) = listener.accept().unwrap();
-            for line in std::io::BufReader::new(stream).lines().flatten() {
+            for line in std::io::BufReader::new(stream)
+                .lines()
+                .map_while(Result::ok)
+            {
                 recv_clone.lock().unwrap().push(line);
             }
         });
@@ -203,7 +211,7 @@ mod tests {
 
         thread::sleep(Duration::from_millis(200));
 
-        let lines = received.lock().unwrap();
+        let lines = received.lock().unwrap().clone();
         assert_eq!(lines.len(), 1);
         let v: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
         assert_eq!(v["level"], "info");
@@ -220,7 +228,10 @@ mod tests {
 
         thread::spawn(move || {
             let (stream, _) = listener.accept().unwrap();
-            for line in std::io::BufReader::new(stream).lines().flatten() {
+            for line in std::io::BufReader::new(stream)
+                .lines()
+                .map_while(Result::ok)
+            {
                 if let Ok(v) = serde_json::from_str::<serde_json::Value>(&line) {
                     recv_clone.lock().unwrap().push(v);
                 }
@@ -249,9 +260,11 @@ mod tests {
         // Nothing listening on this port.
         let output = RemoteOutput::tcp("127.0.0.1:19999");
         // Must not panic or block.
-        assert!(output
-            .write(&make_record(Level::Warn, "X", "dropped"))
-            .is_ok());
+        assert!(
+            output
+                .write(&make_record(Level::Warn, "X", "dropped"))
+                .is_ok()
+        );
     }
 
     #[test]
@@ -263,7 +276,10 @@ mod tests {
 
         thread::spawn(move || {
             let (stream, _) = listener.accept().unwrap();
-            for line in std::io::BufReader::new(stream).lines().flatten() {
+            for line in std::io::BufReader::new(stream)
+                .lines()
+                .map_while(Result::ok)
+            {
                 recv_clone.lock().unwrap().push(line);
             }
         });
@@ -282,7 +298,7 @@ mod tests {
 
         thread::sleep(Duration::from_millis(200));
 
-        let lines = received.lock().unwrap();
+        let lines = received.lock().unwrap().clone();
         // Only the normal record should arrive
         assert_eq!(lines.len(), 1);
         assert!(lines[0].contains("normal"));
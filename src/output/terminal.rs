@@ -2,17 +2,23 @@
 
 use crate::config::HighlightConfig;
 use crate::fmt::{
-    Color, FormatTemplate, FormatValues, IconSet, ScopeConfig, TagConfig, Transform, highlight,
-    style,
+    Color, FormatTemplate, FormatValues, Highlighter, IconSet, Placeholder, ScopeConfig, TagConfig,
+    Transform, WrapMode, display_width, hostname, style, truncate, wrap,
 };
 use crate::level::Level;
 
 use super::{LogRecord, Output};
+use chrono::Local;
 use std::collections::HashMap;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+use std::sync::OnceLock;
+
+/// Width used when the terminal size can't be determined.
+const FALLBACK_WIDTH: usize = 80;
 
 /// Terminal output configuration.
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)] // independent rendering switches
 pub struct TerminalOutput {
     /// Enable colored output.
     colors_enabled: bool,
@@ -26,12 +32,30 @@ pub struct TerminalOutput {
     message_transform: Transform,
     /// Output structure template.
     template: FormatTemplate,
+    /// Per-level structure templates, overriding `template`.
+    level_templates: HashMap<Level, FormatTemplate>,
     /// Named colors for styling.
     color_map: HashMap<String, Color>,
     /// Colors per level.
     level_colors: HashMap<Level, Color>,
-    /// Auto-highlighting config.
-    highlight_config: HighlightConfig,
+    /// Explicit colors per scope.
+    scope_colors: HashMap<String, Color>,
+    /// Color unlisted scopes by hashing their name.
+    hashed_scope_colors: bool,
+    /// Send every record to stderr instead of splitting by level.
+    stderr_only: bool,
+    /// Indent continuation lines of multi-line messages under `{msg}`.
+    wrap_continuation: bool,
+    /// How long messages are wrapped.
+    wrap: WrapMode,
+    /// Fixed wrap width; detected from the terminal when `None`.
+    wrap_width: Option<usize>,
+    /// Auto-highlighting with keyword regexes compiled once.
+    highlighter: Highlighter,
+    /// Make highlighted URLs and paths clickable (OSC 8).
+    hyperlinks: bool,
+    /// Cut longer messages to this many columns, ending in `…`.
+    max_message_len: Option<usize>,
 }
 
 impl Default for TerminalOutput {
@@ -69,9 +93,18 @@ impl TerminalOutput {
             scope_config: ScopeConfig::default(),
             message_transform: Transform::None,
             template: FormatTemplate::parse("{tag} {scope}  {msg}"),
+            level_templates: HashMap::new(),
             color_map,
             level_colors,
-            highlight_config: HighlightConfig::default(),
+            scope_colors: HashMap::new(),
+            hashed_scope_colors: false,
+            stderr_only: false,
+            wrap_continuation: false,
+            wrap: WrapMode::Off,
+            wrap_width: None,
+            highlighter: Highlighter::default(),
+            hyperlinks: false,
+            max_message_len: None,
         }
     }
 
@@ -114,6 +147,16 @@ impl TerminalOutput {
     #[must_use]
     pub fn template(mut self, template: &str) -> Self {
         self.template = FormatTemplate::parse(template);
+        self.template.warn_unknown("terminal structure");
+        self
+    }
+
+    /// Sets the output template for one level, overriding [`template`](Self::template).
+    #[must_use]
+    pub fn template_for(mut self, level: Level, template: &str) -> Self {
+        let template = FormatTemplate::parse(template);
+        template.warn_unknown(&format!("terminal structure for {level}"));
+        self.level_templates.insert(level, template);
         self
     }
 
@@ -131,15 +174,94 @@ impl TerminalOutput {
         self
     }
 
+    /// Sets the color for a scope.
+    #[must_use]
+    pub fn scope_color(mut self, scope: impl Into<String>, color: Color) -> Self {
+        self.scope_colors.insert(scope.into(), color);
+        self
+    }
+
+    /// Colors scopes without an explicit color by hashing their name.
+    #[must_use]
+    pub const fn hashed_scope_colors(mut self, enabled: bool) -> Self {
+        self.hashed_scope_colors = enabled;
+        self
+    }
+
+    /// Writes all records to stderr (by default only warn/error go there).
+    #[must_use]
+    pub const fn stderr_only(mut self, enabled: bool) -> Self {
+        self.stderr_only = enabled;
+        self
+    }
+
     /// Sets the highlight configuration.
     #[must_use]
     pub fn highlight_config(mut self, config: HighlightConfig) -> Self {
-        self.highlight_config = config;
+        self.highlighter = Highlighter::new(config);
+        self
+    }
+
+    /// Makes highlighted URLs and paths clickable with OSC 8 hyperlinks.
+    ///
+    /// Only applies with colors enabled and the URL/path highlight patterns
+    /// set. [`write`](Output::write) leaves links out when the target stream
+    /// is not a terminal.
+    #[must_use]
+    pub const fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = enabled;
+        self
+    }
+
+    /// Cuts messages longer than `len` columns, ending them with `…`.
+    ///
+    /// Applied to the styled message, so colors and links stay intact.
+    /// Only the terminal is affected; files keep the full text.
+    #[must_use]
+    pub const fn max_message_len(mut self, len: usize) -> Self {
+        self.max_message_len = Some(len);
+        self
+    }
+
+    /// Indents continuation lines of multi-line messages to the `{msg}` column.
+    #[must_use]
+    pub const fn wrap_continuation(mut self, enabled: bool) -> Self {
+        self.wrap_continuation = enabled;
+        self
+    }
+
+    /// Wraps messages to the terminal width, indenting continuation lines.
+    ///
+    /// The width comes from `COLUMNS`, then the tty, then 80 columns.
+    #[must_use]
+    pub const fn wrap(mut self, mode: WrapMode) -> Self {
+        self.wrap = mode;
+        self
+    }
+
+    /// Wraps at a fixed width instead of the detected terminal width.
+    #[must_use]
+    pub const fn wrap_width(mut self, width: usize) -> Self {
+        self.wrap_width = Some(width);
         self
     }
 
-    /// Formats and prints a log record.
-    fn format_record(&self, record: &LogRecord) -> String {
+    /// Resolves the color for a scope: explicit first, then hashed.
+    fn resolve_scope_color(&self, scope: &str) -> Option<Color> {
+        self.scope_colors
+            .get(scope)
+            .copied()
+            .or_else(|| self.hashed_scope_colors.then(|| Color::hashed(scope)))
+    }
+
+    /// Renders a log record to the line that `write` prints (without newline).
+    #[must_use]
+    pub fn render(&self, record: &LogRecord) -> String {
+        self.render_with(record, self.hyperlinks)
+    }
+
+    /// Renders a record, with OSC 8 hyperlinks if `links` is set.
+    fn render_with(&self, record: &LogRecord, links: bool) -> String {
         let level_color = self
             .level_colors
             .get(&record.level)
@@ -148,32 +270,41 @@ impl TerminalOutput {
 
         // Format tag with color (uses label_override if set)
         let tag = record.format_tag(&self.tag_config);
-        let tag = if self.colors_enabled {
+        // A hidden tag stays empty so `{tag?}` can collapse it
+        let tag = if self.colors_enabled && !tag.is_empty() {
             format!("{}{}{}", level_color.fg_ansi(), tag, Color::RESET)
         } else {
             tag
         };
 
-        // Format icon with color
-        let icon = self.icons.get(record.level);
+        // Format icon with color, padded so `{icon} {msg}` stays aligned
+        let icon = self.icons.get_padded(record.level);
         let icon = if self.colors_enabled && !icon.is_empty() {
             format!("{}{}{}", level_color.fg_ansi(), icon, Color::RESET)
         } else {
-            icon.to_string()
+            icon
         };
 
-        // Format scope (padded and dimmed)
+        // Format scope (padded; scope color if any, dimmed otherwise)
         let padded_scope = self.scope_config.format(&record.scope);
-        let scope = if self.colors_enabled {
-            format!("\x1b[2m{padded_scope}\x1b[0m")
+        // Blank scopes stay uncolored so `{scope?}` can collapse them
+        let scope = if self.colors_enabled && !record.scope.is_empty() {
+            self.resolve_scope_color(&record.scope).map_or_else(
+                || format!("\x1b[2m{padded_scope}\x1b[0m"),
+                |color| format!("{}{padded_scope}{}", color.fg_ansi(), Color::RESET),
+            )
         } else {
             padded_scope
         };
 
         // Apply message transform and auto-highlighting
-        let transformed_msg = self.message_transform.apply(&record.message);
-        let msg_with_highlights = if self.colors_enabled {
-            highlight::inject_tags(&transformed_msg, &self.highlight_config)
+        let transformed_msg = self.message_transform.apply(&record.message_with_fields());
+        let msg_with_highlights = if self.colors_enabled && links {
+            self.highlighter
+                .inject_tags_with_links(&transformed_msg, record.level, &record.scope)
+        } else if self.colors_enabled {
+            self.highlighter
+                .inject_tags_for(&transformed_msg, record.level, &record.scope)
         } else {
             transformed_msg
         };
@@ -183,32 +314,148 @@ impl TerminalOutput {
         } else {
             style::render_plain(&msg_segments)
         };
+        let msg = match self.max_message_len {
+            Some(max) => truncate(&msg, max),
+            None => msg,
+        };
 
-        // Build values and render template
-        let values = FormatValues::new()
+        let template = self
+            .level_templates
+            .get(&record.level)
+            .unwrap_or(&self.template);
+
+        // Build values and render template; per-record lookups only if used
+        let mut values = FormatValues::new()
             .tag(&tag)
             .icon(&icon)
             .scope(&scope)
-            .msg(&msg)
             .level(record.level.as_str())
-            .app(record.app_name.as_deref().unwrap_or("hyprslog"));
+            .app(record.app_name.as_deref().unwrap_or("hyprslog"))
+            .location(&record.values.location);
+        if template.uses(Placeholder::Hostname) {
+            values = values.hostname(hostname());
+        }
+        if template.uses(Placeholder::Pid) {
+            values = values.pid(std::process::id().to_string());
+        }
+        if template.uses(Placeholder::Timestamp) {
+            values = values.timestamp(
+                record
+                    .time()
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+            );
+        }
 
-        self.template.render(&values)
+        let msg = self.layout_message(msg, template, &values);
+        template.render(&values.msg(msg))
+    }
+
+    /// Wraps the message and indents continuation lines under the `{msg}` column.
+    fn layout_message(
+        &self,
+        msg: String,
+        template: &FormatTemplate,
+        values: &FormatValues,
+    ) -> String {
+        let wrapping = self.wrap != WrapMode::Off;
+        let multiline = self.wrap_continuation && msg.contains('\n');
+        if !wrapping && !multiline {
+            return msg;
+        }
+
+        let column = msg_column(template, values);
+        let lines: Vec<String> = if wrapping {
+            let width = self.wrap_width.unwrap_or_else(terminal_width);
+            // No room left after the prefix: leave the message alone
+            if column >= width {
+                return msg;
+            }
+            msg.split('\n')
+                .flat_map(|line| wrap(line, width - column, self.wrap))
+                .collect()
+        } else {
+            msg.split('\n').map(ToString::to_string).collect()
+        };
+
+        lines.join(&format!("\n{}", " ".repeat(column)))
     }
 }
 
+/// Returns the terminal width: `COLUMNS`, then the tty size, then 80.
+///
+/// Looked up once and cached for the life of the process.
+fn terminal_width() -> usize {
+    static WIDTH: OnceLock<usize> = OnceLock::new();
+
+    *WIDTH.get_or_init(|| {
+        std::env::var("COLUMNS")
+            .ok()
+            .and_then(|c| c.trim().parse().ok())
+            .filter(|&c: &usize| c > 0)
+            .or_else(tty_width)
+            .unwrap_or(FALLBACK_WIDTH)
+    })
+}
+
+/// Asks the kernel for the width of the tty on stdout or stderr.
+#[cfg(unix)]
+fn tty_width() -> Option<usize> {
+    let width = |size: rustix::termios::Winsize| (size.ws_col > 0).then_some(size.ws_col);
+    let stdout = io::stdout();
+    let stderr = io::stderr();
+    let cols = if stdout.is_terminal() {
+        rustix::termios::tcgetwinsize(&stdout).ok().and_then(width)
+    } else if stderr.is_terminal() {
+        rustix::termios::tcgetwinsize(&stderr).ok().and_then(width)
+    } else {
+        None
+    };
+    cols.map(usize::from)
+}
+
+/// Tty sizes are only queried on Unix.
+#[cfg(not(unix))]
+const fn tty_width() -> Option<usize> {
+    None
+}
+
+/// Returns the display column at which `{msg}` starts in the rendered line.
+fn msg_column(template: &FormatTemplate, values: &FormatValues) -> usize {
+    const MARKER: &str = "\u{0}";
+
+    let rendered = template.render(&values.clone().msg(MARKER));
+    rendered.find(MARKER).map_or(0, |end| {
+        let prefix = &rendered[..end];
+        display_width(prefix.rsplit('\n').next().unwrap_or(prefix))
+    })
+}
+
 impl Output for TerminalOutput {
     fn write(&self, record: &LogRecord) -> Result<(), crate::Error> {
         // Raw mode: just output the message without formatting
         if record.raw {
-            writeln!(io::stdout(), "{}", record.message)?;
+            if self.stderr_only {
+                writeln!(io::stderr(), "{}", record.message)?;
+            } else {
+                writeln!(io::stdout(), "{}", record.message)?;
+            }
             return Ok(());
         }
 
-        let formatted = self.format_record(record);
-
         // Warn and Error go to stderr, others to stdout
-        if record.level >= Level::Warn {
+        let to_stderr = self.stderr_only || record.level >= Level::Warn;
+        // Escapes a pager or file can't follow would show up as garbage
+        let links = self.hyperlinks
+            && if to_stderr {
+                io::stderr().is_terminal()
+            } else {
+                io::stdout().is_terminal()
+            };
+        let formatted = self.render_with(record, links);
+
+        if to_stderr {
             writeln!(io::stderr(), "{formatted}")?;
         } else {
             writeln!(io::stdout(), "{formatted}")?;
@@ -222,4 +469,8 @@ impl Output for TerminalOutput {
         io::stderr().flush()?;
         Ok(())
     }
+
+    fn name(&self) -> &'static str {
+        "terminal"
+    }
 }
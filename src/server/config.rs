@@ -87,11 +87,7 @@ impl ServerConfig {
     pub fn config_path() -> PathBuf {
         directories::BaseDirs::new().map_or_else(
             || PathBuf::from("log-server.toml"),
-            |dirs| {
-                dirs.config_dir()
-                    .join("hypr/hyprs")
-                    .join("log-server.toml")
-            },
+            |dirs| dirs.config_dir().join("hypr/hyprs").join("log-server.toml"),
         )
     }
 
@@ -17,18 +17,13 @@ where
     let mut lines = BufReader::new(reader).lines();
     loop {
         match lines.next_line().await {
-            Ok(Some(line)) if !line.trim().is_empty() => {
-                match WireRecord::from_line(&line) {
-                    Ok(rec) => dispatch(&rec, &logger),
-                    Err(e) => {
-                        internal::warn(
-                            "RSERVER",
-                            &format!("malformed JSON ({e}): {line}"),
-                        );
-                    }
+            Ok(Some(line)) if !line.trim().is_empty() => match WireRecord::from_line(&line) {
+                Ok(rec) => dispatch(&rec, &logger),
+                Err(e) => {
+                    internal::warn("RSERVER", &format!("malformed JSON ({e}): {line}"));
                 }
-            }
-            Ok(Some(_)) => {} // blank line — skip
+            },
+            Ok(Some(_)) => {}  // blank line — skip
             Ok(None) => break, // EOF
             Err(e) => {
                 internal::trace("RSERVER", &format!("read error: {e}"));
@@ -28,10 +28,13 @@ pub fn read_pid(config: &ServerConfig) -> Result<Option<u32>, crate::Error> {
         return Ok(None);
     }
     let raw = fs::read_to_string(path)?;
-    raw.trim()
-        .parse::<u32>()
-        .map(Some)
-        .map_err(|_| crate::Error::Format(format!("invalid PID in {}: {:?}", config.pid_file, raw.trim())))
+    raw.trim().parse::<u32>().map(Some).map_err(|_| {
+        crate::Error::Format(format!(
+            "invalid PID in {}: {:?}",
+            config.pid_file,
+            raw.trim()
+        ))
+    })
 }
 
 /// Returns `true` if a process with the given PID is currently running.
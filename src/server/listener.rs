@@ -24,7 +24,10 @@ pub async fn run_listeners(config: &ServerConfig, logger: Arc<Logger>) -> Result
     let tcp = TcpListener::bind(config.tcp_addr())
         .await
         .map_err(crate::Error::Io)?;
-    internal::info("RSERVER", &format!("listening on tcp:{}", config.tcp_addr()));
+    internal::info(
+        "RSERVER",
+        &format!("listening on tcp:{}", config.tcp_addr()),
+    );
 
     let unix_log = Arc::clone(&logger);
     let tcp_log = Arc::clone(&logger);
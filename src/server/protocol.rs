@@ -72,7 +72,10 @@ mod tests {
     fn roundtrip_without_app() {
         let rec = WireRecord::from_parts(Level::Warn, "DB", None, "Slow query");
         let line = rec.to_line().unwrap();
-        assert!(!line.contains("\"app\""), "app field must be absent when None");
+        assert!(
+            !line.contains("\"app\""),
+            "app field must be absent when None"
+        );
         let parsed = WireRecord::from_line(&line).unwrap();
         assert_eq!(parsed, rec);
     }
@@ -0,0 +1,69 @@
+//! Tests for the background-thread output wrapper.
+
+mod common;
+
+use common::Capture;
+use hyprs_log::output::{AsyncOutput, LogRecord, Output, OverflowPolicy};
+use hyprs_log::{Error, Logger};
+use std::sync::{Arc, Mutex};
+
+/// Capture whose writes wait while the gate is held.
+#[derive(Clone, Default)]
+struct Gated {
+    capture: Capture,
+    gate: Arc<Mutex<()>>,
+}
+
+impl Output for Gated {
+    fn write(&self, record: &LogRecord) -> Result<(), Error> {
+        let _open = self.gate.lock().unwrap();
+        self.capture.write(record)
+    }
+
+    fn flush(&self) -> Result<(), Error> {
+        self.capture.flush()
+    }
+}
+
+#[test]
+fn async_output_delivers_records_in_order() {
+    let capture = Capture::default();
+    let logger = Logger::builder().async_output(capture.clone()).build();
+
+    for i in 0..200 {
+        logger.info("ASYNC", &format!("msg {i}"));
+    }
+    logger.flush().unwrap();
+
+    let expected: Vec<String> = (0..200).map(|i| format!("msg {i}")).collect();
+    assert_eq!(capture.messages(), expected);
+}
+
+#[test]
+fn async_output_drop_policy_discards_when_full() {
+    let gated = Gated::default();
+    let output = AsyncOutput::with_options(gated.clone(), 2, OverflowPolicy::Drop);
+    let logger = Logger::builder().output(output).build();
+
+    {
+        // Hold the gate so the worker stalls and the queue fills up
+        let _closed = gated.gate.lock().unwrap();
+        for i in 0..50 {
+            logger.info("ASYNC", &format!("msg {i}"));
+        }
+    }
+    logger.flush().unwrap();
+
+    let received = gated.capture.writes();
+    assert!((1..50).contains(&received), "received {received}");
+}
+
+#[test]
+fn async_output_drains_on_drop() {
+    let capture = Capture::default();
+    {
+        let logger = Logger::builder().async_output(capture.clone()).build();
+        logger.info("ASYNC", "last");
+    }
+    assert_eq!(capture.messages(), vec!["last".to_string()]);
+}
@@ -1,6 +1,12 @@
 //! Tests for cleanup module.
 
-use hyprs_log::{CleanupOptions, cleanup, format_size, parse_size, stats};
+mod common;
+
+use common::Capture;
+use hyprs_log::{
+    CleanupOptions, SizeUnit, cleanup, cleanup_with_progress, format_size, format_size_with,
+    parse_size, stats, stats_with_extensions,
+};
 use std::fs;
 use tempfile::tempdir;
 
@@ -23,6 +29,26 @@ fn format_size_bytes() {
     assert_eq!(format_size(1024 * 1024 * 1024), "1.00 GB");
 }
 
+#[test]
+fn format_size_with_forced_unit() {
+    assert_eq!(
+        format_size_with(512 * 1024, Some(SizeUnit::MB), 2),
+        "0.50 MB"
+    );
+    assert_eq!(
+        format_size_with(3 * 1024 * 1024, Some(SizeUnit::MB), 1),
+        "3.0 MB"
+    );
+    assert_eq!(format_size_with(2048, Some(SizeUnit::B), 2), "2048 B");
+}
+
+#[test]
+fn format_size_with_zero_precision() {
+    assert_eq!(format_size_with(1536, Some(SizeUnit::KB), 0), "2 KB");
+    assert_eq!(format_size_with(10 * 1024, None, 0), "10 KB");
+    assert_eq!(format_size_with(100, None, 0), "100 B");
+}
+
 #[test]
 fn cleanup_empty_base() {
     let dir = tempdir().unwrap();
@@ -53,6 +79,47 @@ fn stats_with_files() {
     assert!(stats.total_size > 0);
 }
 
+#[test]
+fn stats_counts_compressed_files() {
+    let dir = tempdir().unwrap();
+
+    fs::write(dir.path().join("current.log"), "0123456789").unwrap();
+    fs::write(dir.path().join("old.log.gz"), "01234").unwrap();
+    fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+    let stats = stats(dir.path(), None).unwrap();
+    assert_eq!(stats.total_files, 2);
+    assert_eq!(stats.total_size, 15);
+
+    let logs_only = stats_with_extensions(dir.path(), None, &["log"]).unwrap();
+    assert_eq!(logs_only.total_files, 1);
+    assert_eq!(logs_only.total_size, 10);
+}
+
+#[test]
+fn future_mtime_counts_as_age_zero() {
+    use std::time::{Duration, SystemTime};
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("skewed.log");
+    fs::write(&path, "from the future").unwrap();
+    let future = SystemTime::now() + Duration::from_hours(10 * 24);
+    fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(future)
+        .unwrap();
+
+    let stats = stats(dir.path(), None).unwrap();
+    assert_eq!(stats.total_files, 1);
+    assert_eq!(stats.files[0].age_days, 0);
+
+    let options = CleanupOptions::new().max_age_days(1).dry_run(true);
+    let result = cleanup(dir.path(), &options).unwrap();
+    assert!(result.would_delete.is_empty());
+}
+
 #[test]
 fn cleanup_dry_run() {
     let dir = tempdir().unwrap();
@@ -88,6 +155,50 @@ fn cleanup_delete_all() {
     assert!(!log.exists());
 }
 
+#[test]
+fn cleanup_reports_increasing_progress() {
+    let dir = tempdir().unwrap();
+    for i in 0..5 {
+        fs::write(dir.path().join(format!("app{i}.log")), "line").unwrap();
+    }
+
+    let mut calls = Vec::new();
+    let options = CleanupOptions::new().delete_all(true);
+    let result = cleanup_with_progress(dir.path(), &options, &mut |processed, total| {
+        calls.push((processed, total));
+    })
+    .unwrap();
+
+    assert_eq!(result.deleted.len(), 5);
+    assert_eq!(calls, (1..=5).map(|n| (n, 5)).collect::<Vec<_>>());
+}
+
+#[test]
+fn cleanup_stops_at_max_depth() {
+    let dir = tempdir().unwrap();
+    let mut nested = dir.path().to_path_buf();
+    fs::write(nested.join("level0.log"), "x").unwrap();
+    for level in 1..=4 {
+        nested.push(format!("d{level}"));
+        fs::create_dir(&nested).unwrap();
+        fs::write(nested.join(format!("level{level}.log")), "x").unwrap();
+    }
+
+    let options = CleanupOptions::new()
+        .delete_all(true)
+        .dry_run(true)
+        .max_depth(2);
+    let result = cleanup(dir.path(), &options).unwrap();
+
+    let mut found: Vec<&str> = result
+        .would_delete
+        .iter()
+        .map(|p| p.rsplit('/').next().unwrap())
+        .collect();
+    found.sort_unstable();
+    assert_eq!(found, vec!["level0.log", "level1.log", "level2.log"]);
+}
+
 #[test]
 fn cleanup_options_builder() {
     let options = CleanupOptions::new()
@@ -101,3 +212,129 @@ fn cleanup_options_builder() {
     assert_eq!(options.app_filter, Some("myapp".to_string()));
     assert!(options.dry_run);
 }
+
+#[test]
+fn stats_buckets_bytes_per_day() {
+    use chrono::NaiveDate;
+    use hyprs_log::{LogFileInfo, LogStats};
+
+    let day1 = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let day2 = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+    let file = |path: &str, size, date| LogFileInfo {
+        path: path.to_string(),
+        size,
+        age_days: 0,
+        modified_date: date,
+    };
+    let stats = LogStats {
+        files: vec![
+            file("a.log", 100, Some(day1)),
+            file("b.log", 50, Some(day2)),
+            file("c.log", 25, Some(day1)),
+            file("d.log", 999, None),
+        ],
+        ..LogStats::default()
+    };
+
+    let per_day = stats.bytes_per_day();
+    assert_eq!(per_day.len(), 2);
+    assert_eq!(per_day[&day1], 125);
+    assert_eq!(per_day[&day2], 50);
+}
+
+#[test]
+fn projection_combines_deletions_and_compressions() {
+    use hyprs_log::CleanupResult;
+
+    let result = CleanupResult {
+        would_delete: vec!["a.log".to_string(), "b.log".to_string()],
+        would_free: 600,
+        would_compress: vec!["c.log".to_string()],
+        would_compress_save: 150,
+        ..CleanupResult::default()
+    };
+
+    assert_eq!(result.projection(1000), (1000, 250, 750));
+    // Never projects below zero
+    assert_eq!(result.projection(500), (500, 0, 750));
+}
+
+#[test]
+fn cleanup_log_colors_savings_and_failures() {
+    use hyprs_log::{CleanupResult, Color, Logger, TerminalOutput};
+
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+    let result = CleanupResult {
+        deleted: vec!["a.log".to_string()],
+        freed: 2048,
+        failed: vec![("b.log".to_string(), "permission denied".to_string())],
+        ..CleanupResult::default()
+    };
+    result.log(&logger, false);
+
+    let records = capture.records();
+    let render = |colors: bool| {
+        let terminal = TerminalOutput::new().colors(colors).template("{msg}");
+        records
+            .iter()
+            .filter(|r| !r.raw)
+            .map(|r| terminal.render(r))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let colored = render(true);
+    assert!(colored.contains(&format!("{}2.00 KB", Color::green().fg_ansi())));
+    assert!(colored.contains(&format!(
+        "{}Failed to process 1 file(s)",
+        Color::red().fg_ansi()
+    )));
+
+    let plain = render(false);
+    assert!(!plain.contains('\x1b'));
+    assert!(plain.contains("Deleted 1 file(s), freed 2.00 KB"));
+    assert!(plain.contains("Failed to process 1 file(s)"));
+}
+
+#[test]
+fn stats_csv_has_header_and_quotes_commas() {
+    use chrono::NaiveDate;
+    use hyprs_log::{LogFileInfo, LogStats};
+
+    let stats = LogStats {
+        files: vec![
+            LogFileInfo {
+                path: "/logs/app.log".to_string(),
+                size: 120,
+                age_days: 3,
+                modified_date: NaiveDate::from_ymd_opt(2024, 3, 1),
+            },
+            LogFileInfo {
+                path: "/logs/a,b.log".to_string(),
+                size: 7,
+                age_days: 0,
+                modified_date: None,
+            },
+        ],
+        ..LogStats::default()
+    };
+
+    let csv = stats.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "path,size,age_days,modified_date");
+    assert_eq!(lines[1], "/logs/app.log,120,3,2024-03-01");
+    assert_eq!(lines[2], "\"/logs/a,b.log\",7,0,");
+}
+
+#[test]
+fn stats_json_reports_total_files() {
+    let dir = tempdir().unwrap();
+    fs::write(dir.path().join("a.log"), "one").unwrap();
+    fs::write(dir.path().join("b.log"), "two").unwrap();
+
+    let stats = stats(dir.path(), None).unwrap();
+    let json: serde_json::Value = serde_json::to_value(&stats).unwrap();
+    assert_eq!(json["total_files"], 2);
+    assert_eq!(json["files"].as_array().unwrap().len(), 2);
+}
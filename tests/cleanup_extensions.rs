@@ -77,6 +77,28 @@ fn cleanup_compress_creates_gz_files() {
     assert!(gz.exists());
 }
 
+#[test]
+fn cleanup_compress_twice_compresses_nothing_new() {
+    let dir = tempdir().unwrap();
+    let log = dir.path().join("test.log");
+    fs::write(&log, "first generation").unwrap();
+
+    let options = CleanupOptions::new().delete_all(true).compress(true);
+    let first = cleanup(dir.path(), &options).unwrap();
+    assert_eq!(first.compressed.len(), 1);
+    let gz = dir.path().join("test.log.gz");
+    let archived = fs::read(&gz).unwrap();
+
+    // Same name again next to the existing archive
+    fs::write(&log, "second generation").unwrap();
+    let second = cleanup(dir.path(), &options).unwrap();
+
+    assert!(second.compressed.is_empty());
+    assert_eq!(second.skipped, vec![log.display().to_string()]);
+    assert!(log.exists());
+    assert_eq!(fs::read(&gz).unwrap(), archived);
+}
+
 #[test]
 fn cleanup_compress_dry_run() {
     let dir = tempdir().unwrap();
@@ -118,3 +140,82 @@ fn cleanup_options_builder_new_fields() {
     assert_eq!(options.keep_last, Some(5));
     assert!(options.compress);
 }
+
+#[test]
+fn cleanup_archive_moves_to_mirrored_path() {
+    let dir = tempdir().unwrap();
+    let archive = tempdir().unwrap();
+
+    let app_dir = dir.path().join("myapp").join("2024");
+    fs::create_dir_all(&app_dir).unwrap();
+    let log = app_dir.join("old.log");
+    fs::write(&log, "archived content").unwrap();
+
+    let options = CleanupOptions::new()
+        .delete_all(true)
+        .archive_to(archive.path());
+    let result = cleanup(dir.path(), &options).unwrap();
+
+    assert_eq!(result.moved.len(), 1);
+    assert_eq!(result.moved_bytes(), 16);
+    assert!(!log.exists());
+
+    let dest = archive.path().join("myapp").join("2024").join("old.log");
+    assert_eq!(fs::read_to_string(dest).unwrap(), "archived content");
+}
+
+#[test]
+fn cleanup_archive_dry_run_keeps_files() {
+    let dir = tempdir().unwrap();
+    let archive = tempdir().unwrap();
+
+    let log = dir.path().join("test.log");
+    fs::write(&log, "content").unwrap();
+
+    let options = CleanupOptions::new()
+        .delete_all(true)
+        .archive_to(archive.path())
+        .dry_run(true);
+    let result = cleanup(dir.path(), &options).unwrap();
+
+    assert_eq!(result.would_move.len(), 1);
+    assert_eq!(
+        result.would_move[0].1,
+        archive.path().join("test.log").display().to_string()
+    );
+    assert!(log.exists());
+    assert!(!archive.path().join("test.log").exists());
+}
+
+#[test]
+fn cleanup_archive_rejects_compress() {
+    let dir = tempdir().unwrap();
+    let options = CleanupOptions::new()
+        .compress(true)
+        .archive_to(dir.path().join("archive"));
+    assert!(cleanup(dir.path(), &options).is_err());
+}
+
+#[test]
+fn cleanup_keep_last_per_app_protects_each_app() {
+    let dir = tempdir().unwrap();
+
+    for (app, count) in [("chatty", 5), ("quiet", 3)] {
+        let app_dir = dir.path().join("2024").join("01").join(app);
+        fs::create_dir_all(&app_dir).unwrap();
+        for i in 1..=count {
+            fs::write(app_dir.join(format!("log{i}.log")), "content").unwrap();
+        }
+    }
+
+    let options = CleanupOptions::new().delete_all(true).keep_last_per_app(2);
+    let result = cleanup(dir.path(), &options).unwrap();
+
+    assert_eq!(result.deleted.len(), 4);
+    for app in ["chatty", "quiet"] {
+        let remaining = fs::read_dir(dir.path().join("2024").join("01").join(app))
+            .unwrap()
+            .count();
+        assert_eq!(remaining, 2, "{app} keeps its own newest files");
+    }
+}
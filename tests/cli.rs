@@ -31,3 +31,277 @@ fn unknown_command_exits_failure() {
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(stderr.contains("Unknown command"));
 }
+
+#[test]
+fn config_prints_effective_config_with_includes_and_app_overrides() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let conf_dir = tmp.path().join("hypr/hyprs");
+    std::fs::create_dir_all(&conf_dir).unwrap();
+    let extra = conf_dir.join("colors.conf");
+    std::fs::write(&extra, "[colors]\naccent = \"#123456\"\n").unwrap();
+    std::fs::write(
+        conf_dir.join("log.conf"),
+        format!(
+            "source = \"{}\"\n\n[general]\nlevel = \"info\"\n\n[apps.myapp]\nlevel = \"debug\"\n",
+            extra.display()
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .args(["config", "--app", "myapp"])
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .output()
+        .expect("failed to run hyprslog");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: hyprs_log::Config = toml::from_str(&stdout).unwrap();
+    assert_eq!(parsed.colors["accent"], "#123456");
+    assert_eq!(parsed.general.level, "debug");
+}
+
+#[test]
+fn config_diff_prints_app_overrides() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let conf_dir = tmp.path().join("hypr/hyprs");
+    std::fs::create_dir_all(&conf_dir).unwrap();
+    std::fs::write(
+        conf_dir.join("log.conf"),
+        "[general]\nlevel = \"info\"\n\n[apps.myapp]\nlevel = \"debug\"\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .args(["config", "--app", "myapp", "--diff"])
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .output()
+        .expect("failed to run hyprslog");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout, "general.level: \"info\" -> \"debug\"\n");
+}
+
+/// Runs `hyprslog log ...` with file output under a temp dir and returns the
+/// app directories that received log files.
+fn log_app_dirs(args: &[&str]) -> Vec<String> {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let conf_dir = tmp.path().join("hypr/hyprs");
+    let logs = tmp.path().join("logs");
+    std::fs::create_dir_all(&conf_dir).unwrap();
+    std::fs::write(
+        conf_dir.join("log.conf"),
+        format!(
+            "[general]\napp_name = \"cfgapp\"\n\n[terminal]\nenabled = false\n\n\
+             [file]\nenabled = true\nbase_dir = \"{}\"\npath_structure = \"{{app}}\"\n",
+            logs.display()
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .arg("log")
+        .args(args)
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .output()
+        .expect("failed to run hyprslog");
+    assert!(output.status.success(), "{output:?}");
+
+    let mut dirs: Vec<String> = std::fs::read_dir(&logs)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+#[test]
+fn unwritable_log_dir_falls_back_to_stderr() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let conf_dir = tmp.path().join("hypr/hyprs");
+    std::fs::create_dir_all(&conf_dir).unwrap();
+    let blocker = tmp.path().join("not-a-dir");
+    std::fs::write(&blocker, "").unwrap();
+    std::fs::write(
+        conf_dir.join("log.conf"),
+        format!(
+            "[terminal]\nenabled = false\n\n[file]\nenabled = true\nbase_dir = \"{}\"\n",
+            blocker.join("logs").display()
+        ),
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .args(["info", "NET", "still visible"])
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .output()
+        .expect("failed to run hyprslog");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("still visible"), "{stderr}");
+}
+
+#[test]
+fn message_max_len_from_config_truncates_terminal_line() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let conf_dir = tmp.path().join("hypr/hyprs");
+    std::fs::create_dir_all(&conf_dir).unwrap();
+    std::fs::write(
+        conf_dir.join("log.conf"),
+        "[terminal]\ncolors = false\nstructure = \"{msg}\"\n\n[file]\nenabled = false\n\n\
+         [message]\nmax_len = 8\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .args(["info", "NET", "a long message that gets cut"])
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .output()
+        .expect("failed to run hyprslog");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim_end(), "a long …");
+}
+
+#[test]
+fn log_without_app_uses_config_app_name() {
+    assert_eq!(log_app_dirs(&["info", "NET", "up"]), vec!["cfgapp"]);
+}
+
+#[test]
+fn log_with_app_flag() {
+    assert_eq!(
+        log_app_dirs(&["--app", "flagapp", "warn", "NET", "slow"]),
+        vec!["flagapp"]
+    );
+}
+
+#[test]
+fn app_flag_inside_message_is_message_text() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let conf_dir = tmp.path().join("hypr/hyprs");
+    std::fs::create_dir_all(&conf_dir).unwrap();
+    std::fs::write(
+        conf_dir.join("log.conf"),
+        "[general]\napp_name = \"cfgapp\"\n\n[terminal]\ncolors = false\n\
+         structure = \"{app}: {msg}\"\n\n[file]\nenabled = false\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .args(["log", "info", "NET", "restart", "--app", "failed"])
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .output()
+        .expect("failed to run hyprslog");
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim_end(), "cfgapp: restart --app failed");
+}
+
+#[test]
+fn log_with_positional_app_stays_compatible() {
+    assert_eq!(
+        log_app_dirs(&["posapp", "error", "NET", "down", "hard"]),
+        vec!["posapp"]
+    );
+}
+
+#[test]
+fn log_rejects_missing_message() {
+    let output = run(&["log", "info", "NET"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn export_writes_html_document() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let log = tmp.path().join("session.log");
+    let html = tmp.path().join("session.html");
+    std::fs::write(&log, "\x1b[38;2;255;85;85m[ERR]\x1b[0m down\n").unwrap();
+
+    let output = run(&[
+        "export",
+        "--format",
+        "html",
+        "--output",
+        html.to_str().unwrap(),
+        log.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    let doc = std::fs::read_to_string(&html).unwrap();
+    assert!(doc.contains("<span style=\"color:#ff5555\">[ERR]</span> down"));
+}
+
+#[test]
+fn export_rejects_unknown_format() {
+    let output = run(&["export", "--format", "pdf", "x.log"]);
+    assert!(!output.status.success());
+}
+
+/// Runs `hyprslog` with terminal-only output and colors enabled in config,
+/// returning stdout and stderr combined.
+fn colored_run(args: &[&str]) -> (bool, String) {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let conf_dir = tmp.path().join("hypr/hyprs");
+    std::fs::create_dir_all(&conf_dir).unwrap();
+    std::fs::write(
+        conf_dir.join("log.conf"),
+        "[terminal]\ncolors = true\n\n[file]\nenabled = false\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .args(args)
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .output()
+        .expect("failed to run hyprslog");
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    (output.status.success(), text)
+}
+
+#[test]
+fn color_flag_overrides_config() {
+    let (ok, never) = colored_run(&["--color", "never", "info", "NET", "up"]);
+    assert!(ok);
+    assert!(never.contains("up"));
+    assert!(!never.contains('\x1b'), "{never:?}");
+
+    let (ok, always) = colored_run(&["--color=always", "info", "NET", "up"]);
+    assert!(ok);
+    assert!(always.contains('\x1b'), "{always:?}");
+
+    // Output is a pipe here, so auto turns colors off
+    let (ok, auto) = colored_run(&["--color", "auto", "info", "NET", "up"]);
+    assert!(ok);
+    assert!(!auto.contains('\x1b'), "{auto:?}");
+}
+
+#[test]
+fn color_flag_after_the_command_is_message_text() {
+    let (ok, text) = colored_run(&["info", "NET", "use", "--color", "never"]);
+    assert!(ok);
+    assert!(text.contains("use --color never"), "{text:?}");
+}
+
+#[test]
+fn color_flag_rejects_unknown_value() {
+    let (ok, text) = colored_run(&["--color", "sometimes", "info", "NET", "up"]);
+    assert!(!ok);
+    assert!(text.contains("Invalid --color value"));
+}
+
+#[test]
+fn stats_rejects_json_with_csv() {
+    let output = run(&["stats", "--json", "--csv"]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--json and --csv"));
+}
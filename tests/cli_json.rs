@@ -0,0 +1,234 @@
+//! Tests for the `json` CLI command.
+
+mod common;
+
+use common::Capture;
+use hyprs_log::cli::cmd_json;
+use hyprs_log::output::LogRecord;
+use hyprs_log::{Config, Level, Logger};
+use std::io::Write;
+use std::process::{Command, ExitCode, Stdio};
+
+fn run_json(input: &str) -> (ExitCode, Vec<LogRecord>) {
+    run_json_args(&[input])
+}
+
+fn run_json_args(args: &[&str]) -> (ExitCode, Vec<LogRecord>) {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Trace)
+        .output(capture.clone())
+        .build();
+    let code = cmd_json(args, &Config::default(), &logger);
+    let records = capture.records();
+    (code, records)
+}
+
+/// Pipes `stdin` into `hyprslog json` with a default config.
+fn run_json_stdin(stdin: &str) -> std::process::Output {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .arg("json")
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run hyprslog");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn array_input_logs_each_element() {
+    let (code, records) = run_json(
+        r#"[
+            {"level":"info","scope":"A","msg":"first"},
+            {"level":"warn","scope":"B","msg":"second","ts":"2026-01-01T00:00:00Z","fields":{"n":3,"who":"me"}}
+        ]"#,
+    );
+
+    assert_eq!(code, ExitCode::SUCCESS);
+    assert_eq!(records.len(), 2);
+    assert_eq!(
+        (
+            records[0].level,
+            records[0].scope.as_str(),
+            records[0].message.as_str()
+        ),
+        (Level::Info, "A", "first")
+    );
+    assert!(records[0].values.fields.is_empty());
+    assert_eq!(records[1].level, Level::Warn);
+    assert_eq!(
+        records[1].timestamp.map(|ts| ts.to_rfc3339()),
+        Some("2026-01-01T00:00:00+00:00".to_string())
+    );
+    assert_eq!(
+        records[1].values.fields,
+        vec![
+            ("n".to_string(), "3".to_string()),
+            ("who".to_string(), "me".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn unparsable_ts_is_kept_as_a_field() {
+    let (code, records) = run_json(r#"{"scope":"A","msg":"m","ts":"yesterday"}"#);
+
+    assert_eq!(code, ExitCode::SUCCESS);
+    assert_eq!(
+        records[0].values.fields,
+        vec![("ts".to_string(), "yesterday".to_string())]
+    );
+    // Stamped with the time of logging instead
+    assert!(records[0].timestamp.is_some());
+}
+
+#[test]
+fn mixed_array_logs_valid_entries_and_fails() {
+    let (code, records) = run_json(
+        r#"[{"level":"info","scope":"A","msg":"ok"},{"level":"loud","scope":"A","msg":"bad level"},{"scope":"A"},42]"#,
+    );
+
+    assert_eq!(code, ExitCode::FAILURE);
+    let messages: Vec<&str> = records.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(messages, vec!["ok"]);
+}
+
+#[test]
+fn pretty_printed_argument_is_one_entry() {
+    let (code, records) =
+        run_json("{\n \"level\":\"info\",\n \"scope\":\"T\",\n \"msg\":\"hi\"\n}");
+
+    assert_eq!(code, ExitCode::SUCCESS);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].message, "hi");
+}
+
+#[test]
+fn ndjson_argument_logs_each_line() {
+    let (code, records) = run_json(
+        "{\"level\":\"info\",\"scope\":\"A\",\"msg\":\"one\"}\n\
+         {\"level\":\"warn\",\"scope\":\"A\",\"msg\":\"two\"}",
+    );
+
+    assert_eq!(code, ExitCode::SUCCESS);
+    let messages: Vec<&str> = records.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(messages, vec!["one", "two"]);
+}
+
+#[test]
+fn ndjson_stdin_still_works() {
+    let output = run_json_stdin(
+        "{\"level\":\"info\",\"scope\":\"A\",\"msg\":\"line one\"}\n\n\
+         {\"level\":\"info\",\"scope\":\"A\",\"msg\":\"line two\"}\n",
+    );
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("line one"));
+    assert!(stdout.contains("line two"));
+}
+
+#[test]
+fn array_stdin_is_detected() {
+    let output = run_json_stdin(
+        "  \n[{\"level\":\"info\",\"scope\":\"A\",\"msg\":\"from array\"},\
+         {\"level\":\"info\",\"scope\":\"A\",\"msg\":\"and another\"}]\n",
+    );
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("from array"));
+    assert!(stdout.contains("and another"));
+}
+
+#[test]
+fn entry_without_level_uses_default_level() {
+    let (code, records) = run_json_args(&[
+        "--default-level",
+        "warn",
+        r#"{"scope":"A","msg":"no level"}"#,
+    ]);
+
+    assert_eq!(code, ExitCode::SUCCESS);
+    assert_eq!(records.len(), 1);
+    assert_eq!(
+        (records[0].level, records[0].scope.as_str()),
+        (Level::Warn, "A")
+    );
+}
+
+#[test]
+fn entry_without_scope_uses_default_scope() {
+    let (code, records) = run_json_args(&[
+        r#"{"level":"error","msg":"no scope"}"#,
+        "--default-scope",
+        "UPSTREAM",
+    ]);
+
+    assert_eq!(code, ExitCode::SUCCESS);
+    assert_eq!(records.len(), 1);
+    assert_eq!(
+        (records[0].level, records[0].scope.as_str()),
+        (Level::Error, "UPSTREAM")
+    );
+}
+
+#[test]
+fn config_defaults_fill_missing_level_and_scope() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Trace)
+        .output(capture.clone())
+        .build();
+    let mut config = Config::default();
+    config.json.default_level = "debug".to_string();
+    config.json.default_scope = "PRODUCER".to_string();
+
+    let code = cmd_json(&[r#"{"msg":"bare"}"#], &config, &logger);
+
+    assert_eq!(code, ExitCode::SUCCESS);
+    let records = capture.records();
+    assert_eq!(
+        (records[0].level, records[0].scope.as_str()),
+        (Level::Debug, "PRODUCER")
+    );
+}
+
+#[test]
+fn entry_without_msg_fails() {
+    let (code, records) = run_json(r#"{"level":"info","scope":"A"}"#);
+
+    assert_eq!(code, ExitCode::FAILURE);
+    assert!(records.is_empty());
+}
+
+#[test]
+fn invalid_default_level_fails() {
+    let (code, records) = run_json_args(&["--default-level", "loud", r#"{"msg":"x"}"#]);
+
+    assert_eq!(code, ExitCode::FAILURE);
+    assert!(records.is_empty());
+}
+
+#[test]
+fn entry_levels_are_parsed_leniently() {
+    let (code, records) = run_json(
+        r#"[{"level":"E","scope":"A","msg":"letter"},{"level":"3","scope":"A","msg":"string number"},{"level":1,"scope":"A","msg":"number"},{"level":"notice","scope":"A","msg":"synonym"}]"#,
+    );
+
+    assert_eq!(code, ExitCode::SUCCESS);
+    let levels: Vec<Level> = records.iter().map(|r| r.level).collect();
+    assert_eq!(
+        levels,
+        vec![Level::Error, Level::Warn, Level::Debug, Level::Info]
+    );
+}
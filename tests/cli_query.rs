@@ -0,0 +1,138 @@
+//! Tests for the `query` CLI command.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+const ENTRIES: &str = r#"{"id":"1","ts":"2023-12-31T23:00:00+01:00","level":"error","scope":"NET","msg":"old failure","host":"h"}
+{"id":"2","ts":"2024-01-02T10:00:00+01:00","level":"info","scope":"NET","msg":"connected","app":"web","host":"h"}
+{"id":"3","ts":"2024-01-03T10:00:00+01:00","level":"error","scope":"net","msg":"connection reset","app":"web","host":"h"}
+not json at all
+{"id":"4","ts":"2024-01-04T10:00:00+01:00","level":"warn","scope":"DB","msg":"slow query","app":"api","host":"h"}
+{"id":"5","ts":"2024-01-05T10:00:00+01:00","level":"error","scope":"NET","msg":"connection refused","app":"api","host":"h"}
+"#;
+
+/// Writes the sample entries and a config pointing `json.path` at them.
+fn setup() -> TempDir {
+    let tmp = TempDir::new().unwrap();
+    let journal = tmp.path().join("journal.jsonl");
+    fs::write(&journal, ENTRIES).unwrap();
+    let conf_dir = tmp.path().join("hypr/hyprs");
+    fs::create_dir_all(&conf_dir).unwrap();
+    fs::write(
+        conf_dir.join("log.conf"),
+        format!(
+            "[terminal]\ncolors = false\n\n[file]\nenabled = false\n\n[json]\npath = \"{}\"\n",
+            journal.display()
+        ),
+    )
+    .unwrap();
+    tmp
+}
+
+/// Runs `hyprslog query` and returns the ids of the printed entries.
+fn query_ids(config_home: &Path, args: &[&str]) -> Vec<String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .arg("query")
+        .args(args)
+        .env("XDG_CONFIG_HOME", config_home)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            value["id"].as_str().unwrap().to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn no_filters_prints_every_entry() {
+    let tmp = setup();
+    assert_eq!(query_ids(tmp.path(), &[]), ["1", "2", "3", "4", "5"]);
+}
+
+#[test]
+fn level_is_a_minimum() {
+    let tmp = setup();
+    assert_eq!(
+        query_ids(tmp.path(), &["--level", "warn"]),
+        ["1", "3", "4", "5"]
+    );
+}
+
+#[test]
+fn scope_matches_case_insensitively() {
+    let tmp = setup();
+    assert_eq!(
+        query_ids(tmp.path(), &["--level", "error", "--scope", "NET"]),
+        ["1", "3", "5"]
+    );
+}
+
+#[test]
+fn since_and_until_bound_days() {
+    let tmp = setup();
+    assert_eq!(
+        query_ids(
+            tmp.path(),
+            &["--since", "2024-01-01", "--until", "2024-01-04"]
+        ),
+        ["2", "3", "4"]
+    );
+}
+
+#[test]
+fn grep_app_and_limit() {
+    let tmp = setup();
+    assert_eq!(query_ids(tmp.path(), &["--grep", "connection"]), ["3", "5"]);
+    assert_eq!(query_ids(tmp.path(), &["--app", "api"]), ["4", "5"]);
+    assert_eq!(query_ids(tmp.path(), &["--limit", "2"]), ["1", "2"]);
+}
+
+#[test]
+fn explicit_file_overrides_config_path() {
+    let tmp = setup();
+    let other = tmp.path().join("other.jsonl");
+    fs::write(
+        &other,
+        r#"{"id":"x","ts":"2024-02-01T00:00:00Z","level":"info","scope":"A","msg":"m","host":"h"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(query_ids(tmp.path(), &[other.to_str().unwrap()]), ["x"]);
+}
+
+#[test]
+fn pretty_renders_through_terminal() {
+    let tmp = setup();
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .args(["query", "--pretty", "--scope", "db"])
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(text.contains("slow query"), "{text}");
+    assert!(!text.contains("\"id\""));
+}
+
+#[test]
+fn rejects_bad_date() {
+    let tmp = setup();
+    let output = Command::new(env!("CARGO_BIN_EXE_hyprslog"))
+        .args(["query", "--since", "yesterday"])
+        .env("XDG_CONFIG_HOME", tmp.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
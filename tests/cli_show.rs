@@ -0,0 +1,116 @@
+//! Tests for the `show` CLI command.
+
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+use tempfile::TempDir;
+
+fn show(config_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_hyprslog"));
+    cmd.arg("show")
+        .args(args)
+        .env("XDG_CONFIG_HOME", config_home);
+    cmd
+}
+
+fn append(path: &Path, text: &str) {
+    let mut file = OpenOptions::new().append(true).open(path).unwrap();
+    file.write_all(text.as_bytes()).unwrap();
+}
+
+/// Streams the child's stdout lines into a channel.
+fn stdout_lines(child: &mut Child) -> mpsc::Receiver<String> {
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in BufReader::new(stdout).lines() {
+            if tx.send(line.unwrap()).is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+#[test]
+fn prints_last_lines_of_file() {
+    let tmp = TempDir::new().unwrap();
+    let log = tmp.path().join("app.log");
+    fs::write(&log, "one\ntwo\nthree\nfour\n").unwrap();
+
+    let output = show(tmp.path(), &["--lines", "2", log.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "three\nfour\n");
+}
+
+#[test]
+fn resolves_app_log_from_path_structure() {
+    let tmp = TempDir::new().unwrap();
+    let conf_dir = tmp.path().join("hypr/hyprs");
+    let logs = tmp.path().join("logs");
+    fs::create_dir_all(&conf_dir).unwrap();
+    fs::create_dir_all(logs.join("mytool")).unwrap();
+    fs::write(
+        conf_dir.join("log.conf"),
+        format!(
+            "[file]\nbase_dir = \"{}\"\npath_structure = \"{{app}}\"\n",
+            logs.display()
+        ),
+    )
+    .unwrap();
+    fs::write(logs.join("mytool/NET_info_01.log"), "net line\n").unwrap();
+
+    let output = show(tmp.path(), &["--app", "mytool", "--lines", "1"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "net line\n");
+}
+
+#[test]
+fn missing_app_log_fails() {
+    let tmp = TempDir::new().unwrap();
+    let output = show(tmp.path(), &["--app", "nothing-here"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn follow_prints_appended_and_rotated_lines() {
+    let tmp = TempDir::new().unwrap();
+    let log = tmp.path().join("app.log");
+    fs::write(&log, "old\n").unwrap();
+
+    let mut child = show(
+        tmp.path(),
+        &["--follow", "--lines", "1", log.to_str().unwrap()],
+    )
+    .stdout(Stdio::piped())
+    .stderr(Stdio::null())
+    .spawn()
+    .unwrap();
+    let lines = stdout_lines(&mut child);
+    let timeout = Duration::from_secs(10);
+
+    assert_eq!(lines.recv_timeout(timeout).unwrap(), "old");
+
+    append(&log, "appended\n");
+    assert_eq!(lines.recv_timeout(timeout).unwrap(), "appended");
+
+    // Rotation: the file is replaced by a new, shorter one
+    fs::remove_file(&log).unwrap();
+    fs::write(&log, "new\n").unwrap();
+    let rotated = lines.recv_timeout(timeout);
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    assert_eq!(rotated.unwrap(), "new");
+}
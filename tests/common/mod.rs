@@ -0,0 +1,93 @@
+//! Fixtures shared by the integration tests.
+
+#![allow(dead_code)]
+
+use hyprs_log::output::{LogRecord, Output};
+use hyprs_log::{Error, FormatValues, Level};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Output that keeps every record in memory and counts flushes.
+#[derive(Clone, Default)]
+pub struct Capture {
+    records: Arc<Mutex<Vec<LogRecord>>>,
+    flushes: Arc<AtomicUsize>,
+}
+
+impl Output for Capture {
+    fn write(&self, record: &LogRecord) -> Result<(), Error> {
+        self.records.lock().unwrap().push(record.clone());
+        Ok(())
+    }
+
+    fn flush(&self) -> Result<(), Error> {
+        self.flushes.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl Capture {
+    /// Returns the records written so far.
+    pub fn records(&self) -> Vec<LogRecord> {
+        self.records.lock().unwrap().clone()
+    }
+
+    /// Returns the message of every record written so far.
+    pub fn messages(&self) -> Vec<String> {
+        self.records
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| r.message.clone())
+            .collect()
+    }
+
+    /// Returns `(level, message)` of every record written so far.
+    pub fn level_messages(&self) -> Vec<(Level, String)> {
+        self.records
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| (r.level, r.message.clone()))
+            .collect()
+    }
+
+    /// Returns `(level, scope, message)` of every record written so far.
+    pub fn entries(&self) -> Vec<(Level, String, String)> {
+        self.records
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| (r.level, r.scope.clone(), r.message.clone()))
+            .collect()
+    }
+
+    /// Returns how many records were written.
+    pub fn writes(&self) -> usize {
+        self.records.lock().unwrap().len()
+    }
+
+    /// Returns how often the output was flushed.
+    pub fn flushes(&self) -> usize {
+        self.flushes.load(Ordering::SeqCst)
+    }
+
+    /// Forgets the records written so far.
+    pub fn clear(&self) {
+        self.records.lock().unwrap().clear();
+    }
+}
+
+/// Builds a plain record; override other fields with struct update syntax.
+pub fn record(level: Level, scope: &str, message: &str) -> LogRecord {
+    LogRecord {
+        level,
+        scope: scope.to_string(),
+        message: message.to_string(),
+        values: FormatValues::new(),
+        label_override: None,
+        app_name: None,
+        raw: false,
+        timestamp: None,
+    }
+}
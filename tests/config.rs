@@ -1,6 +1,7 @@
 //! Tests for configuration functionality.
 
 use hyprs_log::Transform;
+use hyprs_log::config::KeywordRule;
 use hyprs_log::{Config, Level};
 
 #[test]
@@ -56,6 +57,38 @@ green = "#00ff00"
     assert_eq!(red.g, 0);
 }
 
+#[test]
+fn get_color_accepts_names_and_rgb() {
+    let toml = r##"
+[colors]
+accent = "rgb(255, 121, 198)"
+warn = "orange"
+short = "#0af"
+broken = "not-a-color"
+"##;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.get_color("accent"),
+        Some(hyprs_log::Color::new(255, 121, 198))
+    );
+    assert_eq!(
+        config.get_color("warn"),
+        Some(hyprs_log::Color::new(255, 165, 0))
+    );
+    assert_eq!(
+        config.get_color("short"),
+        Some(hyprs_log::Color::new(0, 170, 255))
+    );
+    assert_eq!(config.get_color("broken"), None);
+    assert_eq!(
+        config.validate(),
+        vec![
+            "colors.broken: invalid color: 'not-a-color' (expected #rrggbb, #rgb, a color name, or rgb(r, g, b))"
+                .to_string()
+        ]
+    );
+}
+
 #[test]
 fn parse_presets() {
     let toml = r#"
@@ -73,3 +106,101 @@ msg = "Application stopped"
     assert_eq!(config.presets.len(), 2);
     assert_eq!(config.presets["startup"].scope, Some("INIT".to_string()));
 }
+
+#[test]
+fn parse_highlight_keyword_rules() {
+    let toml = r#"
+[highlight.keywords]
+ok = "green"
+timeout = { color = "red", levels = ["warn", "error"] }
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    let keywords = &config.highlight.keywords;
+    assert_eq!(keywords["ok"], KeywordRule::Color("green".to_string()));
+    assert_eq!(keywords["timeout"].color(), "red");
+    assert!(keywords["timeout"].applies_to(Some((Level::Error, "NET"))));
+    assert!(!keywords["timeout"].applies_to(Some((Level::Info, "NET"))));
+}
+
+#[test]
+fn parse_scope_colors() {
+    let toml = r##"
+[scope]
+hashed_colors = true
+
+[scope.colors]
+NET = "#8be9fd"
+"##;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert!(config.scope.hashed_colors);
+    assert_eq!(config.scope.colors["NET"], "#8be9fd");
+    assert_eq!(config.scope.min_width, 12);
+}
+
+#[test]
+fn parse_icon_type_emoji() {
+    let mut config = Config::default();
+    config.terminal.icons = "Emoji".to_string();
+    assert_eq!(config.parse_icon_type(), hyprs_log::IconType::Emoji);
+}
+
+#[test]
+fn parse_terminal_structure_overrides() {
+    let toml = r#"
+[terminal]
+structure = "{tag} {msg}"
+
+[terminal.structure_overrides]
+error = "{timestamp} {pid} {tag} {msg}"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(config.terminal.structure, "{tag} {msg}");
+    assert_eq!(
+        config.terminal.structure_overrides["error"],
+        "{timestamp} {pid} {tag} {msg}"
+    );
+    assert!(!config.terminal.structure_overrides.contains_key("info"));
+}
+
+#[test]
+fn validate_reports_bad_levels_and_placeholders() {
+    let toml = r#"
+[general]
+level = "verbose"
+
+[terminal]
+icons = "unicode"
+structure = "{tag} {scpoe} {msg}"
+
+[tag.labels]
+fatal = "FATAL"
+"#;
+    let config: Config = toml::from_str(toml).unwrap();
+    assert_eq!(
+        config.validate(),
+        vec![
+            "general.level: unknown level \"verbose\"".to_string(),
+            "tag.labels.fatal: unknown level \"fatal\"".to_string(),
+            "terminal.icons: unknown icon type \"unicode\"".to_string(),
+            "terminal.structure: unknown placeholder {scpoe}".to_string(),
+        ]
+    );
+    assert!(Config::default().validate().is_empty());
+}
+
+#[test]
+fn to_toml_round_trips() {
+    let mut config = Config::default();
+    config
+        .colors
+        .insert("accent".to_string(), "#123456".to_string());
+    config
+        .tag
+        .labels
+        .insert("info".to_string(), "OK".to_string());
+
+    let parsed: Config = toml::from_str(&config.to_toml().unwrap()).unwrap();
+    assert_eq!(parsed.colors["accent"], "#123456");
+    assert_eq!(parsed.tag.labels["info"], "OK");
+    assert_eq!(parsed.general.level, config.general.level);
+}
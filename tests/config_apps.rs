@@ -141,3 +141,77 @@ fn for_app_multiple_apps_independent() {
     assert_eq!(other_config.general.level, "error");
     assert_eq!(global_config.general.level, "info");
 }
+
+#[test]
+fn logger_from_config_for_app_applies_level_override() {
+    let config: Config = toml::from_str(
+        r#"
+[general]
+level = "info"
+
+[terminal]
+enabled = false
+
+[apps.mytool]
+level = "debug"
+"#,
+    )
+    .unwrap();
+
+    let tool = hyprs_log::Logger::from_config_for_app(&config, "mytool");
+    assert_eq!(tool.min_level(), hyprs_log::Level::Debug);
+
+    let other = hyprs_log::Logger::from_config_for_app(&config, "other");
+    assert_eq!(other.min_level(), hyprs_log::Level::Info);
+}
+
+#[test]
+fn diff_reports_only_app_overridden_fields() {
+    let config: Config = toml::from_str(
+        r#"
+[general]
+level = "info"
+
+[terminal]
+colors = true
+
+[apps.mytool]
+level = "debug"
+
+[apps.mytool.terminal]
+colors = true
+"#,
+    )
+    .unwrap();
+
+    let diff = config.diff(&config.for_app("mytool"));
+
+    assert_eq!(
+        diff,
+        vec![(
+            "general.level".to_string(),
+            "\"info\"".to_string(),
+            "\"debug\"".to_string()
+        )]
+    );
+    assert!(config.diff(&config.for_app("other")).is_empty());
+}
+
+#[test]
+fn diff_renders_keys_missing_on_one_side_as_empty() {
+    let base = Config::default();
+    let mut labelled = base.clone();
+    labelled
+        .tag
+        .labels
+        .insert("warn".to_string(), "CAREFUL".to_string());
+
+    assert_eq!(
+        base.diff(&labelled),
+        vec![(
+            "tag.labels.warn".to_string(),
+            String::new(),
+            "\"CAREFUL\"".to_string()
+        )]
+    );
+}
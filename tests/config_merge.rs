@@ -1,6 +1,29 @@
 use hyprs_log::Config;
-use hyprs_log::config::PresetConfig;
+use hyprs_log::config::{MergeStrategy, PresetConfig};
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Writes a main file (with `main_extra` prepended) that sources one include.
+fn load_pair(dir: &Path, main_extra: &str) -> Config {
+    let main_path = dir.join("main.conf");
+    let include_path = dir.join("include.conf");
+    fs::write(
+        &main_path,
+        format!(
+            "source = \"{}\"\n{main_extra}\n[colors]\nred = \"#111111\"\n\n[terminal]\nicons = \"ascii\"\nstructure = \"main {{msg}}\"\n",
+            include_path.display()
+        ),
+    )
+    .unwrap();
+    fs::write(
+        &include_path,
+        "[colors]\nred = \"#222222\"\nblue = \"#0000ff\"\n\n[terminal]\ncolors = false\nstructure = \"include {msg}\"\n",
+    )
+    .unwrap();
+    Config::load_from(&main_path).unwrap()
+}
 
 #[test]
 fn merge_preserves_existing_and_adds_missing() {
@@ -11,7 +34,7 @@ fn merge_preserves_existing_and_adds_missing() {
         .insert("info".to_string(), "INFO".to_string());
     base.highlight
         .keywords
-        .insert("ok".to_string(), "green".to_string());
+        .insert("ok".to_string(), "green".to_string().into());
     base.icons
         .nerdfont
         .insert("info".to_string(), "i".to_string());
@@ -43,7 +66,7 @@ fn merge_preserves_existing_and_adds_missing() {
     other
         .highlight
         .keywords
-        .insert("fail".to_string(), "red".to_string());
+        .insert("fail".to_string(), "red".to_string().into());
     other
         .icons
         .nerdfont
@@ -75,10 +98,74 @@ fn merge_preserves_existing_and_adds_missing() {
     assert_eq!(base.colors["blue"], "#0000ff");
     assert_eq!(base.tag.labels["info"], "INFO");
     assert_eq!(base.tag.labels["warn"], "WARN");
-    assert_eq!(base.highlight.keywords["ok"], "green");
-    assert_eq!(base.highlight.keywords["fail"], "red");
+    assert_eq!(base.highlight.keywords["ok"].color(), "green");
+    assert_eq!(base.highlight.keywords["fail"].color(), "red");
     assert_eq!(base.icons.nerdfont["info"], "i");
     assert_eq!(base.icons.nerdfont["warn"], "!");
     assert_eq!(base.presets["startup"].msg, "Start");
     assert_eq!(base.presets["shutdown"].msg, "Stop");
 }
+
+#[test]
+fn main_wins_keeps_main_values_for_shared_keys() {
+    let tmp_dir = TempDir::new().unwrap();
+    let config = load_pair(tmp_dir.path(), "");
+
+    assert_eq!(config.colors["red"], "#111111");
+    assert_eq!(config.colors["blue"], "#0000ff");
+    assert_eq!(config.terminal.structure, "main {msg}");
+    assert_eq!(config.terminal.icons, "ascii");
+    // Includes only add map entries; their scalar settings are ignored
+    assert!(config.terminal.colors);
+}
+
+#[test]
+fn fill_lets_include_set_keys_main_leaves_unset() {
+    let tmp_dir = TempDir::new().unwrap();
+    let config = load_pair(tmp_dir.path(), "[general]\nmerge_strategy = \"fill\"\n");
+
+    assert_eq!(config.general.merge_strategy, MergeStrategy::Fill);
+    assert_eq!(config.colors["red"], "#111111");
+    assert_eq!(config.colors["blue"], "#0000ff");
+    assert_eq!(config.terminal.structure, "main {msg}");
+    // Keys only the include sets still apply, also in scalar sections
+    assert!(!config.terminal.colors);
+    assert_eq!(config.terminal.icons, "ascii");
+}
+
+#[test]
+fn source_wins_lets_include_override_shared_keys() {
+    let tmp_dir = TempDir::new().unwrap();
+    let config = load_pair(
+        tmp_dir.path(),
+        "[general]\nmerge_strategy = \"source_wins\"\n",
+    );
+
+    assert_eq!(config.general.merge_strategy, MergeStrategy::SourceWins);
+    assert_eq!(config.colors["red"], "#222222");
+    assert_eq!(config.colors["blue"], "#0000ff");
+    assert_eq!(config.terminal.structure, "include {msg}");
+    assert!(!config.terminal.colors);
+    assert_eq!(config.terminal.icons, "ascii");
+}
+
+#[test]
+fn merge_with_source_wins_overrides_scalars_and_shared_entries() {
+    let mut base = Config::default();
+    base.colors.insert("red".to_string(), "#111111".to_string());
+    base.colors
+        .insert("green".to_string(), "#00ff00".to_string());
+    base.general.level = "debug".to_string();
+
+    let mut other = Config::default();
+    other
+        .colors
+        .insert("red".to_string(), "#222222".to_string());
+    other.general.level = "warn".to_string();
+
+    base.merge_with(other, MergeStrategy::SourceWins);
+
+    assert_eq!(base.colors["red"], "#222222");
+    assert_eq!(base.colors["green"], "#00ff00");
+    assert_eq!(base.general.level, "warn");
+}
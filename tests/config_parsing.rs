@@ -1,5 +1,5 @@
-use hyprs_log::Config;
 use hyprs_log::fmt::{Alignment, IconType, Transform};
+use hyprs_log::{Config, Level};
 
 #[test]
 fn parse_icon_type_variants() {
@@ -56,3 +56,24 @@ fn parse_scope_alignment_variants() {
     config.scope.alignment = "center".to_string();
     assert_eq!(config.parse_scope_alignment(), Alignment::Center);
 }
+
+#[test]
+fn parse_scope_levels_skips_invalid_levels() {
+    let config: Config = toml::from_str(
+        r#"
+[scopes]
+net = "trace"
+db = "loud"
+"#,
+    )
+    .unwrap();
+
+    let levels = config.parse_scope_levels();
+    assert_eq!(levels.len(), 1);
+    assert_eq!(levels["net"], Level::Trace);
+    assert!(
+        config
+            .validate()
+            .contains(&"scopes.db: unknown level \"loud\"".to_string())
+    );
+}
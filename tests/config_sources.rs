@@ -74,3 +74,86 @@ fn load_with_cyclic_sources_errors() {
     let err = Config::load_from(&a_path).unwrap_err();
     assert!(matches!(err, Error::CyclicInclude(_)));
 }
+
+#[test]
+fn load_with_glob_source_merges_every_match() {
+    let tmp_dir = TempDir::new().unwrap();
+    let conf_d = tmp_dir.path().join("conf.d");
+    fs::create_dir(&conf_d).unwrap();
+    let base_path = tmp_dir.path().join("base.toml");
+
+    fs::write(
+        &base_path,
+        format!("source = \"{}\"\n", conf_d.join("*.conf").display()),
+    )
+    .unwrap();
+    fs::write(
+        conf_d.join("10-colors.conf"),
+        "[colors]\nred = \"#ff0000\"\n",
+    )
+    .unwrap();
+    fs::write(
+        conf_d.join("20-presets.conf"),
+        "[presets.boot]\nlevel = \"info\"\nmsg = \"up\"\n",
+    )
+    .unwrap();
+    fs::write(
+        conf_d.join("ignored.toml"),
+        "[colors]\nblue = \"#0000ff\"\n",
+    )
+    .unwrap();
+
+    let config = Config::load_from(&base_path).unwrap();
+    assert!(config.colors.contains_key("red"));
+    assert!(config.presets.contains_key("boot"));
+    assert!(!config.colors.contains_key("blue"));
+}
+
+#[test]
+fn load_with_recursive_glob_source_reaches_nested_dirs() {
+    let tmp_dir = TempDir::new().unwrap();
+    let nested = tmp_dir.path().join("conf.d").join("host");
+    fs::create_dir_all(&nested).unwrap();
+    let base_path = tmp_dir.path().join("base.toml");
+
+    fs::write(
+        &base_path,
+        format!(
+            "source = \"{}\"\n",
+            tmp_dir
+                .path()
+                .join("conf.d")
+                .join("**")
+                .join("*.conf")
+                .display()
+        ),
+    )
+    .unwrap();
+    fs::write(
+        tmp_dir.path().join("conf.d").join("colors.conf"),
+        "[colors]\nred = \"#ff0000\"\n",
+    )
+    .unwrap();
+    fs::write(nested.join("tags.conf"), "[colors]\nblue = \"#0000ff\"\n").unwrap();
+
+    let config = Config::load_from(&base_path).unwrap();
+    assert!(config.colors.contains_key("red"));
+    assert!(config.colors.contains_key("blue"));
+}
+
+#[test]
+fn glob_source_matching_itself_is_cyclic() {
+    let tmp_dir = TempDir::new().unwrap();
+    let base_path = tmp_dir.path().join("base.conf");
+
+    fs::write(
+        &base_path,
+        format!("source = \"{}\"\n", tmp_dir.path().join("*.conf").display()),
+    )
+    .unwrap();
+
+    assert!(matches!(
+        Config::load_from(&base_path),
+        Err(Error::CyclicInclude(_))
+    ));
+}
@@ -0,0 +1,56 @@
+//! Tests for collapsing repeated records.
+
+mod common;
+
+use common::Capture;
+use hyprs_log::Logger;
+use hyprs_log::output::DedupOutput;
+
+/// Returns each captured record as `"<scope> <message>"`.
+fn lines(capture: &Capture) -> Vec<String> {
+    capture
+        .entries()
+        .into_iter()
+        .map(|(_, scope, message)| format!("{scope} {message}"))
+        .collect()
+}
+
+#[test]
+fn dedup_collapses_consecutive_repeats() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .output(DedupOutput::new(capture.clone()))
+        .build();
+
+    logger.warn("NET", "timeout");
+    logger.warn("NET", "timeout");
+    logger.warn("NET", "timeout");
+    logger.info("NET", "recovered");
+
+    assert_eq!(
+        lines(&capture),
+        vec![
+            "NET timeout",
+            "NET last message repeated 2 times",
+            "NET recovered",
+        ]
+    );
+}
+
+#[test]
+fn dedup_flush_writes_pending_repeat_notice() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .output(DedupOutput::new(capture.clone()))
+        .build();
+
+    logger.info("DB", "retry");
+    logger.info("DB", "retry");
+    logger.flush().unwrap();
+    logger.flush().unwrap();
+
+    assert_eq!(
+        lines(&capture),
+        vec!["DB retry", "DB last message repeated 1 time"]
+    );
+}
@@ -4,9 +4,42 @@
 
 use hyprs_log::{
     HYPRSLOG_LEVEL_DEBUG, HYPRSLOG_LEVEL_ERROR, HYPRSLOG_LEVEL_INFO, HYPRSLOG_LEVEL_TRACE,
-    HYPRSLOG_LEVEL_WARN, hyprslog_free, hyprslog_init, hyprslog_init_simple,
+    HYPRSLOG_LEVEL_WARN, HyprslogContext, hyprslog_flush, hyprslog_free, hyprslog_get_last_error,
+    hyprslog_init, hyprslog_init_simple, hyprslog_init_with_config, hyprslog_log, hyprslog_log_n,
+    hyprslog_set_autoflush,
 };
+use std::ffi::{CStr, CString, c_char};
+use std::fs;
+use std::path::Path;
 use std::ptr;
+use tempfile::TempDir;
+
+/// Creates a context logging `{scope} {msg}` lines to `ffi.log` in `dir`.
+fn file_context(dir: &Path) -> *mut HyprslogContext {
+    let config_path = dir.join("log.conf");
+    fs::write(
+        &config_path,
+        format!(
+            "[terminal]\nenabled = false\n\n[file]\nenabled = true\nbase_dir = \"{}\"\n\
+             path_structure = \".\"\nfilename_structure = \"ffi.log\"\n\
+             content_structure = \"{{scope}} {{msg}}\"\n",
+            dir.display()
+        ),
+    )
+    .unwrap();
+    let path = CString::new(config_path.to_string_lossy().as_bytes()).unwrap();
+    let ctx = unsafe { hyprslog_init_with_config(path.as_ptr()) };
+    assert!(!ctx.is_null());
+    ctx
+}
+
+fn last_error(ctx: *mut HyprslogContext) -> String {
+    let mut buffer = [0 as c_char; 256];
+    unsafe { hyprslog_get_last_error(ctx, buffer.as_mut_ptr(), buffer.len()) };
+    unsafe { CStr::from_ptr(buffer.as_ptr()) }
+        .to_string_lossy()
+        .into_owned()
+}
 
 #[test]
 fn test_init_free() {
@@ -41,3 +74,71 @@ fn test_level_constants() {
     assert_eq!(HYPRSLOG_LEVEL_WARN, 3);
     assert_eq!(HYPRSLOG_LEVEL_ERROR, 4);
 }
+
+#[test]
+fn test_log_invalid_utf8_is_logged_lossily() {
+    let dir = TempDir::new().unwrap();
+    let ctx = file_context(dir.path());
+
+    // Latin-1 "café"
+    let msg = CString::new(b"caf\xe9".to_vec()).unwrap();
+    let scope = CString::new("NET").unwrap();
+    unsafe { hyprslog_log(ctx, HYPRSLOG_LEVEL_INFO, scope.as_ptr(), msg.as_ptr()) };
+
+    let error = last_error(ctx);
+    assert!(error.contains("Invalid UTF-8 in msg"), "{error}");
+    assert_eq!(unsafe { hyprslog_flush(ctx) }, 0);
+    let content = fs::read_to_string(dir.path().join("ffi.log")).unwrap();
+    assert_eq!(content.trim_end(), "NET caf\u{fffd}");
+
+    unsafe {
+        hyprslog_log(ctx, HYPRSLOG_LEVEL_INFO, scope.as_ptr(), scope.as_ptr());
+    }
+    assert_eq!(last_error(ctx), "");
+    unsafe { hyprslog_free(ctx) };
+}
+
+#[test]
+fn test_set_autoflush() {
+    let dir = TempDir::new().unwrap();
+    let ctx = file_context(dir.path());
+    let scope = CString::new("IO").unwrap();
+    let msg = CString::new("durable").unwrap();
+
+    unsafe {
+        assert_eq!(hyprslog_set_autoflush(ctx, 1), 0);
+        hyprslog_log(ctx, HYPRSLOG_LEVEL_INFO, scope.as_ptr(), msg.as_ptr());
+    }
+    // Readable without an explicit hyprslog_flush
+    let content = fs::read_to_string(dir.path().join("ffi.log")).unwrap();
+    assert_eq!(content.trim_end(), "IO durable");
+
+    unsafe {
+        assert_eq!(hyprslog_set_autoflush(ptr::null_mut(), 1), -1);
+        hyprslog_free(ctx);
+    }
+}
+
+#[test]
+fn test_log_n_logs_exact_bytes() {
+    let dir = TempDir::new().unwrap();
+    let ctx = file_context(dir.path());
+    let scope = CString::new("BUF").unwrap();
+    // Not null-terminated, with an embedded NUL; only the first 5 bytes count
+    let buffer = b"ab\0cdTRAILING";
+
+    unsafe {
+        hyprslog_log_n(
+            ctx,
+            HYPRSLOG_LEVEL_INFO,
+            scope.as_ptr(),
+            buffer.as_ptr().cast::<c_char>(),
+            5,
+        );
+        hyprslog_flush(ctx);
+    }
+
+    let content = fs::read(dir.path().join("ffi.log")).unwrap();
+    assert_eq!(content, b"BUF ab\0cd\n");
+    unsafe { hyprslog_free(ctx) };
+}
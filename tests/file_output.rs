@@ -1,4 +1,10 @@
-use hyprs_log::Logger;
+mod common;
+
+use common::record;
+use hyprs_log::config::Config;
+use hyprs_log::fmt::{ScopeConfig, Timezone, hostname};
+use hyprs_log::output::Output;
+use hyprs_log::{Alignment, FileOutput, Level, LineEnding, Logger, SyncMode, Transform};
 use std::fs;
 use tempfile::TempDir;
 
@@ -96,3 +102,500 @@ fn file_output_uses_log_full_app_override() {
     let path = tmp_dir.path().join("appx").join("override.log");
     assert!(path.exists());
 }
+
+#[test]
+fn file_output_pads_scope_to_configured_width() {
+    let tmp_dir = TempDir::new().unwrap();
+    let base_dir = tmp_dir.path().to_string_lossy().into_owned();
+
+    {
+        let logger = Logger::builder()
+            .file()
+            .base_dir(base_dir)
+            .path_structure("logs")
+            .filename_structure("scope.log")
+            .content_structure("{scope}|{msg}")
+            .scope_config(
+                ScopeConfig::new()
+                    .min_width(8)
+                    .alignment(Alignment::Right)
+                    .transform(Transform::Uppercase),
+            )
+            .done()
+            .build();
+
+        logger.info("net", "up");
+    }
+
+    let path = tmp_dir.path().join("logs").join("scope.log");
+    let content = fs::read_to_string(path).unwrap();
+
+    assert_eq!(content.trim_end(), "     NET|up");
+}
+
+#[test]
+fn file_output_from_config_matches_terminal_scope_width() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let mut config = Config::default();
+    config.terminal.enabled = false;
+    config.file.enabled = true;
+    config.file.base_dir = tmp_dir.path().to_string_lossy().into_owned();
+    config.file.path_structure = "logs".to_string();
+    config.file.filename_structure = "cfg.log".to_string();
+    config.file.content_structure = "{scope}|{msg}".to_string();
+    config.scope.min_width = 6;
+
+    {
+        let logger = Logger::from_config_with(&config, "test");
+        logger.info("IO", "ready");
+    }
+
+    let path = tmp_dir.path().join("logs").join("cfg.log");
+    let content = fs::read_to_string(path).unwrap();
+
+    assert_eq!(content.trim_end(), "IO    |ready");
+}
+
+#[test]
+fn file_output_pad_scope_disabled_keeps_raw_scope() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let mut config = Config::default();
+    config.terminal.enabled = false;
+    config.file.enabled = true;
+    config.file.base_dir = tmp_dir.path().to_string_lossy().into_owned();
+    config.file.path_structure = "logs".to_string();
+    config.file.filename_structure = "raw.log".to_string();
+    config.file.content_structure = "{scope}|{msg}".to_string();
+    config.file.pad_scope = false;
+    config.scope.min_width = 6;
+
+    {
+        let logger = Logger::from_config_with(&config, "test");
+        logger.info("IO", "ready");
+    }
+
+    let path = tmp_dir.path().join("logs").join("raw.log");
+    let content = fs::read_to_string(path).unwrap();
+
+    assert_eq!(content.trim_end(), "IO|ready");
+}
+
+#[test]
+fn file_output_buffered_lines_land_after_flush() {
+    let tmp_dir = TempDir::new().unwrap();
+    let base_dir = tmp_dir.path().to_string_lossy().into_owned();
+
+    let logger = Logger::builder()
+        .file()
+        .base_dir(base_dir)
+        .path_structure("logs")
+        .filename_structure("batch.log")
+        .content_structure("{msg}")
+        .buffer_lines(64)
+        .buffer_flush_ms(60_000)
+        .done()
+        .build();
+
+    for i in 0..500 {
+        logger.info("S", &format!("line {i}"));
+    }
+    logger.flush().unwrap();
+
+    let path = tmp_dir.path().join("logs").join("batch.log");
+    let content = fs::read_to_string(path).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+
+    assert_eq!(lines.len(), 500);
+    assert_eq!(lines[0], "line 0");
+    assert_eq!(lines[499], "line 499");
+}
+
+#[cfg(unix)]
+#[test]
+fn file_output_keeps_handle_open_between_writes() {
+    let tmp_dir = TempDir::new().unwrap();
+    let base_dir = tmp_dir.path().to_string_lossy().into_owned();
+
+    let logger = Logger::builder()
+        .file()
+        .base_dir(base_dir)
+        .path_structure("logs")
+        .filename_structure("open.log")
+        .content_structure("{msg}")
+        .done()
+        .build();
+
+    logger.info("S", "first");
+    logger.flush().unwrap();
+
+    // A reopened file would be created fresh at the old path; an open handle
+    // keeps writing to the renamed file.
+    let path = tmp_dir.path().join("logs").join("open.log");
+    let moved = tmp_dir.path().join("logs").join("moved.log");
+    fs::rename(&path, &moved).unwrap();
+
+    logger.info("S", "second");
+    logger.flush().unwrap();
+
+    let content = fs::read_to_string(&moved).unwrap();
+    assert_eq!(content.lines().collect::<Vec<_>>(), vec!["first", "second"]);
+    assert!(!path.exists());
+}
+
+#[test]
+fn file_output_drop_flushes_buffered_lines() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let output = FileOutput::new()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure("logs")
+        .filename_structure("drop.log")
+        .content_structure("{msg}")
+        .buffer_lines(100);
+
+    for line in ["first", "last"] {
+        output.write(&record(Level::Info, "S", line)).unwrap();
+    }
+    drop(output);
+
+    let content = fs::read_to_string(tmp_dir.path().join("logs").join("drop.log")).unwrap();
+    assert_eq!(content.lines().last(), Some("last"));
+}
+
+#[test]
+fn file_output_syncs_on_flush() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let output = FileOutput::new()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure("logs")
+        .filename_structure("audit.log")
+        .content_structure("{msg}")
+        .buffer_lines(100)
+        .sync_mode(SyncMode::Full);
+
+    for line in ["one", "two", "three"] {
+        output.write(&record(Level::Info, "S", line)).unwrap();
+    }
+    assert_eq!(output.syncs(), 0);
+
+    output.flush().unwrap();
+    assert_eq!(output.syncs(), 1);
+
+    // Nothing new to sync
+    output.flush().unwrap();
+    assert_eq!(output.syncs(), 1);
+
+    let content = fs::read_to_string(tmp_dir.path().join("logs").join("audit.log")).unwrap();
+    assert_eq!(content.lines().count(), 3);
+}
+
+#[test]
+fn file_output_without_sync_mode_never_syncs() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let output = FileOutput::new()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure("logs")
+        .filename_structure("plain.log")
+        .content_structure("{msg}");
+    output.write(&record(Level::Info, "S", "line")).unwrap();
+    output.flush().unwrap();
+
+    assert_eq!(output.syncs(), 0);
+}
+
+#[test]
+fn file_output_creates_nested_directories() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let output = FileOutput::new()
+        .base_dir(tmp_dir.path().join("not/yet").to_string_lossy())
+        .path_structure("{app}/{level}/deep")
+        .filename_structure("nested.log")
+        .content_structure("{msg}")
+        .app_name("nest");
+    output.write(&record(Level::Info, "S", "hello")).unwrap();
+    output.flush().unwrap();
+
+    let path = tmp_dir.path().join("not/yet/nest/info/deep/nested.log");
+    assert_eq!(fs::read_to_string(path).unwrap(), "hello\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn file_output_applies_file_mode() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tmp_dir = TempDir::new().unwrap();
+
+    let logger = Logger::builder()
+        .file()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure("logs")
+        .filename_structure("secret.log")
+        .file_mode(0o600)
+        .done()
+        .build();
+    logger.info("S", "sensitive");
+    logger.flush().unwrap();
+
+    let meta = fs::metadata(tmp_dir.path().join("logs").join("secret.log")).unwrap();
+    assert_eq!(meta.permissions().mode() & 0o777, 0o600);
+}
+
+#[test]
+fn file_output_numbers_lines_per_file() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let logger = Logger::builder()
+        .file()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure(".")
+        .filename_structure("{scope}.log")
+        .content_structure("{seq} {msg}")
+        .sequence(true)
+        .done()
+        .build();
+    for text in ["one", "two", "three"] {
+        logger.info("AUDIT", text);
+    }
+    logger.info("OTHER", "first");
+    logger.flush().unwrap();
+
+    let audit = fs::read_to_string(tmp_dir.path().join("AUDIT.log")).unwrap();
+    assert_eq!(audit, "1 one\n2 two\n3 three\n");
+    let other = fs::read_to_string(tmp_dir.path().join("OTHER.log")).unwrap();
+    assert_eq!(other, "1 first\n");
+}
+
+#[test]
+fn sequence_continues_after_writers_are_evicted() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let output = FileOutput::new()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure(".")
+        .filename_structure("{scope}.log")
+        .content_structure("{seq} {msg}")
+        .sequence(true);
+    output.write(&record(Level::Info, "AUDIT", "one")).unwrap();
+    // More files than stay open at once, so every writer gets closed
+    for _ in 0..2 {
+        for i in 0..40 {
+            output
+                .write(&record(Level::Info, &format!("S{i}"), "fill"))
+                .unwrap();
+        }
+    }
+    output.write(&record(Level::Info, "AUDIT", "two")).unwrap();
+    output.flush().unwrap();
+
+    let audit = fs::read_to_string(tmp_dir.path().join("AUDIT.log")).unwrap();
+    assert_eq!(audit, "1 one\n2 two\n");
+    for i in 0..40 {
+        let fill = fs::read_to_string(tmp_dir.path().join(format!("S{i}.log"))).unwrap();
+        assert_eq!(fill, "1 fill\n2 fill\n");
+    }
+}
+
+#[test]
+fn pid_and_hostname_placeholders_render_in_file_content() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let output = FileOutput::new()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure(".")
+        .filename_structure("host.log")
+        .content_structure("{pid} {hostname} {msg}");
+    output.write(&record(Level::Info, "S", "hello")).unwrap();
+    output.flush().unwrap();
+
+    let content = fs::read_to_string(tmp_dir.path().join("host.log")).unwrap();
+    assert_eq!(
+        content,
+        format!("{} {} hello\n", std::process::id(), hostname())
+    );
+}
+
+#[test]
+fn seq_placeholder_is_empty_without_sequence() {
+    let tmp_dir = TempDir::new().unwrap();
+
+    let output = FileOutput::new()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure(".")
+        .filename_structure("plain.log")
+        .content_structure("[{seq}] {msg}");
+    output.write(&record(Level::Info, "S", "hello")).unwrap();
+    output.flush().unwrap();
+
+    let content = fs::read_to_string(tmp_dir.path().join("plain.log")).unwrap();
+    assert_eq!(content, "[] hello\n");
+}
+
+#[test]
+fn file_output_renders_timestamps_in_configured_zone() {
+    let tmp_dir = TempDir::new().unwrap();
+    let write_in = |name: &str, timezone: Timezone| {
+        let output = FileOutput::new()
+            .base_dir(tmp_dir.path().to_string_lossy())
+            .path_structure(".")
+            .filename_structure(name)
+            .content_structure("{timestamp}")
+            .timestamp_format("%Y-%m-%dT%H:%M:%S%:z")
+            .timezone(timezone);
+        output.write(&record(Level::Info, "S", "tick")).unwrap();
+        output.flush().unwrap();
+        let line = fs::read_to_string(tmp_dir.path().join(name)).unwrap();
+        chrono::DateTime::parse_from_rfc3339(line.trim()).unwrap()
+    };
+
+    let utc = write_in("utc.log", Timezone::Utc);
+    let offset = write_in("offset.log", "+05:30".parse().unwrap());
+
+    assert_eq!(utc.offset().local_minus_utc(), 0);
+    assert_eq!(offset.offset().local_minus_utc(), 5 * 3600 + 30 * 60);
+    // Same instant, different wall clock
+    assert!((offset - utc).num_seconds().abs() < 5);
+    assert_ne!(utc.naive_local(), offset.naive_local());
+}
+
+#[test]
+fn timezone_parses_names_and_offsets() {
+    assert_eq!("UTC".parse::<Timezone>(), Ok(Timezone::Utc));
+    assert_eq!("local".parse::<Timezone>(), Ok(Timezone::Local));
+    for offset in ["-0800", "-08:00", "-08"] {
+        let Ok(Timezone::Fixed(parsed)) = offset.parse::<Timezone>() else {
+            panic!("{offset} should parse");
+        };
+        assert_eq!(parsed.local_minus_utc(), -8 * 3600);
+    }
+    #[cfg(not(feature = "chrono-tz"))]
+    assert!("Europe/Berlin".parse::<Timezone>().is_err());
+    assert!("+25:00".parse::<Timezone>().is_err());
+}
+
+#[test]
+#[cfg(feature = "chrono-tz")]
+fn named_timezone_renders_across_dst_change() {
+    let timezone: Timezone = "Europe/Berlin".parse().unwrap();
+    assert!(matches!(timezone, Timezone::Named(_)));
+
+    let tmp_dir = TempDir::new().unwrap();
+    let logger = Logger::builder()
+        .file()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure(".")
+        .filename_structure("berlin.log")
+        .content_structure("{timestamp} {msg}")
+        .timestamp_format("%Y-%m-%d %H:%M:%S%:z")
+        .timezone(timezone)
+        .done()
+        .build();
+
+    // Clocks jump from 02:00 CET to 03:00 CEST at 01:00 UTC
+    for (ts, text) in [
+        ("2024-03-31T00:30:00Z", "before"),
+        ("2024-03-31T01:30:00Z", "after"),
+    ] {
+        let at = chrono::DateTime::parse_from_rfc3339(ts).unwrap().to_utc();
+        logger.log_at(at, Level::Info, "TZ", text);
+    }
+    logger.flush().unwrap();
+
+    let content = fs::read_to_string(tmp_dir.path().join("berlin.log")).unwrap();
+    assert_eq!(
+        content.lines().collect::<Vec<_>>(),
+        vec![
+            "2024-03-31 01:30:00+01:00 before",
+            "2024-03-31 03:30:00+02:00 after",
+        ]
+    );
+}
+
+#[test]
+fn log_at_writes_the_given_timestamp() {
+    let tmp_dir = TempDir::new().unwrap();
+    let logger = Logger::builder()
+        .file()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure(".")
+        .filename_structure("replay.log")
+        .content_structure("{timestamp} {msg}")
+        .timestamp_format("%Y-%m-%d %H:%M:%S")
+        .timezone(Timezone::Utc)
+        .done()
+        .build();
+
+    let past = chrono::DateTime::parse_from_rfc3339("2021-03-04T05:06:07Z")
+        .unwrap()
+        .to_utc();
+    logger.log_at(past, Level::Info, "REPLAY", "old event");
+    logger.flush().unwrap();
+
+    let content = fs::read_to_string(tmp_dir.path().join("replay.log")).unwrap();
+    assert_eq!(content.trim_end(), "2021-03-04 05:06:07 old event");
+}
+
+#[test]
+fn file_output_uses_configured_line_ending() {
+    let tmp_dir = TempDir::new().unwrap();
+    let write_with = |name: &str, ending: Option<LineEnding>| {
+        let mut output = FileOutput::new()
+            .base_dir(tmp_dir.path().to_string_lossy())
+            .path_structure(".")
+            .filename_structure(name)
+            .content_structure("{msg}");
+        if let Some(ending) = ending {
+            output = output.line_ending(ending);
+        }
+        output.write(&record(Level::Info, "S", "a")).unwrap();
+        output.write(&record(Level::Info, "S", "b")).unwrap();
+        output.flush().unwrap();
+        fs::read(tmp_dir.path().join(name)).unwrap()
+    };
+
+    assert_eq!(write_with("default.log", None), b"a\nb\n");
+    assert_eq!(
+        write_with("crlf.log", Some(LineEnding::CrLf)),
+        b"a\r\nb\r\n"
+    );
+}
+
+#[test]
+fn fixed_clock_sets_rendered_timestamp() {
+    struct FixedClock(std::time::SystemTime);
+
+    impl hyprs_log::Clock for FixedClock {
+        fn now(&self) -> std::time::SystemTime {
+            self.0
+        }
+    }
+
+    let tmp_dir = TempDir::new().unwrap();
+    let fixed = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+    let logger = Logger::builder()
+        .clock(FixedClock(fixed))
+        .file()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure(".")
+        .filename_structure("clock.log")
+        .content_structure("{timestamp} {msg}")
+        .timestamp_format("%Y-%m-%d %H:%M:%S")
+        .timezone(Timezone::Utc)
+        .done()
+        .build();
+
+    logger.info("CLOCK", "first");
+    logger.warn("CLOCK", "second");
+    logger.flush().unwrap();
+
+    let content = fs::read_to_string(tmp_dir.path().join("clock.log")).unwrap();
+    assert_eq!(
+        content,
+        "2023-11-14 22:13:20 first\n2023-11-14 22:13:20 second\n"
+    );
+}
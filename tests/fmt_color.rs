@@ -1,4 +1,8 @@
+mod common;
+
+use common::record;
 use hyprs_log::fmt::{Color, colorize, colorize_bg};
+use hyprs_log::{Level, TerminalOutput};
 
 #[test]
 fn from_hex_parses_valid_colors() {
@@ -40,3 +44,100 @@ fn colorize_helpers_wrap_with_reset() {
     let fg_bg = colorize_bg(text, fg, bg);
     assert_eq!(fg_bg, "\x1b[38;2;1;2;3m\x1b[48;2;4;5;6mhi\x1b[0m");
 }
+
+#[test]
+fn hashed_color_is_deterministic_per_name() {
+    assert_eq!(Color::hashed("NET"), Color::hashed("NET"));
+    assert_ne!(Color::hashed("NET"), Color::hashed("DB"));
+    // Pinned so a hash change (which would recolor every scope) is noticed
+    assert_eq!(Color::hashed("NET"), Color::new(224, 123, 108));
+}
+
+#[test]
+fn terminal_applies_configured_scope_color() {
+    let color = Color::from_hex("#123456");
+    let output = TerminalOutput::new()
+        .template("{scope}")
+        .scope_color("NET", color);
+
+    let rendered = output.render(&record(Level::Info, "NET", "msg"));
+    assert!(rendered.starts_with(&color.fg_ansi()));
+    assert!(rendered.contains("NET"));
+
+    // Unconfigured scopes stay dimmed
+    assert!(
+        output
+            .render(&record(Level::Info, "DB", "msg"))
+            .starts_with("\x1b[2m")
+    );
+}
+
+#[test]
+fn terminal_hashed_scope_colors() {
+    let output = TerminalOutput::new()
+        .template("{scope}")
+        .hashed_scope_colors(true);
+
+    let rendered = output.render(&record(Level::Info, "DB", "msg"));
+    assert!(rendered.starts_with(&Color::hashed("DB").fg_ansi()));
+}
+
+#[test]
+fn parse_accepts_hex_forms() {
+    assert_eq!(Color::parse("#ff79c6"), Ok(Color::new(255, 121, 198)));
+    assert_eq!(Color::parse("FF79C6"), Ok(Color::new(255, 121, 198)));
+    assert_eq!(Color::parse("#f0a"), Ok(Color::new(255, 0, 170)));
+    assert_eq!(Color::parse("0af"), Ok(Color::new(0, 170, 255)));
+}
+
+#[test]
+fn parse_accepts_named_colors_case_insensitively() {
+    assert_eq!(Color::parse("red"), Ok(Color::new(255, 0, 0)));
+    assert_eq!(Color::parse("Teal"), Ok(Color::new(0, 128, 128)));
+    assert_eq!(Color::parse("grey"), Color::parse("gray"));
+}
+
+#[test]
+fn parse_accepts_rgb_and_rgba() {
+    assert_eq!(
+        Color::parse("rgb(255,121,198)"),
+        Ok(Color::new(255, 121, 198))
+    );
+    assert_eq!(Color::parse(" RGB( 1 , 2 , 3 ) "), Ok(Color::new(1, 2, 3)));
+    assert_eq!(
+        Color::parse("rgba(10, 20, 30, 0.5)"),
+        Ok(Color::new(10, 20, 30))
+    );
+    assert_eq!("rgb(0,0,0)".parse::<Color>(), Ok(Color::new(0, 0, 0)));
+}
+
+#[test]
+fn parse_rejects_garbage_with_clear_error() {
+    for input in [
+        "",
+        "#ff79c",
+        "#gggggg",
+        "notacolor",
+        "rgb(256,0,0)",
+        "rgb(1,2)",
+        "rgba(1,2,3)",
+        "rgba(1,2,3,2)",
+        "rgb(1,2,3",
+    ] {
+        assert!(Color::parse(input).is_err(), "{input:?} should not parse");
+    }
+    let err = Color::parse("bluish").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid color: 'bluish' (expected #rrggbb, #rgb, a color name, or rgb(r, g, b))"
+    );
+}
+
+#[test]
+fn inline_tags_accept_short_hex_and_names() {
+    let colors = std::collections::HashMap::new();
+    let rendered = hyprs_log::fmt::render(&hyprs_log::fmt::parse("<#f00>x</#f00>"), &colors);
+    assert_eq!(rendered, "\x1b[38;2;255;0;0mx\x1b[0m");
+    let rendered = hyprs_log::fmt::render(&hyprs_log::fmt::parse("<teal>x</teal>"), &colors);
+    assert_eq!(rendered, "\x1b[38;2;0;128;128mx\x1b[0m");
+}
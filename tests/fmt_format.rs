@@ -34,3 +34,111 @@ fn parse_unclosed_brace_is_literal() {
 
     assert_eq!(template.render(&values), "start {tag");
 }
+
+#[test]
+fn validate_reports_unknown_placeholders() {
+    let typo = FormatTemplate::parse("{tag} {scpoe}  {msg}");
+    assert_eq!(typo.validate(), vec!["scpoe".to_string()]);
+
+    let valid = FormatTemplate::parse("{tag} {scope}  {msg}");
+    assert!(valid.validate().is_empty());
+}
+
+#[test]
+fn validate_accepts_every_known_placeholder() {
+    let names: Vec<&str> = Placeholder::ALL.iter().map(|ph| ph.as_str()).collect();
+    let all = format!("{{{}}}", names.join("}{"));
+    assert!(FormatTemplate::parse(&all).validate().is_empty());
+}
+
+#[test]
+fn optional_placeholder_collapses_when_empty() {
+    let template = FormatTemplate::parse("{timestamp} {tag} {scope?}  {msg}");
+
+    let present = FormatValues::new()
+        .timestamp("12:00")
+        .tag("[INFO]")
+        .scope("NET")
+        .msg("up");
+    assert_eq!(template.render(&present), "12:00 [INFO] NET  up");
+
+    let empty = FormatValues::new()
+        .timestamp("12:00")
+        .tag("[INFO]")
+        .msg("up");
+    assert_eq!(template.render(&empty), "12:00 [INFO] up");
+
+    // Padding-only values count as empty
+    let padded = empty.scope("     ");
+    assert_eq!(template.render(&padded), "12:00 [INFO] up");
+}
+
+#[test]
+fn optional_placeholder_at_end_trims_preceding_space() {
+    let template = FormatTemplate::parse("{msg} {app?}");
+
+    let values = FormatValues::new().msg("hello");
+    assert_eq!(template.render(&values), "hello");
+    assert_eq!(template.render(&values.app("tool")), "hello tool");
+    assert!(template.validate().is_empty());
+}
+
+#[test]
+fn merge_fills_unset_values_and_keeps_own_fields() {
+    let defaults = FormatValues::new()
+        .app("svc")
+        .hostname("box")
+        .field("env", "prod")
+        .field("region", "eu");
+    let merged = FormatValues::new()
+        .app("own")
+        .field("env", "dev")
+        .merge(&defaults);
+
+    assert_eq!(merged.app, "own");
+    assert_eq!(merged.hostname, "box");
+    assert_eq!(
+        merged.fields,
+        vec![
+            ("region".to_string(), "eu".to_string()),
+            ("env".to_string(), "dev".to_string()),
+        ]
+    );
+}
+
+#[test]
+#[allow(clippy::literal_string_with_formatting_args)] // `{pad:N}` is template syntax
+fn pad_placeholder_emits_exact_spaces() {
+    let template = FormatTemplate::parse("{tag}{pad:3}{msg}");
+    assert_eq!(
+        template.segments(),
+        &[
+            FormatSegment::Placeholder(Placeholder::Tag),
+            FormatSegment::Pad(3),
+            FormatSegment::Placeholder(Placeholder::Msg),
+        ]
+    );
+
+    let values = FormatValues::new().tag("[INFO]").msg("up");
+    assert_eq!(template.render(&values), "[INFO]   up");
+    assert!(template.validate().is_empty());
+}
+
+#[test]
+#[allow(clippy::literal_string_with_formatting_args)] // `{pad:N}` is template syntax
+fn pad_survives_optional_collapse() {
+    let template = FormatTemplate::parse("{scope?}{pad:2}{msg}");
+    assert_eq!(template.render(&FormatValues::new().msg("up")), "  up");
+
+    let invalid = FormatTemplate::parse("{pad:x}{msg}");
+    assert_eq!(invalid.validate(), vec!["pad:x"]);
+}
+
+#[test]
+fn uses_reports_plain_and_optional_placeholders() {
+    let template = FormatTemplate::parse("{timestamp} {scope?} {msg}");
+
+    assert!(template.uses(Placeholder::Timestamp));
+    assert!(template.uses(Placeholder::Scope));
+    assert!(!template.uses(Placeholder::Pid));
+}
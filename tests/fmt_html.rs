@@ -0,0 +1,83 @@
+mod common;
+
+use common::record;
+use hyprs_log::fmt::{ansi_to_html, ansi_to_html_document, colorize};
+use hyprs_log::{Color, Level, TerminalOutput};
+
+#[test]
+fn truecolor_foreground_becomes_span() {
+    assert_eq!(
+        ansi_to_html("\x1b[38;2;255;85;85merror\x1b[0m done"),
+        "<span style=\"color:#ff5555\">error</span> done"
+    );
+}
+
+#[test]
+fn combined_attributes_share_one_span() {
+    assert_eq!(
+        ansi_to_html("\x1b[1m\x1b[48;2;40;42;54m\x1b[38;2;80;250;123mok\x1b[0m"),
+        "<span style=\"color:#50fa7b;background-color:#282a36;font-weight:bold\">ok</span>"
+    );
+    assert_eq!(
+        ansi_to_html("\x1b[2;3;4mx\x1b[0m"),
+        "<span style=\"opacity:0.6;font-style:italic;text-decoration:underline\">x</span>"
+    );
+}
+
+#[test]
+fn style_change_closes_previous_span() {
+    let input = format!(
+        "{}{}",
+        colorize("a", Color::red()),
+        colorize("b", Color::green())
+    );
+    assert_eq!(
+        ansi_to_html(&input),
+        "<span style=\"color:#ff5555\">a</span><span style=\"color:#50fa7b\">b</span>"
+    );
+}
+
+#[test]
+fn partial_resets_clear_single_attributes() {
+    assert_eq!(
+        ansi_to_html("\x1b[1;38;2;1;2;3mA\x1b[39mB\x1b[22mC"),
+        "<span style=\"color:#010203;font-weight:bold\">A</span>\
+         <span style=\"font-weight:bold\">B</span>C"
+    );
+}
+
+#[test]
+fn text_is_escaped_and_other_escapes_dropped() {
+    assert_eq!(
+        ansi_to_html("\x1b[2K<a & \"b\">\x1b]8;;https://x\x07link\x1b]8;;\x07"),
+        "&lt;a &amp; &quot;b&quot;&gt;link"
+    );
+}
+
+#[test]
+fn unterminated_span_is_closed() {
+    assert_eq!(
+        ansi_to_html("\x1b[1mbold\nnext"),
+        "<span style=\"font-weight:bold\">bold\nnext</span>"
+    );
+}
+
+#[test]
+fn document_wraps_fragment_in_pre() {
+    let doc = ansi_to_html_document("\x1b[1mx\x1b[0m", "a<b>.log");
+    assert!(doc.starts_with("<!DOCTYPE html>"));
+    assert!(doc.contains("<title>a&lt;b&gt;.log</title>"));
+    assert!(doc.contains("<pre><span style=\"font-weight:bold\">x</span></pre>"));
+}
+
+#[test]
+fn terminal_output_round_trips_without_escapes() {
+    let output = TerminalOutput::new().colors(true);
+    let html = ansi_to_html(&output.render(&record(
+        Level::Error,
+        "NET",
+        "Connection <bold>failed</bold>",
+    )));
+    assert!(!html.contains('\x1b'));
+    assert!(html.contains("<span style=\"font-weight:bold\">failed</span>"));
+}
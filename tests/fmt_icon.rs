@@ -27,3 +27,28 @@ fn iconset_from_type_matches_variant() {
     assert_eq!(icons.icon_type(), IconType::NerdFont);
     assert!(!icons.get(Level::Error).is_empty());
 }
+
+#[test]
+fn emoji_icons_expose_expected_values() {
+    let icons = IconSet::emoji();
+
+    assert_eq!(icons.icon_type(), IconType::Emoji);
+    assert_eq!(icons.get(Level::Error), "🔴");
+    assert_eq!(icons.get(Level::Warn), "🟡");
+    assert_eq!(icons.get(Level::Info), "🔵");
+    assert_eq!(IconSet::from(IconType::Emoji).get(Level::Info), "🔵");
+}
+
+#[test]
+fn padded_icons_share_the_widest_width() {
+    let mut icons = IconSet::none();
+    assert_eq!(icons.width(), 0);
+    assert_eq!(icons.get_padded(Level::Info), "");
+
+    icons.set(Level::Info, "i");
+    icons.set(Level::Error, "ERR");
+    assert_eq!(icons.width(), 3);
+    assert_eq!(icons.get_padded(Level::Info), "i  ");
+    assert_eq!(icons.get_padded(Level::Error), "ERR");
+    assert_eq!(icons.get_padded(Level::Warn), "");
+}
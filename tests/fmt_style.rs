@@ -38,3 +38,44 @@ fn render_plain_and_strip_tags_remove_styles() {
     assert_eq!(render_plain(&segments), "abc");
     assert_eq!(strip_tags("a<bold>b</bold>c"), "abc");
 }
+
+#[test]
+fn unmatched_tags_stay_plain_text() {
+    let msg = "Usage: hyprslog [<app>] <level> <scope> a < b";
+    assert_eq!(strip_tags(msg), msg);
+    assert_eq!(strip_tags("<x> then <bold>y</bold>"), "<x> then y");
+}
+
+#[test]
+fn background_tags_parse() {
+    assert_eq!(
+        parse("<bg:red>ALERT</bg:red>"),
+        vec![Segment::Background("ALERT".to_string(), "red".to_string())]
+    );
+    assert_eq!(
+        parse("<on-#ff0000>x</on-#ff0000>"),
+        vec![Segment::Background("x".to_string(), "#ff0000".to_string())]
+    );
+}
+
+#[test]
+fn background_nested_in_foreground_keeps_fg_on_close() {
+    let mut colors = HashMap::new();
+    colors.insert("white".to_string(), Color::new(255, 255, 255));
+    let rendered = render(
+        &parse("<white>a <bg:#ff0000>ALERT</bg:#ff0000> b</white>"),
+        &colors,
+    );
+    assert_eq!(
+        rendered,
+        "\x1b[38;2;255;255;255ma \x1b[48;2;255;0;0mALERT\x1b[49m b\x1b[0m"
+    );
+}
+
+#[test]
+fn strip_removes_background_and_nested_tags() {
+    assert_eq!(
+        strip_tags("<red>a <bg:yellow>ALERT</bg:yellow> b</red> <on-blue>c</on-blue>"),
+        "a ALERT b c"
+    );
+}
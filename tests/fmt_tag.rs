@@ -51,3 +51,41 @@ fn format_applies_transform() {
 
     assert_eq!(tag, "warn");
 }
+
+#[test]
+fn format_with_label_never_clips_long_labels() {
+    for alignment in [Alignment::Left, Alignment::Right, Alignment::Center] {
+        let tag = TagConfig::default()
+            .min_width(5)
+            .alignment(alignment)
+            .format_with_label(Level::Info, "deployment");
+
+        assert_eq!(tag, "[DEPLOYMENT]", "{alignment:?}");
+    }
+}
+
+#[test]
+fn format_with_label_pads_short_labels_per_alignment() {
+    let cases = [
+        (Alignment::Left, "[OK    ]"),
+        (Alignment::Right, "[    OK]"),
+        (Alignment::Center, "[  OK  ]"),
+    ];
+    for (alignment, expected) in cases {
+        let tag = TagConfig::default()
+            .min_width(6)
+            .alignment(alignment)
+            .format_with_label(Level::Info, "ok");
+
+        assert_eq!(tag, expected, "{alignment:?}");
+    }
+}
+
+#[test]
+fn hide_for_renders_empty_tag() {
+    let config = TagConfig::default().hide_for(Level::Info);
+
+    assert_eq!(config.format(Level::Info), "");
+    assert_eq!(config.format(Level::Warn), "[WARN ]");
+    assert_eq!(config.format_with_label(Level::Info, "ok"), "[ OK  ]");
+}
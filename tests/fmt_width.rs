@@ -0,0 +1,84 @@
+use hyprs_log::Level;
+use hyprs_log::fmt::{Alignment, IconSet, ScopeConfig, TagConfig, display_width, pad, truncate};
+
+#[test]
+fn display_width_counts_wide_chars_as_two_columns() {
+    assert_eq!(display_width("net"), 3);
+    assert_eq!(display_width("网络"), 4);
+    assert_eq!(display_width("🔴"), 2);
+}
+
+#[test]
+fn display_width_ignores_ansi_escapes() {
+    assert_eq!(display_width("\x1b[31mwarn\x1b[0m"), 4);
+    assert_eq!(display_width("\x1b[38;2;1;2;3m网\x1b[0m"), 2);
+    assert_eq!(display_width("\x1b]8;;https://x\x07link\x1b]8;;\x07"), 4);
+}
+
+#[test]
+fn pad_keeps_ansi_colored_text_at_visible_width() {
+    let padded = pad("\x1b[31mab\x1b[0m", 5, Alignment::Right);
+    assert_eq!(padded, "   \x1b[31mab\x1b[0m");
+    assert_eq!(display_width(&padded), 5);
+}
+
+#[test]
+fn cjk_scope_pads_to_min_width_columns() {
+    let scope = ScopeConfig::new()
+        .min_width(10)
+        .alignment(Alignment::Left)
+        .format("网络");
+
+    assert_eq!(scope, "网络      ");
+    assert_eq!(display_width(&scope), 10);
+}
+
+#[test]
+fn cjk_scope_centered_stays_at_min_width_columns() {
+    let scope = ScopeConfig::new()
+        .min_width(9)
+        .alignment(Alignment::Center)
+        .format("日本語");
+
+    assert_eq!(display_width(&scope), 9);
+}
+
+#[test]
+fn emoji_icon_tag_pads_to_min_width_columns() {
+    let icon = IconSet::emoji().get(Level::Error).to_string();
+    let tag = TagConfig::new()
+        .prefix("")
+        .suffix("")
+        .min_width(6)
+        .alignment(Alignment::Left)
+        .format_with_label(Level::Error, &format!("{icon}err"));
+
+    assert_eq!(display_width(&tag), 6);
+    assert_eq!(tag, format!("{icon}ERR "));
+}
+
+#[test]
+fn truncate_keeps_short_strings() {
+    assert_eq!(truncate("hello", 5), "hello");
+}
+
+#[test]
+fn truncate_to_zero_is_empty() {
+    assert_eq!(truncate("hello", 0), "");
+    assert_eq!(truncate("\x1b[31mred\x1b[0m", 0), "");
+    assert_eq!(truncate("", 0), "");
+}
+
+#[test]
+fn truncate_cuts_on_char_boundaries() {
+    assert_eq!(truncate("héllo wörld", 6), "héllo…");
+    // A wide character that doesn't fit is left out whole
+    assert_eq!(truncate("ab日本語", 4), "ab…");
+}
+
+#[test]
+fn truncate_skips_escapes_and_resets_style() {
+    let cut = truncate("\x1b[31mred text here\x1b[0m", 4);
+    assert_eq!(cut, "\x1b[31mred\x1b[0m…");
+    assert_eq!(display_width(&cut), 4);
+}
@@ -1,27 +1,32 @@
 //! Tests for auto-highlighting functionality.
 
-use hyprs_log::config::{HighlightConfig, PatternsConfig};
-use hyprs_log::fmt::highlight::inject_tags;
+use hyprs_log::Level;
+use hyprs_log::config::{HighlightConfig, KeywordRule, PatternsConfig};
+use hyprs_log::fmt::highlight::{Highlighter, inject_tags, inject_tags_for};
 use std::collections::HashMap;
 
 fn test_config() -> HighlightConfig {
     let mut keywords = HashMap::new();
-    keywords.insert("ERROR".to_string(), "red".to_string());
-    keywords.insert("WARN".to_string(), "yellow".to_string());
-    keywords.insert("OK".to_string(), "green".to_string());
-    keywords.insert("SUCCESS".to_string(), "green".to_string());
-    keywords.insert("FAIL".to_string(), "red".to_string());
-    keywords.insert("true".to_string(), "green".to_string());
-    keywords.insert("false".to_string(), "red".to_string());
+    keywords.insert("ERROR".to_string(), "red".to_string().into());
+    keywords.insert("WARN".to_string(), "yellow".to_string().into());
+    keywords.insert("OK".to_string(), "green".to_string().into());
+    keywords.insert("SUCCESS".to_string(), "green".to_string().into());
+    keywords.insert("FAIL".to_string(), "red".to_string().into());
+    keywords.insert("true".to_string(), "green".to_string().into());
+    keywords.insert("false".to_string(), "red".to_string().into());
 
     HighlightConfig {
         enabled: true,
         keywords,
+        case_insensitive: true,
+        word_boundary: true,
         patterns: PatternsConfig {
             paths: Some("cyan".to_string()),
             urls: Some("blue".to_string()),
             numbers: Some("orange".to_string()),
             quoted: Some("yellow".to_string()),
+            ip: Some("purple".to_string()),
+            timestamp: Some("pink".to_string()),
         },
     }
 }
@@ -117,3 +122,163 @@ fn test_complex_message() {
     assert!(result.contains("<orange>0</orange>"));
     assert!(result.contains("<cyan>/tmp/log.txt</cyan>"));
 }
+
+#[test]
+fn test_ipv4_highlighting() {
+    let config = test_config();
+    let result = inject_tags("Connected to 192.168.1.10:8080", &config);
+    assert!(result.contains("<purple>192.168.1.10:8080</purple>"));
+    assert!(!result.contains("<orange>"));
+}
+
+#[test]
+fn test_ipv6_highlighting() {
+    let config = test_config();
+    let result = inject_tags("Listening on 2001:db8::8a2e:370:7334 and fe80::1", &config);
+    assert!(result.contains("<purple>2001:db8::8a2e:370:7334</purple>"));
+    assert!(result.contains("<purple>fe80::1</purple>"));
+}
+
+#[test]
+fn test_ipv6_with_clock_like_groups_stays_whole() {
+    let config = test_config();
+    let result = inject_tags("Peer fe80::12:34:56 joined at 12:34:56", &config);
+    assert!(
+        result.contains("<purple>fe80::12:34:56</purple>"),
+        "{result}"
+    );
+    assert!(result.ends_with("at <pink>12:34:56</pink>"), "{result}");
+}
+
+#[test]
+fn test_iso_timestamp_highlighting() {
+    let config = test_config();
+    let result = inject_tags("Started at 2025-01-15T14:30:00Z", &config);
+    assert!(result.contains("<pink>2025-01-15T14:30:00Z</pink>"));
+    assert!(!result.contains("<orange>"));
+}
+
+#[test]
+fn test_ip_and_timestamp_skip_existing_tags() {
+    let config = test_config();
+    let msg = "<red>10.0.0.1 at 2025-01-15</red> then 10.0.0.2";
+    let result = inject_tags(msg, &config);
+    assert!(result.starts_with("<red>10.0.0.1 at 2025-01-15</red>"));
+    assert!(result.contains("<purple>10.0.0.2</purple>"));
+    assert_eq!(result.matches("<purple>").count(), 1);
+    assert!(!result.contains("<pink>"));
+}
+
+#[test]
+fn test_keyword_case_sensitive_when_disabled() {
+    let mut config = test_config();
+    config.case_insensitive = false;
+    let result = inject_tags("ERROR and error", &config);
+    assert!(result.contains("<red>ERROR</red>"));
+    assert!(!result.contains("<red>error</red>"));
+}
+
+#[test]
+fn test_keyword_word_boundary_respected() {
+    let mut config = test_config();
+    config
+        .keywords
+        .insert("error".to_string(), "red".to_string().into());
+    let result = inject_tags("errorless run, Error logged", &config);
+    assert!(!result.contains("<red>error</red>less"));
+    assert!(result.contains("<red>Error</red>"));
+}
+
+#[test]
+fn test_keyword_substring_when_boundary_disabled() {
+    let mut config = test_config();
+    config.word_boundary = false;
+    let result = inject_tags("LOOK at this", &config);
+    assert!(result.contains("LO<green>OK</green>"));
+}
+
+#[test]
+fn test_keyword_with_punctuation_edges() {
+    let mut config = test_config();
+    config
+        .keywords
+        .insert("[ERR]".to_string(), "red".to_string().into());
+    let result = inject_tags("status [ERR] seen", &config);
+    assert!(result.contains("<red>[ERR]</red>"));
+}
+
+fn timeout_rule() -> KeywordRule {
+    KeywordRule::Conditional {
+        color: "red".to_string(),
+        levels: vec!["warn".to_string(), "error".to_string()],
+        scopes: Vec::new(),
+    }
+}
+
+#[test]
+fn test_level_scoped_keyword_applies_to_matching_level() {
+    let mut config = test_config();
+    config
+        .keywords
+        .insert("timeout".to_string(), timeout_rule());
+
+    let error = inject_tags_for("request timeout", &config, Level::Error, "NET");
+    assert!(error.contains("<red>timeout</red>"));
+
+    let info = inject_tags_for("request timeout", &config, Level::Info, "NET");
+    assert!(!info.contains("<red>timeout</red>"));
+}
+
+#[test]
+fn test_scope_scoped_keyword() {
+    let mut config = test_config();
+    config.keywords.insert(
+        "retry".to_string(),
+        KeywordRule::Conditional {
+            color: "yellow".to_string(),
+            levels: Vec::new(),
+            scopes: vec!["net".to_string()],
+        },
+    );
+
+    let net = inject_tags_for("retry 1", &config, Level::Info, "NET");
+    assert!(net.contains("<yellow>retry</yellow>"));
+
+    let db = inject_tags_for("retry 1", &config, Level::Info, "DB");
+    assert!(!db.contains("<yellow>retry</yellow>"));
+}
+
+#[test]
+fn test_conditional_keyword_skipped_without_context() {
+    let mut config = test_config();
+    config
+        .keywords
+        .insert("timeout".to_string(), timeout_rule());
+    let result = inject_tags("request timeout, OK", &config);
+    assert!(!result.contains("<red>timeout</red>"));
+    assert!(result.contains("<green>OK</green>"));
+}
+
+#[test]
+fn test_longer_keyword_wins_overlap() {
+    let mut config = test_config();
+    config
+        .keywords
+        .insert("connection".to_string(), "yellow".into());
+    config
+        .keywords
+        .insert("connection refused".to_string(), "red".into());
+    let result = inject_tags("connection refused by peer", &config);
+    assert!(result.contains("<red>connection refused</red>"));
+}
+
+#[test]
+fn test_links_point_paths_at_file_uris() {
+    let highlighter = Highlighter::new(test_config());
+    let result =
+        highlighter.inject_tags_with_links("Loading /etc/hypr/log.conf", Level::Info, "CFG");
+    assert_eq!(
+        result,
+        "Loading <cyan>\x1b]8;;file:///etc/hypr/log.conf\x1b\\/etc/hypr/log.conf\x1b]8;;\x1b\\</cyan>"
+    );
+}
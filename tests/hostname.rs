@@ -0,0 +1,33 @@
+//! Tests for the `{hostname}` placeholder.
+//!
+//! Kept in its own test binary because it mutates the process environment.
+
+mod common;
+
+use common::record;
+use hyprs_log::fmt::{FormatTemplate, FormatValues, HOSTNAME_ENV, hostname};
+use hyprs_log::{Level, TerminalOutput};
+
+#[test]
+fn hostname_placeholder_honors_env_override_and_caches_it() {
+    let output = TerminalOutput::new()
+        .colors(false)
+        .template("{hostname} {msg}");
+
+    // SAFETY: this is the only test in this binary, so no other thread reads
+    // the environment concurrently.
+    unsafe { std::env::set_var(HOSTNAME_ENV, "container-7") };
+    assert_eq!(
+        output.render(&record(Level::Info, "HOST", "hello")),
+        "container-7 hello"
+    );
+
+    // Resolved once, so later changes don't reach new records
+    // SAFETY: see above.
+    unsafe { std::env::set_var(HOSTNAME_ENV, "container-8") };
+    assert_eq!(hostname(), "container-7");
+
+    let template = FormatTemplate::parse("[{hostname}]");
+    let values = FormatValues::new().hostname(hostname());
+    assert_eq!(template.render(&values), "[container-7]");
+}
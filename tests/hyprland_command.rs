@@ -0,0 +1,64 @@
+//! Tests for Hyprland command socket requests.
+
+#![cfg(feature = "hyprland")]
+
+use hyprs_log::Error;
+use hyprs_log::hyprland::command;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixListener;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[test]
+fn query_returns_reply() {
+    let dir = tempfile::tempdir().unwrap();
+    let listener = UnixListener::bind(command::socket_path(dir.path())).unwrap();
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0u8; 64];
+        let n = stream.read(&mut request).unwrap();
+        assert_eq!(&request[..n], b"j/version");
+        stream.write_all(b"{\"tag\":\"v0.41\"}").unwrap();
+    });
+
+    let reply = command::query(dir.path(), "j/version", Duration::from_secs(2)).unwrap();
+    server.join().unwrap();
+    assert_eq!(reply, "{\"tag\":\"v0.41\"}");
+}
+
+#[test]
+fn zero_timeout_waits_for_the_reply() {
+    let dir = tempfile::tempdir().unwrap();
+    let listener = UnixListener::bind(command::socket_path(dir.path())).unwrap();
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0u8; 64];
+        let _ = stream.read(&mut request).unwrap();
+        stream.write_all(b"ok").unwrap();
+    });
+
+    let reply = command::query(dir.path(), "j/version", Duration::ZERO).unwrap();
+    server.join().unwrap();
+    assert_eq!(reply, "ok");
+}
+
+#[test]
+fn query_times_out_when_server_never_replies() {
+    let dir = tempfile::tempdir().unwrap();
+    let listener = UnixListener::bind(command::socket_path(dir.path())).unwrap();
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        // Hold the connection open without replying
+        thread::sleep(Duration::from_secs(1));
+        drop(stream);
+    });
+
+    let timeout = Duration::from_millis(100);
+    let started = Instant::now();
+    let result = command::query(dir.path(), "j/clients", timeout);
+    let elapsed = started.elapsed();
+    server.join().unwrap();
+
+    assert!(matches!(result, Err(Error::Timeout(t)) if t == timeout));
+    assert!(elapsed < Duration::from_millis(900), "took {elapsed:?}");
+}
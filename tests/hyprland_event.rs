@@ -47,3 +47,63 @@ fn parse_trims_whitespace() {
     assert_eq!(event.name, "workspace");
     assert_eq!(event.data, "3");
 }
+
+#[test]
+fn format_message_expands_movewindowv2() {
+    let event = HyprlandEvent::parse("movewindowv2>>80a6f50,2,dev,main").unwrap();
+    assert_eq!(
+        event.format_message(),
+        "movewindowv2: address=80a6f50 workspace_id=2 workspace=dev,main"
+    );
+}
+
+#[test]
+fn format_message_expands_geometry() {
+    let event = HyprlandEvent::parse("resizewindow>>80a6f50,100,-20,1280,720").unwrap();
+    assert_eq!(
+        event.format_message(),
+        "resizewindow: address=80a6f50 x=100 y=-20 w=1280 h=720"
+    );
+}
+
+#[test]
+fn geometry_is_only_parsed_for_geometry_events() {
+    let title = HyprlandEvent::parse("windowtitle>>80a6f50,1,2,3,4").unwrap();
+    assert!(title.fields().is_none());
+    assert_eq!(title.format_message(), "windowtitle: 80a6f50,1,2,3,4");
+}
+
+#[test]
+fn format_message_keeps_unexpected_payloads_raw() {
+    let moved = HyprlandEvent::parse("movewindowv2>>80a6f50,dev").unwrap();
+    assert_eq!(moved.format_message(), "movewindowv2: 80a6f50,dev");
+
+    let almost = HyprlandEvent::parse("resizewindow>>80a6f50,100,20,wide,720").unwrap();
+    assert_eq!(
+        almost.format_message(),
+        "resizewindow: 80a6f50,100,20,wide,720"
+    );
+    assert!(almost.fields().is_none());
+}
+
+#[test]
+fn to_json_includes_parsed_fields() {
+    let event = HyprlandEvent::parse("openwindow>>80a6f50,2,kitty,Kitty, the terminal").unwrap();
+    let json = event.to_json();
+
+    assert_eq!(json["name"], "openwindow");
+    assert_eq!(json["data"], "80a6f50,2,kitty,Kitty, the terminal");
+    assert_eq!(json["parsed_fields"]["address"], "80a6f50");
+    assert_eq!(json["parsed_fields"]["workspace"], "2");
+    assert_eq!(json["parsed_fields"]["class"], "kitty");
+    assert_eq!(json["parsed_fields"]["title"], "Kitty, the terminal");
+}
+
+#[test]
+fn to_json_unknown_event_has_empty_fields() {
+    let event = HyprlandEvent::parse("custom>>whatever").unwrap();
+    assert_eq!(
+        serde_json::to_string(&event).unwrap(),
+        r#"{"name":"custom","data":"whatever","parsed_fields":{}}"#
+    );
+}
@@ -0,0 +1,184 @@
+//! Tests for replaying recorded Hyprland events and the listener loop, without a socket.
+
+#![cfg(feature = "hyprland")]
+
+mod common;
+
+use common::Capture;
+use hyprs_log::config::HyprlandConfig;
+use hyprs_log::hyprland::listener::{replay_events, run_event_loop};
+use hyprs_log::{Level, Logger};
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+
+const RECORDED: &str = "openwindow>>80a6f50,2,kitty,Kitty\n\
+                        activewindow>>kitty,Kitty\n\
+                        not an event\n\
+                        urgent>>80a6f50\n\
+                        workspace>>3\n";
+
+#[test]
+fn replay_formats_recorded_lines_like_live_listener() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Trace)
+        .output(capture.clone())
+        .build();
+
+    replay_events(Cursor::new(RECORDED), &logger, &HyprlandConfig::default());
+
+    assert_eq!(
+        capture.entries(),
+        vec![
+            (
+                Level::Info,
+                "HYPR".to_string(),
+                "openwindow: 80a6f50,2,kitty,Kitty".to_string()
+            ),
+            (
+                Level::Debug,
+                "HYPR".to_string(),
+                "activewindow: kitty,Kitty".to_string()
+            ),
+            (
+                Level::Warn,
+                "HYPR".to_string(),
+                "urgent: 80a6f50".to_string()
+            ),
+            (Level::Info, "HYPR".to_string(), "workspace: 3".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn replay_applies_event_filter() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Trace)
+        .output(capture.clone())
+        .build();
+    let config = HyprlandConfig {
+        event_filter: Some(vec!["workspace".to_string()]),
+        ..HyprlandConfig::default()
+    };
+
+    replay_events(Cursor::new(RECORDED), &logger, &config);
+
+    let messages = capture.messages();
+    assert_eq!(messages, vec!["workspace: 3".to_string()]);
+}
+
+#[test]
+fn replay_uses_configured_event_names() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Trace)
+        .output(capture.clone())
+        .build();
+    let config: HyprlandConfig = toml::from_str(
+        r#"
+        [event_names]
+        openwindow = "Fenster geöffnet"
+        urgent = "Urgent"
+        "#,
+    )
+    .unwrap();
+
+    replay_events(Cursor::new(RECORDED), &logger, &config);
+
+    let messages = capture.messages();
+    assert_eq!(
+        messages,
+        vec![
+            "Fenster geöffnet: 80a6f50,2,kitty,Kitty".to_string(),
+            "activewindow: kitty,Kitty".to_string(),
+            "Urgent: 80a6f50".to_string(),
+            "workspace: 3".to_string(),
+        ]
+    );
+}
+
+/// Replays [`RECORDED`] under `config` and returns the logged messages.
+fn replay_messages(config: &HyprlandConfig) -> Vec<String> {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Trace)
+        .output(capture.clone())
+        .build();
+    replay_events(Cursor::new(RECORDED), &logger, config);
+    capture.messages()
+}
+
+#[test]
+fn replay_applies_ignore_events() {
+    let config = HyprlandConfig {
+        ignore_events: vec!["activewindow".to_string(), "urgent".to_string()],
+        ..HyprlandConfig::default()
+    };
+
+    assert_eq!(
+        replay_messages(&config),
+        vec![
+            "openwindow: 80a6f50,2,kitty,Kitty".to_string(),
+            "workspace: 3".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn replay_denylist_wins_over_allowlist() {
+    let config = HyprlandConfig {
+        event_filter: Some(vec!["workspace".to_string(), "urgent".to_string()]),
+        ignore_events: vec!["urgent".to_string()],
+        ..HyprlandConfig::default()
+    };
+
+    assert_eq!(replay_messages(&config), vec!["workspace: 3".to_string()]);
+    assert!(!config.allows_event("urgent"));
+    assert!(!config.allows_event("openwindow"));
+    assert!(config.allows_event("workspace"));
+}
+
+#[test]
+fn replay_logs_at_configured_event_levels() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Trace)
+        .output(capture.clone())
+        .build();
+    let config: HyprlandConfig = toml::from_str(
+        r#"
+        [event_levels]
+        urgent = "error"
+        workspace = "debug"
+        openwindow = "loud"
+        "#,
+    )
+    .unwrap();
+
+    replay_events(Cursor::new(RECORDED), &logger, &config);
+
+    let levels: Vec<Level> = capture.records().iter().map(|r| r.level).collect();
+    // openwindow's invalid override falls back to its default (info)
+    assert_eq!(
+        levels,
+        vec![Level::Info, Level::Debug, Level::Error, Level::Debug]
+    );
+}
+
+#[test]
+fn event_loop_flushes_on_shutdown() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+    let shutdown = AtomicBool::new(true);
+
+    run_event_loop(
+        Path::new("/nonexistent/hypr"),
+        &logger,
+        &HyprlandConfig::default(),
+        &shutdown,
+    );
+
+    assert_eq!(capture.flushes(), 1);
+}
@@ -49,3 +49,25 @@ fn resolve_without_env_vars_fails() {
     // We just verify it doesn't panic.
     let _ = socket::resolve_socket_dir(&config);
 }
+
+#[test]
+fn find_socket_dir_prefers_xdg_runtime_dir() {
+    let runtime = tempfile::tempdir().unwrap();
+    let sig = "hyprslog-test-sig-present";
+    let expected = runtime.path().join("hypr").join(sig);
+    std::fs::create_dir_all(&expected).unwrap();
+
+    assert_eq!(
+        socket::find_socket_dir(Some(runtime.path()), sig),
+        Some(expected)
+    );
+}
+
+#[test]
+fn find_socket_dir_without_any_candidate_is_none() {
+    let runtime = tempfile::tempdir().unwrap();
+    // Neither {runtime}/hypr/{sig} nor /tmp/hypr/{sig} exists
+    let sig = "hyprslog-test-sig-missing";
+    assert_eq!(socket::find_socket_dir(Some(runtime.path()), sig), None);
+    assert_eq!(socket::find_socket_dir(None, sig), None);
+}
@@ -0,0 +1,50 @@
+//! Tests for suppressing internal diagnostics.
+//!
+//! Kept in its own test binary because the internal logger is process-global.
+
+use hyprs_log::config::Config;
+use hyprs_log::{Logger, internal};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn disabled_internal_logging_leaves_user_logger_working() {
+    let tmp_dir = TempDir::new().unwrap();
+    let base_dir = tmp_dir.path().to_string_lossy().into_owned();
+
+    let mut config = Config::default();
+    config.general.level = "debug".to_string();
+    config.terminal.enabled = false;
+    config.file.enabled = true;
+    config.file.base_dir.clone_from(&base_dir);
+    config.file.path_structure = ".".to_string();
+    config.file.filename_structure = "internal.log".to_string();
+    config.file.content_structure = "{scope} {msg}".to_string();
+    internal::init_with_config(&config);
+
+    internal::set_enabled(false);
+    assert!(!internal::is_enabled());
+    internal::debug("CONFIG", "hidden diagnostic");
+
+    let logger = Logger::builder()
+        .file()
+        .base_dir(&base_dir)
+        .path_structure(".")
+        .filename_structure("user.log")
+        .content_structure("{scope} {msg}")
+        .done()
+        .build();
+    logger.info("APP", "user message");
+    logger.flush().unwrap();
+
+    internal::set_enabled(true);
+    internal::debug("CONFIG", "visible diagnostic");
+    internal::flush().unwrap();
+
+    let internal_log = fs::read_to_string(tmp_dir.path().join("internal.log")).unwrap();
+    assert!(!internal_log.contains("hidden diagnostic"));
+    assert!(internal_log.contains("visible diagnostic"));
+
+    let user_log = fs::read_to_string(tmp_dir.path().join("user.log")).unwrap();
+    assert_eq!(user_log, "APP user message\n");
+}
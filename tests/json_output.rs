@@ -1,6 +1,6 @@
 //! Tests for JSON database output.
 
-use hyprs_log::{Level, Logger};
+use hyprs_log::{Level, LineEnding, Logger};
 use std::fs;
 use tempfile::TempDir;
 
@@ -180,3 +180,95 @@ fn json_includes_label_override() {
 
     assert_eq!(parsed["label"], "SUCCESS");
 }
+
+#[test]
+fn json_drop_keeps_last_line() {
+    let tmp_dir = TempDir::new().unwrap();
+    let json_path = tmp_dir.path().join("test.jsonl");
+
+    {
+        let logger = Logger::builder().json().path(&json_path).done().build();
+        logger.info("TEST", "first");
+        logger.info("TEST", "last");
+    }
+
+    let content = fs::read_to_string(&json_path).unwrap();
+    let last: serde_json::Value = serde_json::from_str(content.lines().last().unwrap()).unwrap();
+
+    assert_eq!(last["msg"], "last");
+}
+
+#[test]
+fn json_includes_structured_fields() {
+    let tmp_dir = TempDir::new().unwrap();
+    let json_path = tmp_dir.path().join("test.jsonl");
+
+    let logger = Logger::builder().json().path(&json_path).done().build();
+
+    logger.log_kv(Level::Info, "NET", "request", &[("elapsed_ms", "42")]);
+    logger.info("NET", "plain");
+
+    let content = fs::read_to_string(&json_path).unwrap();
+    let lines: Vec<serde_json::Value> = content
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(lines[0]["fields"]["elapsed_ms"], "42");
+    assert!(lines[1].get("fields").is_none());
+}
+
+#[test]
+fn json_uses_configured_line_ending() {
+    let tmp_dir = TempDir::new().unwrap();
+    let json_path = tmp_dir.path().join("crlf.jsonl");
+
+    let logger = Logger::builder()
+        .json()
+        .path(&json_path)
+        .line_ending(LineEnding::CrLf)
+        .done()
+        .build();
+    logger.info("TEST", "one");
+    logger.info("TEST", "two");
+
+    let content = fs::read(&json_path).unwrap();
+    assert!(content.ends_with(b"}\r\n"));
+    assert_eq!(content.windows(2).filter(|w| w == b"\r\n").count(), 2);
+}
+
+#[test]
+fn json_rotates_past_max_file_size() {
+    let tmp_dir = TempDir::new().unwrap();
+    let path = tmp_dir.path().join("journal.jsonl");
+    let logger = Logger::builder()
+        .json()
+        .path(&path)
+        .max_file_size(600)
+        .max_backups(2)
+        .done()
+        .build();
+
+    for i in 0..20 {
+        logger.info("ROT", &format!("entry number {i}"));
+    }
+
+    let backup = tmp_dir.path().join("journal.jsonl.1");
+    assert!(backup.exists());
+    assert!(tmp_dir.path().join("journal.jsonl.2").exists());
+    assert!(!tmp_dir.path().join("journal.jsonl.3").exists());
+
+    for file in [&path, &backup] {
+        let content = fs::read_to_string(file).unwrap();
+        assert!(content.len() <= 600, "{}", content.len());
+        assert!(content.ends_with('\n'));
+        for line in content.lines() {
+            let entry: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(entry["scope"], "ROT");
+        }
+    }
+
+    // The newest entry is in the active file
+    let current = fs::read_to_string(&path).unwrap();
+    assert!(current.contains("entry number 19"));
+}
@@ -13,6 +13,7 @@ fn format_tag_without_override() {
         label_override: None,
         app_name: None,
         raw: false,
+        timestamp: None,
     };
 
     let tag_config = TagConfig::default();
@@ -31,6 +32,7 @@ fn format_tag_with_override() {
         label_override: Some("SUCCESS".to_string()),
         app_name: None,
         raw: false,
+        timestamp: None,
     };
 
     let tag_config = TagConfig::default();
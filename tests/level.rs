@@ -33,6 +33,47 @@ fn level_from_str_invalid() {
     assert!("invalid".parse::<Level>().is_err());
 }
 
+#[test]
+fn level_from_str_stays_strict() {
+    assert!("3".parse::<Level>().is_err());
+    assert!("e".parse::<Level>().is_err());
+    assert!("fatal".parse::<Level>().is_err());
+}
+
+#[test]
+fn parse_lenient_accepts_names() {
+    assert_eq!(Level::parse_lenient("WARNING").unwrap(), Level::Warn);
+    assert_eq!(Level::parse_lenient(" debug ").unwrap(), Level::Debug);
+}
+
+#[test]
+fn parse_lenient_accepts_numbers() {
+    let parsed: Vec<_> = ["0", "1", "2", "3", "4"]
+        .iter()
+        .map(|s| Level::parse_lenient(s).unwrap())
+        .collect();
+    assert_eq!(parsed, Level::all());
+    assert!(Level::parse_lenient("5").is_err());
+}
+
+#[test]
+fn parse_lenient_accepts_letters() {
+    let parsed: Vec<_> = ["t", "D", "i", "W", "e"]
+        .iter()
+        .map(|s| Level::parse_lenient(s).unwrap())
+        .collect();
+    assert_eq!(parsed, Level::all());
+}
+
+#[test]
+fn parse_lenient_accepts_synonyms() {
+    assert_eq!(Level::parse_lenient("notice").unwrap(), Level::Info);
+    assert_eq!(Level::parse_lenient("crit").unwrap(), Level::Error);
+    assert_eq!(Level::parse_lenient("CRITICAL").unwrap(), Level::Error);
+    assert_eq!(Level::parse_lenient("fatal").unwrap(), Level::Error);
+    assert!(Level::parse_lenient("loud").is_err());
+}
+
 #[test]
 fn level_default() {
     assert_eq!(Level::default(), Level::Info);
@@ -0,0 +1,98 @@
+//! Tests for logfmt output.
+
+mod common;
+
+use common::record;
+use hyprs_log::output::LogRecord;
+use hyprs_log::{FormatValues, Level, LogfmtOutput, Logger};
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn render_quotes_message_with_spaces() {
+    let line = LogfmtOutput::new().render(&record(Level::Info, "NET", "connection established"));
+
+    assert!(line.starts_with("ts="));
+    assert!(
+        line.contains(r#" level=info scope=NET msg="connection established""#),
+        "{line}"
+    );
+}
+
+#[test]
+fn render_escapes_quotes_and_strips_style_tags() {
+    let line = LogfmtOutput::new().render(&record(Level::Info, "NET", r#"<red>said</red> "hi""#));
+
+    assert!(line.contains(r#"msg="said \"hi\"""#), "{line}");
+}
+
+#[test]
+fn render_leaves_simple_values_unquoted() {
+    let line = LogfmtOutput::new().render(&record(Level::Info, "NET", "ready"));
+
+    assert!(line.contains(" msg=ready"), "{line}");
+}
+
+#[test]
+fn render_appends_fields_in_order() {
+    let values = FormatValues::new()
+        .field("port", "8080")
+        .field("peer", "host a");
+    let line = LogfmtOutput::new().app_name("demo").render(&LogRecord {
+        values,
+        ..record(Level::Info, "NET", "up")
+    });
+
+    assert!(
+        line.ends_with(r#" msg=up app=demo port=8080 peer="host a""#),
+        "{line}"
+    );
+}
+
+#[test]
+fn logger_writes_logfmt_lines_to_file() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("out.logfmt");
+
+    let logger = Logger::builder().logfmt().path(&path).done().build();
+    logger.log_kv(Level::Warn, "DB", "slow query", &[("ms", "250")]);
+    logger.flush().unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines.len(), 1);
+    assert!(
+        lines[0].ends_with(r#" level=warn scope=DB msg="slow query" ms=250"#),
+        "{}",
+        lines[0]
+    );
+}
+
+#[test]
+fn render_sanitizes_field_keys() {
+    let values = FormatValues::new()
+        .field("user id", "7")
+        .field("a=b", "x")
+        .field("", "empty");
+    let line = LogfmtOutput::new().render(&LogRecord {
+        values,
+        ..record(Level::Info, "NET", "up")
+    });
+
+    assert!(line.ends_with(" user_id=7 a_b=x _=empty"), "{line}");
+}
+
+#[test]
+fn file_stays_open_across_writes() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("nested").join("out.logfmt");
+
+    let logger = Logger::builder().logfmt().path(&path).done().build();
+    logger.info("NET", "one");
+    // Removing the file only unlinks it; the open handle keeps writing there
+    fs::remove_file(&path).unwrap();
+    logger.info("NET", "two");
+    logger.flush().unwrap();
+
+    assert!(!path.exists());
+}
@@ -1,8 +1,13 @@
 //! Tests for logger functionality.
 
+mod common;
+
+use common::Capture;
 use hyprs_log::config::PresetConfig;
-use hyprs_log::{Level, Logger};
+use hyprs_log::output::{LogRecord, Output};
+use hyprs_log::{Error, Level, Logger};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 #[test]
 fn builder_default() {
@@ -78,3 +83,634 @@ fn preset_found() {
     assert_eq!(logger.preset_count(), 1);
     assert!(logger.preset("startup"));
 }
+
+#[test]
+fn child_logger_uses_fixed_scope() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    let net = logger.child("NET");
+    net.info("hi");
+    net.debug("filtered by parent level");
+    net.error("down");
+
+    assert_eq!(
+        capture.entries(),
+        vec![
+            (Level::Info, "NET".to_string(), "hi".to_string()),
+            (Level::Error, "NET".to_string(), "down".to_string()),
+        ]
+    );
+    assert_eq!(net.scope(), "NET");
+}
+
+#[test]
+fn with_level_shares_outputs_at_lower_threshold() {
+    let capture = Capture::default();
+    let parent = Logger::builder()
+        .level(Level::Info)
+        .output(capture.clone())
+        .build();
+    let verbose = parent.with_level(Level::Debug);
+
+    parent.debug("REQ", "parent debug");
+    verbose.debug("REQ", "derived debug");
+
+    assert_eq!(parent.min_level(), Level::Info);
+    assert_eq!(verbose.output_count(), 1);
+    assert_eq!(
+        capture.entries(),
+        vec![(Level::Debug, "REQ".to_string(), "derived debug".to_string())]
+    );
+}
+
+#[test]
+fn error_with_source_renders_cause_chain() {
+    #[derive(Debug)]
+    struct ConfigError(std::io::Error);
+
+    impl std::fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "config unreadable")
+        }
+    }
+
+    impl std::error::Error for ConfigError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+    let err = ConfigError(std::io::Error::other("permission denied"));
+
+    logger.error_with_source("CONFIG", "Startup failed", &err);
+
+    assert_eq!(
+        capture.entries(),
+        vec![(
+            Level::Error,
+            "CONFIG".to_string(),
+            "Startup failed: config unreadable\ncaused by: permission denied".to_string()
+        )]
+    );
+}
+
+#[test]
+fn scope_level_lets_one_scope_log_below_global_level() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Info)
+        .scope_level("net", Level::Trace)
+        .output(capture.clone())
+        .build();
+
+    logger.trace("NET", "packet dump");
+    logger.debug("DB", "query plan");
+    logger.info("DB", "connected");
+
+    assert!(logger.is_enabled_for(Level::Debug, "Net"));
+    assert!(!logger.is_enabled_for(Level::Debug, "DB"));
+    assert_eq!(
+        capture.entries(),
+        vec![
+            (Level::Trace, "NET".to_string(), "packet dump".to_string()),
+            (Level::Info, "DB".to_string(), "connected".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn shared_output_observes_records_from_arc_logger() {
+    let capture = Arc::new(Capture::default());
+    let logger = Arc::new(
+        Logger::builder()
+            .shared_output(Arc::clone(&capture) as Arc<dyn Output>)
+            .build(),
+    );
+
+    let worker = {
+        let logger = Arc::clone(&logger);
+        std::thread::spawn(move || logger.warn("THREAD", "from worker"))
+    };
+    worker.join().unwrap();
+
+    assert_eq!(
+        capture.entries(),
+        vec![(Level::Warn, "THREAD".to_string(), "from worker".to_string())]
+    );
+}
+
+/// Output whose writes always fail.
+struct Failing;
+
+impl Output for Failing {
+    fn write(&self, _record: &LogRecord) -> Result<(), Error> {
+        Err(std::io::Error::other("disk full").into())
+    }
+
+    fn flush(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn output_error_callback_fires_once_per_repeated_error() {
+    let reports: Arc<Mutex<Vec<(usize, String, String)>>> = Arc::default();
+    let sink = Arc::clone(&reports);
+    let capture = Capture::default();
+
+    let logger = Logger::builder()
+        .output(capture.clone())
+        .output(Failing)
+        .on_output_error(move |err| {
+            sink.lock().unwrap().push((
+                err.output_index,
+                err.error.to_string(),
+                err.record.message.clone(),
+            ));
+        })
+        .build();
+
+    logger.info("DISK", "first");
+    logger.info("DISK", "second");
+
+    let reports = reports.lock().unwrap().clone();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].0, 1);
+    assert!(reports[0].1.contains("disk full"));
+    assert_eq!(reports[0].2, "first");
+    // Healthy outputs are unaffected
+    assert_eq!(capture.writes(), 2);
+}
+
+#[test]
+fn file_output_probes_base_dir_on_first_write() {
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let base_dir = tmp_dir.path().join("later");
+
+    let logger = Logger::builder()
+        .fallback_terminal(true)
+        .file()
+        .base_dir(base_dir.to_string_lossy())
+        .path_structure(".")
+        .filename_structure("app.log")
+        .done()
+        .build();
+    assert_eq!(logger.output_count(), 1);
+    // Building touches nothing
+    assert!(!base_dir.exists());
+
+    logger.info("IO", "first");
+    logger.flush().unwrap();
+    // The probe file is gone again; only the log remains
+    let names: Vec<String> = std::fs::read_dir(&base_dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(names, vec!["app.log"]);
+}
+
+#[test]
+fn timer_logs_elapsed_ms_on_drop() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Debug)
+        .output(capture.clone())
+        .build();
+
+    {
+        let _timer = logger.timer("NET", "request");
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+
+    let records = capture.records();
+    assert_eq!(records.len(), 1);
+    let message = &records[0].message;
+    let fields = &records[0].values.fields;
+    assert!(message.starts_with("request completed in "), "{message}");
+    assert_eq!(fields[0].0, "elapsed_ms");
+    assert!(fields[0].1.parse::<u64>().unwrap() >= 5);
+}
+
+#[test]
+fn timer_done_logs_once() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    let timer = logger.timer("DB", "query").level(Level::Info);
+    timer.done();
+
+    assert_eq!(capture.writes(), 1);
+}
+
+#[test]
+fn log_sampled_emits_one_in_every_n() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    for _ in 0..100 {
+        logger.log_sampled(Level::Warn, "RETRY", "connection refused", 10);
+    }
+    logger.log_sampled(Level::Warn, "OTHER", "independent", 10);
+
+    let records = capture.entries();
+    let retry: Vec<&str> = records
+        .iter()
+        .filter(|(_, scope, _)| scope == "RETRY")
+        .map(|(_, _, msg)| msg.as_str())
+        .collect();
+
+    assert_eq!(retry.len(), 10);
+    assert_eq!(retry[0], "connection refused");
+    assert!(
+        retry[1..]
+            .iter()
+            .all(|msg| *msg == "connection refused (suppressed 9)")
+    );
+    assert!(records.iter().any(|(_, scope, _)| scope == "OTHER"));
+}
+
+#[test]
+fn message_prefix_applies_to_normal_lines_only() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .output(capture.clone())
+        .message_prefix("[tenant:42] ")
+        .build();
+
+    logger.info("JOB", "started");
+    logger.raw("  - step one");
+
+    let messages = capture.messages();
+    assert_eq!(messages, vec!["[tenant:42] started", "  - step one"]);
+}
+
+#[test]
+fn with_prefix_derives_a_prefixed_logger() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    logger.with_prefix("[tenant:7] ").warn("JOB", "slow");
+    logger.warn("JOB", "plain");
+
+    let messages = capture.messages();
+    assert_eq!(messages, vec!["[tenant:7] slow", "plain"]);
+}
+
+#[test]
+fn warn_once_emits_each_key_once() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    for _ in 0..3 {
+        logger.warn_once("old-api", "DEPRECATED", "old_api() is deprecated");
+    }
+    logger.warn_once("old-flag", "DEPRECATED", "--old-flag is deprecated");
+
+    let records = capture.entries();
+    let messages: Vec<&str> = records.iter().map(|(_, _, msg)| msg.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec!["old_api() is deprecated", "--old-flag is deprecated"]
+    );
+    assert!(records.iter().all(|(level, _, _)| *level == Level::Warn));
+}
+
+#[test]
+fn warn_once_keys_are_shared_with_derived_loggers() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    logger.warn_once("old-api", "DEPRECATED", "old_api() is deprecated");
+    logger
+        .with_prefix("[acme] ")
+        .warn_once("old-api", "DEPRECATED", "old_api() is deprecated");
+    logger
+        .with_level(Level::Debug)
+        .warn_once("old-api", "DEPRECATED", "old_api() is deprecated");
+
+    assert_eq!(capture.writes(), 1);
+}
+
+#[test]
+fn is_enabled_matches_whether_log_emits() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Warn)
+        .output(capture.clone())
+        .build();
+
+    for level in &Level::all() {
+        let before = capture.writes();
+        logger.log(*level, "CHK", "probe");
+        let emitted = capture.writes() > before;
+        assert_eq!(logger.is_enabled(*level), emitted, "{level}");
+    }
+
+    logger.set_level(Level::Trace);
+    assert!(logger.is_enabled(Level::Trace));
+}
+
+#[derive(serde::Serialize)]
+struct Request<'a> {
+    method: &'a str,
+    status: u16,
+    cached: bool,
+    user: Option<&'a str>,
+}
+
+#[test]
+fn log_serialize_attaches_struct_fields() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    let request = Request {
+        method: "GET",
+        status: 200,
+        cached: false,
+        user: None,
+    };
+    logger
+        .log_serialize(Level::Info, "HTTP", "request done", &request)
+        .unwrap();
+
+    let records = capture.records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].message, "request done");
+    let mut fields = records[0].values.fields.clone();
+    fields.sort();
+    assert_eq!(
+        fields,
+        vec![
+            ("cached".to_string(), "false".to_string()),
+            ("method".to_string(), "GET".to_string()),
+            ("status".to_string(), "200".to_string()),
+            ("user".to_string(), "null".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn log_serialize_rejects_nested_values() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    let nested = HashMap::from([("tags", vec!["a", "b"])]);
+    let result = logger.log_serialize(Level::Info, "HTTP", "nested", &nested);
+    assert!(matches!(result, Err(Error::Format(_))));
+
+    let scalar = logger.log_serialize(Level::Info, "HTTP", "scalar", &42);
+    assert!(matches!(scalar, Err(Error::Format(_))));
+
+    assert!(capture.writes() == 0);
+}
+
+#[test]
+fn default_fields_merge_into_every_record() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .default_fields(
+            hyprs_log::FormatValues::new()
+                .field("service", "mytool")
+                .field("env", "prod"),
+        )
+        .output(capture.clone())
+        .build();
+
+    logger.log_kv(
+        Level::Info,
+        "NET",
+        "up",
+        &[("env", "staging"), ("port", "80")],
+    );
+    logger.info("NET", "plain");
+
+    let records = capture.records();
+    let fields = |i: usize| -> Vec<(&str, &str)> {
+        records[i]
+            .values
+            .fields
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect()
+    };
+    assert_eq!(
+        fields(0),
+        vec![("service", "mytool"), ("env", "staging"), ("port", "80")]
+    );
+    assert_eq!(fields(1), vec![("service", "mytool"), ("env", "prod")]);
+}
+
+#[test]
+fn autoflush_flushes_after_every_record() {
+    let counter = Capture::default();
+    let logger = Logger::builder().output(counter.clone()).build();
+
+    logger.info("IO", "buffered");
+    assert_eq!((counter.writes(), counter.flushes()), (1, 0));
+
+    logger.set_autoflush(true);
+    logger.info("IO", "one");
+    logger.info("IO", "two");
+    assert_eq!((counter.writes(), counter.flushes()), (3, 2));
+
+    logger.set_autoflush(false);
+    logger.info("IO", "three");
+    assert_eq!((counter.writes(), counter.flushes()), (4, 2));
+}
+
+#[test]
+fn presets_lists_names_with_metadata() {
+    let config: hyprs_log::config::Config = toml::from_str(
+        r#"
+        [presets.startup]
+        level = "info"
+        scope = "INIT"
+        msg = "Application started"
+
+        [presets.deployed]
+        level = "success"
+        as = "info"
+        scope = "CI"
+        msg = "Deployed"
+        app_name = "ci"
+        "#,
+    )
+    .unwrap();
+    let logger = Logger::builder().presets(config.presets.clone()).build();
+
+    let listed: Vec<(&str, &PresetConfig)> = logger.presets().collect();
+    let names: Vec<&str> = listed.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, vec!["deployed", "startup"]);
+    for (name, preset) in listed {
+        let expected = &config.presets[name];
+        assert_eq!(preset.level, expected.level);
+        assert_eq!(preset.scope, expected.scope);
+        assert_eq!(preset.app_name, expected.app_name);
+    }
+    assert_eq!(
+        logger.presets().next().unwrap().1.as_level.as_deref(),
+        Some("info")
+    );
+}
+
+#[test]
+fn from_config_str_builds_without_a_file() {
+    let logger = Logger::from_config_str(
+        r#"
+[general]
+level = "debug"
+
+[terminal]
+colors = false
+
+[file]
+enabled = false
+
+[presets.x]
+level = "warn"
+scope = "X"
+msg = "inline"
+"#,
+        "inline",
+    )
+    .unwrap();
+
+    assert!(logger.has_preset("x"));
+    assert_eq!(logger.min_level(), Level::Debug);
+    assert_eq!(logger.output_count(), 1);
+}
+
+#[test]
+fn from_config_str_rejects_invalid_toml() {
+    assert!(Logger::from_config_str("[general", "inline").is_err());
+}
+
+#[test]
+fn null_output_accepts_everything() {
+    let logger = Logger::builder().level(Level::Trace).null().build();
+    assert_eq!(logger.output_count(), 1);
+
+    logger.trace("NULL", "dropped");
+    logger.error("NULL", "dropped too");
+    logger.flush().unwrap();
+}
+
+#[test]
+fn null_output_counts_writes() {
+    let null = Arc::new(hyprs_log::NullOutput::new());
+    let logger = Logger::builder().shared_output(null.clone()).build();
+
+    logger.info("NULL", "one");
+    logger.info("NULL", "two");
+    logger.debug("NULL", "filtered");
+
+    assert_eq!(null.writes(), 2);
+}
+
+#[test]
+fn named_output_can_be_toggled() {
+    let capture = Capture::default();
+    let other = Capture::default();
+    let logger = Logger::builder()
+        .output(capture.clone())
+        .name("capture")
+        .output(other.clone())
+        .build();
+
+    assert!(logger.set_output_enabled("capture", false));
+    logger.info("IMPORT", "muted");
+    assert!(capture.writes() == 0);
+    assert_eq!(other.writes(), 1);
+
+    assert!(logger.set_output_enabled("capture", true));
+    logger.info("IMPORT", "back");
+    let captured = capture.entries();
+    assert_eq!(captured.len(), 1);
+    assert_eq!(captured[0].2, "back");
+
+    assert!(!logger.set_output_enabled("missing", false));
+}
+
+#[test]
+fn output_switch_reaches_derived_loggers() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .output(capture.clone())
+        .name("capture")
+        .build();
+    let tenant = logger.with_prefix("[acme] ");
+    let verbose = logger.with_level(Level::Debug);
+
+    assert!(logger.set_output_enabled("capture", false));
+    tenant.info("IMPORT", "muted");
+    verbose.info("IMPORT", "muted");
+    assert!(capture.writes() == 0);
+
+    assert!(tenant.set_output_enabled("capture", true));
+    logger.info("IMPORT", "back");
+    assert_eq!(capture.writes(), 1);
+}
+
+#[test]
+fn output_names_list_outputs_in_order() {
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let logger = Logger::builder()
+        .terminal()
+        .done()
+        .file()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .done()
+        .output(Capture::default())
+        .build();
+
+    assert_eq!(logger.output_names(), vec!["terminal", "file", "custom"]);
+}
+
+#[test]
+fn given_name_overrides_output_name() {
+    let logger = Logger::builder()
+        .null()
+        .output(Capture::default())
+        .name("capture")
+        .build();
+
+    assert_eq!(logger.output_names(), vec!["null", "capture"]);
+    assert!(logger.set_output_enabled("null", false));
+}
+
+#[test]
+fn outputs_share_the_record_timestamp() {
+    /// Records each timestamp and stalls, so a per-output clock would drift.
+    #[derive(Clone, Default)]
+    struct SlowStamps(Arc<Mutex<Vec<chrono::DateTime<chrono::Utc>>>>);
+
+    impl Output for SlowStamps {
+        fn write(&self, record: &LogRecord) -> Result<(), Error> {
+            self.0.lock().unwrap().push(record.time());
+            std::thread::sleep(std::time::Duration::from_millis(5));
+            Ok(())
+        }
+
+        fn flush(&self) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    let first = SlowStamps::default();
+    let second = SlowStamps::default();
+    let logger = Logger::builder()
+        .output(first.clone())
+        .output(second.clone())
+        .build();
+
+    logger.info("TIME", "once");
+    logger.print("TIME", "twice");
+
+    let first = first.0.lock().unwrap().clone();
+    let second = second.0.lock().unwrap().clone();
+    assert_eq!(first.len(), 2);
+    assert_eq!(first, second);
+}
@@ -0,0 +1,159 @@
+//! Tests for the `hl_*!` logging macros.
+
+// Which tests exist depends on the `max_level_*` ceiling
+#![cfg_attr(
+    any(
+        feature = "max_level_off",
+        feature = "max_level_error",
+        feature = "max_level_warn",
+        feature = "max_level_info",
+        feature = "max_level_debug"
+    ),
+    allow(unused_imports, dead_code)
+)]
+
+mod common;
+
+use common::Capture;
+use hyprs_log::{Level, Logger, hl_debug, hl_error, hl_info, hl_log, hl_trace, hl_warn};
+use std::cell::Cell;
+use std::fmt;
+
+/// Counts how often it is formatted.
+struct Counted<'a>(&'a Cell<u32>);
+
+impl fmt::Display for Counted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.set(self.0.get() + 1);
+        f.write_str("counted")
+    }
+}
+
+#[test]
+#[cfg(not(any(
+    feature = "max_level_off",
+    feature = "max_level_error",
+    feature = "max_level_warn",
+    feature = "max_level_info",
+    feature = "max_level_debug"
+)))]
+fn level_macros_format_arguments() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Trace)
+        .output(capture.clone())
+        .build();
+
+    let x = 7;
+    hl_trace!(logger, "NET", "trace {}", x);
+    hl_debug!(logger, "NET", "debug {x}");
+    hl_info!(logger, "NET", "info {}-{}", x, "y");
+    hl_warn!(logger, "NET", "warn {:>3}", x);
+    hl_error!(logger, "NET", "plain");
+
+    assert_eq!(
+        capture.level_messages(),
+        vec![
+            (Level::Trace, "trace 7".to_string()),
+            (Level::Debug, "debug 7".to_string()),
+            (Level::Info, "info 7-y".to_string()),
+            (Level::Warn, "warn   7".to_string()),
+            (Level::Error, "plain".to_string()),
+        ]
+    );
+}
+
+#[test]
+#[cfg(not(feature = "max_level_off"))]
+fn kv_form_attaches_fields() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    hl_error!(logger, "DB", "query took {}ms", 12; "table" => "users", "rows" => 3);
+
+    let records = capture.records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].message, "query took 12ms");
+    assert_eq!(
+        records[0].values.fields,
+        vec![
+            ("table".to_string(), "users".to_string()),
+            ("rows".to_string(), "3".to_string()),
+        ]
+    );
+}
+
+#[test]
+#[cfg(not(any(feature = "max_level_off", feature = "max_level_error")))]
+fn disabled_level_skips_formatting() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Warn)
+        .output(capture.clone())
+        .build();
+    let formatted = Cell::new(0);
+
+    hl_debug!(logger, "NET", "{}", Counted(&formatted));
+    hl_log!(logger, Level::Info, "NET", "{}", Counted(&formatted); "k" => Counted(&formatted));
+    assert_eq!(formatted.get(), 0);
+    assert!(capture.level_messages().is_empty());
+
+    hl_warn!(logger, "NET", "{}", Counted(&formatted));
+    assert_eq!(formatted.get(), 1);
+    assert_eq!(
+        capture.level_messages(),
+        vec![(Level::Warn, "counted".to_string())]
+    );
+}
+
+#[test]
+#[cfg(not(feature = "max_level_off"))]
+fn macros_record_call_site_location() {
+    let capture = Capture::default();
+    let logger = Logger::builder().output(capture.clone()).build();
+
+    let line = line!() + 1;
+    hl_error!(logger, "NET", "here");
+
+    let records = capture.records();
+    assert_eq!(records[0].values.location, format!("{}:{line}", file!()));
+    assert!(records[0].values.location.starts_with("tests/macros.rs:"));
+
+    let rendered = hyprs_log::TerminalOutput::new()
+        .colors(false)
+        .template("[{location}] {msg}")
+        .render(&records[0]);
+    assert_eq!(rendered, format!("[tests/macros.rs:{line}] here"));
+}
+
+#[test]
+#[cfg(all(
+    feature = "max_level_info",
+    not(any(
+        feature = "max_level_off",
+        feature = "max_level_error",
+        feature = "max_level_warn"
+    ))
+))]
+fn max_level_info_compiles_out_trace() {
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .level(Level::Trace)
+        .output(capture.clone())
+        .build();
+    let formatted = Cell::new(0);
+
+    hl_trace!(logger, "NET", "{}", Counted(&formatted));
+    hl_debug!(logger, "NET", "{}", Counted(&formatted));
+    hl_info!(logger, "NET", "kept");
+    hl_error!(logger, "NET", "also kept");
+
+    assert_eq!(formatted.get(), 0);
+    assert_eq!(
+        capture.level_messages(),
+        vec![
+            (Level::Info, "kept".to_string()),
+            (Level::Error, "also kept".to_string()),
+        ]
+    );
+}
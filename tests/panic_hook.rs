@@ -0,0 +1,26 @@
+//! Tests for the logger panic hook.
+//!
+//! Kept in its own test binary because the panic hook is process-global.
+
+mod common;
+
+use common::Capture;
+use hyprs_log::{Level, Logger};
+use std::sync::Arc;
+
+#[test]
+fn panic_hook_logs_error_record() {
+    let capture = Capture::default();
+    let logger = Arc::new(Logger::builder().output(capture.clone()).build());
+    logger.install_panic_hook();
+
+    let result = std::panic::catch_unwind(|| panic!("boom {}", 42));
+    assert!(result.is_err());
+
+    let records = capture.records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].level, Level::Error);
+    assert_eq!(records[0].scope, "PANIC");
+    assert!(records[0].message.contains("boom 42"));
+    assert!(records[0].message.contains("panic_hook.rs"));
+}
@@ -0,0 +1,71 @@
+//! Tests for preset level validation.
+//!
+//! Kept in its own test binary because the internal logger is process-global.
+
+mod common;
+
+use common::Capture;
+use hyprs_log::config::Config;
+use hyprs_log::{Level, Logger, internal};
+use std::fs;
+use tempfile::TempDir;
+
+const PRESETS: &str = r#"
+[presets.typo]
+level = "inof"
+msg = "typo'd level"
+
+[presets.label]
+level = "success"
+as = "warn"
+msg = "custom label"
+
+[presets.bad_as]
+level = "success"
+as = "loud"
+msg = "bad as"
+"#;
+
+#[test]
+fn invalid_preset_levels_warn_and_default_to_info() {
+    let tmp_dir = TempDir::new().unwrap();
+    let mut internal_config = Config::default();
+    internal_config.terminal.enabled = false;
+    internal_config.file.enabled = true;
+    internal_config.file.base_dir = tmp_dir.path().to_string_lossy().into_owned();
+    internal_config.file.path_structure = ".".to_string();
+    internal_config.file.filename_structure = "internal.log".to_string();
+    internal_config.file.content_structure = "{level} {scope} {msg}".to_string();
+    internal::init_with_config(&internal_config);
+
+    let config: Config = toml::from_str(PRESETS).unwrap();
+    assert_eq!(
+        config.validate_presets(),
+        vec![
+            "presets.bad_as.as: unknown level \"loud\"".to_string(),
+            "presets.typo.level: unknown level \"inof\"".to_string(),
+        ]
+    );
+
+    let capture = Capture::default();
+    let logger = Logger::builder()
+        .presets(config.presets)
+        .output(capture.clone())
+        .build();
+    internal::flush().unwrap();
+
+    let internal_log = fs::read_to_string(tmp_dir.path().join("internal.log")).unwrap();
+    assert!(internal_log.contains("warn PRESETS presets.typo.level: unknown level \"inof\""));
+    assert!(internal_log.contains("presets.bad_as.as: unknown level \"loud\""));
+    assert!(!internal_log.contains("presets.label"));
+
+    assert!(logger.preset("typo"));
+    assert!(logger.preset("label"));
+    assert_eq!(
+        capture.level_messages(),
+        vec![
+            (Level::Info, "typo'd level".to_string()),
+            (Level::Warn, "custom label".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,261 @@
+//! Tests for terminal output rendering.
+
+mod common;
+
+use common::record;
+use hyprs_log::fmt::wrap;
+use hyprs_log::output::LogRecord;
+use hyprs_log::{Level, Logger, TerminalOutput, WrapMode};
+
+#[test]
+fn per_level_template_overrides_global_structure() {
+    let output = TerminalOutput::new()
+        .colors(false)
+        .template("{level} {msg}")
+        .template_for(Level::Error, "{level} [{pid}] {msg}");
+
+    let process_id = std::process::id();
+    assert_eq!(
+        output.render(&record(Level::Error, "NET", "hello")),
+        format!("error [{process_id}] hello")
+    );
+    assert_eq!(
+        output.render(&record(Level::Info, "NET", "hello")),
+        "info hello"
+    );
+    assert_eq!(
+        output.render(&record(Level::Warn, "NET", "hello")),
+        "warn hello"
+    );
+}
+
+#[test]
+fn per_level_template_is_independent_of_global_order() {
+    // The global structure set afterwards must not replace the override
+    let output = TerminalOutput::new()
+        .colors(false)
+        .template_for(Level::Error, "E {pid} {msg}")
+        .template("{msg}");
+
+    assert!(
+        output
+            .render(&record(Level::Error, "NET", "hello"))
+            .starts_with("E ")
+    );
+    assert_eq!(output.render(&record(Level::Info, "NET", "hello")), "hello");
+}
+
+#[test]
+fn timestamp_placeholder_renders_in_terminal() {
+    let output = TerminalOutput::new()
+        .colors(false)
+        .template("{timestamp}|{msg}");
+
+    let rendered = output.render(&record(Level::Info, "NET", "hello"));
+    let (stamp, body) = rendered.split_once('|').unwrap();
+    assert_eq!(stamp.len(), "2026-01-01 00:00:00".len());
+    assert_eq!(body, "hello");
+}
+
+#[test]
+fn builder_structure_for_adds_terminal_output() {
+    let logger = Logger::builder()
+        .terminal()
+        .structure("{tag} {msg}")
+        .structure_for(Level::Error, "{timestamp} {pid} {tag} {msg}")
+        .done()
+        .build();
+    assert_eq!(logger.output_count(), 1);
+}
+
+#[test]
+fn continuation_lines_align_under_message() {
+    let output = TerminalOutput::new()
+        .colors(false)
+        .template("[{level}] {msg}")
+        .wrap_continuation(true);
+    let mut rec = record(Level::Info, "NET", "hello");
+    rec.message = "first\nsecond".to_string();
+
+    let prefix_width = "[info] ".len();
+    assert_eq!(
+        output.render(&rec),
+        format!("[info] first\n{}second", " ".repeat(prefix_width))
+    );
+}
+
+#[test]
+fn continuation_lines_stay_raw_by_default() {
+    let output = TerminalOutput::new()
+        .colors(false)
+        .template("{level} {msg}");
+    let mut rec = record(Level::Info, "NET", "hello");
+    rec.message = "first\nsecond".to_string();
+
+    assert_eq!(output.render(&rec), "info first\nsecond");
+}
+
+#[test]
+fn word_wrap_breaks_at_width_and_indents() {
+    let output = TerminalOutput::new()
+        .colors(false)
+        .template("[{level}] {msg}")
+        .wrap(WrapMode::Word)
+        .wrap_width(20);
+    let mut rec = record(Level::Info, "NET", "hello");
+    rec.message = "the quick brown fox jumps over".to_string();
+
+    // 13 columns left after the 7-column prefix
+    assert_eq!(
+        output.render(&rec),
+        "[info] the quick\n       brown fox\n       jumps over"
+    );
+}
+
+#[test]
+fn char_wrap_ignores_ansi_escapes_when_measuring() {
+    let lines = wrap("\x1b[31mabcdef\x1b[0m", 4, WrapMode::Char);
+    assert_eq!(lines, vec!["\x1b[31mabcd", "ef\x1b[0m"]);
+}
+
+#[test]
+fn word_wrap_splits_words_longer_than_width() {
+    assert_eq!(
+        wrap("ab cdefgh", 4, WrapMode::Word),
+        vec!["ab", "cdef", "gh"]
+    );
+}
+
+#[test]
+fn hidden_tag_collapses_optional_placeholder() {
+    let tags = hyprs_log::TagConfig::default().hide_for(Level::Info);
+    for colors in [false, true] {
+        let output = TerminalOutput::new()
+            .colors(colors)
+            .tag_config(tags.clone())
+            .template("{tag?} {msg}");
+
+        let info = output.render(&record(Level::Info, "NET", "hello"));
+        assert!(!info.contains("INFO"), "colors={colors}: {info:?}");
+        assert!(!info.starts_with(' '), "colors={colors}: {info:?}");
+        assert!(info.contains("hello"));
+        let warn = output.render(&record(Level::Warn, "NET", "hello"));
+        assert!(warn.contains("[WARN ]"), "colors={colors}: {warn:?}");
+    }
+}
+
+#[test]
+fn tag_hide_config_applies_per_level() {
+    let config: hyprs_log::Config = "[tag]\nhide = [\"info\"]\n".parse().unwrap();
+    assert!(config.validate().is_empty());
+
+    let invalid: hyprs_log::Config = "[tag]\nhide = [\"loud\"]\n".parse().unwrap();
+    assert_eq!(invalid.validate(), vec!["tag.hide: unknown level \"loud\""]);
+}
+
+#[test]
+fn icon_only_template_has_no_tag_text() {
+    let output = TerminalOutput::new()
+        .colors(false)
+        .icons(hyprs_log::IconSet::ascii())
+        .template("{icon} {msg}");
+
+    assert_eq!(
+        output.render(&record(Level::Warn, "NET", "hello")),
+        "[!] hello"
+    );
+    assert_eq!(
+        output.render(&record(Level::Info, "NET", "hello")),
+        "[i] hello"
+    );
+}
+
+#[test]
+fn icon_is_padded_to_widest_in_set() {
+    let mut icons = hyprs_log::IconSet::ascii();
+    icons.set(Level::Info, "i");
+    let output = TerminalOutput::new()
+        .colors(false)
+        .icons(icons)
+        .template("{icon} {msg}");
+
+    assert_eq!(
+        output.render(&record(Level::Info, "NET", "hello")),
+        "i   hello"
+    );
+    assert_eq!(
+        output.render(&record(Level::Error, "NET", "hello")),
+        "[x] hello"
+    );
+}
+
+fn url_output(hyperlinks: bool) -> TerminalOutput {
+    let mut highlight = hyprs_log::config::HighlightConfig::default();
+    highlight.patterns.urls = Some("blue".to_string());
+    TerminalOutput::new()
+        .template("{msg}")
+        .highlight_config(highlight)
+        .hyperlinks(hyperlinks)
+}
+
+fn url_record() -> LogRecord {
+    LogRecord {
+        message: "see https://example.com/docs now".to_string(),
+        ..record(Level::Info, "NET", "hello")
+    }
+}
+
+#[test]
+fn hyperlinks_wrap_highlighted_urls_in_osc8() {
+    let rendered = url_output(true).render(&url_record());
+
+    assert!(
+        rendered.contains(
+            "\x1b]8;;https://example.com/docs\x1b\\https://example.com/docs\x1b]8;;\x1b\\"
+        )
+    );
+}
+
+#[test]
+fn hyperlinks_are_off_by_default_and_without_colors() {
+    assert!(!url_output(false).render(&url_record()).contains("\x1b]8;;"));
+    assert!(
+        !url_output(true)
+            .colors(false)
+            .render(&url_record())
+            .contains("\x1b]8;;")
+    );
+}
+
+#[test]
+fn long_message_is_truncated_on_terminal_only() {
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let long = LogRecord {
+        message: "x".repeat(300),
+        ..record(Level::Info, "NET", "hello")
+    };
+
+    let terminal = TerminalOutput::new()
+        .colors(false)
+        .template("{msg}")
+        .max_message_len(200);
+    let rendered = terminal.render(&long);
+    assert_eq!(rendered, format!("{}…", "x".repeat(199)));
+    assert_eq!(rendered.chars().count(), 200);
+
+    let file = hyprs_log::FileOutput::new()
+        .base_dir(tmp_dir.path().to_string_lossy())
+        .path_structure(".")
+        .filename_structure("full.log")
+        .content_structure("{msg}");
+    hyprs_log::output::Output::write(&file, &long).unwrap();
+    hyprs_log::output::Output::flush(&file).unwrap();
+    let content = std::fs::read_to_string(tmp_dir.path().join("full.log")).unwrap();
+    assert_eq!(content.trim_end(), "x".repeat(300));
+}
+
+#[test]
+fn message_max_len_is_read_from_config() {
+    let config: hyprs_log::Config = "[message]\nmax_len = 200\n".parse().unwrap();
+    assert_eq!(config.message.max_len, Some(200));
+}